@@ -7,23 +7,28 @@ use binius_core::{
 };
 use binius_field::{
 	as_packed_field::{PackScalar, PackedType},
-	packed::{get_packed_slice, set_packed_slice},
 	underlier::{Divisible, UnderlierType, WithUnderlier},
 	AESTowerField8b, BinaryField1b, BinaryField8b, ExtensionField, Field,
 	PackedAESBinaryField64x8b, PackedField, PackedFieldIndexable, TowerField,
 };
-use binius_hash::Groestl256Core;
-use binius_math::CompositionPoly;
-use bytemuck::{must_cast_slice_mut, Pod};
+use binius_hash::groestl::arch::{
+	transpose_in, transpose_out, BitslicedState, Groestl256BitslicedCore, StateBytes,
+	BITSLICED_LANES,
+};
+use binius_math::{ArithExpr, CompositionPoly};
+use bytemuck::{must_cast_slice, must_cast_slice_mut, Pod};
 use itertools::chain;
 use rand::thread_rng;
-use std::{array, fmt::Debug, iter, slice};
+use std::{array, collections::HashMap, fmt::Debug, iter, slice};
 
 /// Number of rounds in a Grøstl-256 compression
 const N_ROUNDS: usize = 10;
 
 const STATE_SIZE: usize = 64;
 
+/// Size in bytes of a Grøstl-256 digest
+pub(crate) const DIGEST_SIZE: usize = 32;
+
 /// Constant vector of the Rijndael S-box affine transformation.
 const SBOX_VEC: AESTowerField8b = AESTowerField8b::new(0x63);
 /// Matrix columns of the Rijndael S-box affine transformation.
@@ -49,9 +54,36 @@ const MIX_BYTES_VEC: [AESTowerField8b; 8] = [
 	AESTowerField8b::new(0x07),
 ];
 
+/// One of the two permutations of Grøstl-256.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PermutationVariant {
+	P,
+	Q,
+}
+
+impl PermutationVariant {
+	/// The ShiftBytes offset of every row of the state.
+	fn shifts(self) -> [usize; 8] {
+		match self {
+			Self::P => [0, 1, 2, 3, 4, 5, 6, 7],
+			Self::Q => [1, 3, 5, 7, 0, 2, 4, 6],
+		}
+	}
+
+	/// Whether AddRoundConstant changes the state byte `ij`.
+	fn adds_round_const(self, ij: usize) -> bool {
+		match self {
+			Self::P => ij % 8 == 0,
+			Self::Q => true,
+		}
+	}
+}
+
 // TODO: Get rid of round constants and bake them into the constraints
 #[derive(Debug, Clone)]
 struct PermutationRoundGadget {
+	variant: PermutationVariant,
+
 	// Internal oracles (some may be duplicated from input)
 	with_round_consts: [OracleId; STATE_SIZE],
 
@@ -67,6 +99,7 @@ impl PermutationRoundGadget {
 	pub fn new<U, F, F8b>(
 		log_size: usize,
 		builder: &mut ConstraintSystemBuilder<U, F>,
+		variant: PermutationVariant,
 		round: OracleId,
 		multiples_16: &[OracleId],
 		input: [OracleId; STATE_SIZE],
@@ -76,24 +109,35 @@ impl PermutationRoundGadget {
 		F: TowerField + ExtensionField<F8b>,
 		F8b: TowerField + From<AESTowerField8b>,
 	{
-		let with_round_consts: [OracleId; STATE_SIZE] = array::from_fn(|i| {
-			if i % 8 == 0 {
-				let col_idx = i / 8;
-				builder
-					.add_linear_combination(
-						format!("with_round_consts[{i}]"),
-						log_size,
-						[
-							(input[i], F::ONE),
-							(round, F::ONE),
-							(multiples_16[col_idx], F::ONE),
-						],
-					)
-					.unwrap()
-			} else {
-				input[i]
-			}
-		});
+		// Q complements every byte and adds the round constant to the last row instead of the
+		// first one
+		let q_offset: F = F8b::from(AESTowerField8b::new(0xff)).into();
+		let mut with_round_consts = [OracleId::default(); STATE_SIZE];
+		for (ij, with_round_const) in with_round_consts.iter_mut().enumerate() {
+			let name = format!("with_round_consts[{ij}]");
+			let input_term = (input[ij], F::ONE);
+			let round_const_terms = [(round, F::ONE), (multiples_16[ij / 8], F::ONE)];
+			*with_round_const = match (variant, ij % 8) {
+				(PermutationVariant::P, 0) => builder.add_linear_combination(
+					name,
+					log_size,
+					chain!([input_term], round_const_terms),
+				)?,
+				(PermutationVariant::P, _) => input[ij],
+				(PermutationVariant::Q, 7) => builder.add_linear_combination_with_offset(
+					name,
+					log_size,
+					q_offset,
+					chain!([input_term], round_const_terms),
+				)?,
+				(PermutationVariant::Q, _) => builder.add_linear_combination_with_offset(
+					name,
+					log_size,
+					q_offset,
+					[input_term],
+				)?,
+			};
+		}
 
 		let p_sub_bytes = (0..STATE_SIZE)
 			.map(|i| {
@@ -109,6 +153,7 @@ impl PermutationRoundGadget {
 		let output = builder.add_committed_multiple("output", log_size, BinaryField8b::TOWER_LEVEL);
 
 		Ok(Self {
+			variant,
 			with_round_consts,
 			p_sub_bytes,
 			output,
@@ -125,6 +170,7 @@ impl PermutationRoundGadget {
 			.iter()
 			.for_each(|sub_bytes| sub_bytes.add_constraints::<U, F, F8b>(builder));
 
+		let shifts = self.variant.shifts();
 		self.p_sub_bytes.iter().enumerate().for_each(|(ij, _)| {
 			let i = ij / 8;
 			let j = ij % 8;
@@ -133,7 +179,7 @@ impl PermutationRoundGadget {
 			mix_shift_oracles[0] = self.output[ij];
 			for k in 0..8 {
 				let j_prime = (j + k) % 8;
-				let i_prime = (i + j_prime) % 8;
+				let i_prime = (i + shifts[j_prime]) % 8;
 				mix_shift_oracles[k + 1] = self.p_sub_bytes[i_prime * 8 + j_prime].output;
 			}
 
@@ -209,91 +255,80 @@ impl SBoxTraceGadget {
 	}
 }
 
+/// The constant columns added by AddRoundConstant, shared by all permutations of a circuit.
+///
+/// Build them once per trace size with [`RoundConstantOracles::new`] and pass them to every
+/// Grøstl gadget of the circuit.
+pub struct RoundConstantOracles {
+	// round indexes
+	round_idxs: [OracleId; N_ROUNDS],
+	// i * 0x10 from i = 0, ..., 7
+	multiples_16: [OracleId; 8],
+}
+
+impl RoundConstantOracles {
+	/// Adds the round constant columns of a trace of `2^log_size` rows.
+	pub fn new<U, F>(builder: &mut ConstraintSystemBuilder<U, F>, log_size: usize) -> Result<Self>
+	where
+		U: UnderlierType + Pod + PackScalar<F> + PackScalar<AESTowerField8b>,
+		F: TowerField + ExtensionField<AESTowerField8b>,
+	{
+		let mut round_idxs = [OracleId::default(); N_ROUNDS];
+		for (i, round_idx) in round_idxs.iter_mut().enumerate() {
+			*round_idx = add_constant_byte(builder, format!("round_idxs[{i}]"), log_size, i as u8)?;
+		}
+
+		let mut multiples_16 = [OracleId::default(); 8];
+		for (i, multiple_16) in multiples_16.iter_mut().enumerate() {
+			*multiple_16 =
+				add_constant_byte(builder, format!("multiples_16[{i}]"), log_size, i as u8 * 0x10)?;
+		}
+
+		Ok(Self {
+			round_idxs,
+			multiples_16,
+		})
+	}
+}
+
 struct TraceOracle {
 	// Public columns
-	/// permutation input state
-	p_in: [OracleId; STATE_SIZE],
 	/// permutation output state copied from last rounds output for simplicity
 	p_out: [OracleId; STATE_SIZE],
-	// round indexes, is actually a vec of length N_ROUNDS
-	round_idxs: Vec<OracleId>,
-	// i * 0x10 from i = 0, ..., 7
-	multiples_16: Vec<OracleId>,
 	rounds: Vec<PermutationRoundGadget>,
 }
 
 impl TraceOracle {
-	fn new<U, F, F8b>(builder: &mut ConstraintSystemBuilder<U, F>, log_size: usize) -> Result<Self>
+	fn new<U, F, F8b>(
+		builder: &mut ConstraintSystemBuilder<U, F>,
+		log_size: usize,
+		variant: PermutationVariant,
+		p_in: [OracleId; STATE_SIZE],
+		round_consts: &RoundConstantOracles,
+	) -> Result<Self>
 	where
 		U: UnderlierType + Pod + PackScalar<F> + PackScalar<BinaryField1b>,
 		F: TowerField + ExtensionField<F8b>,
 		F8b: TowerField + From<AESTowerField8b>,
 	{
-		let p_in = builder.add_committed_multiple::<STATE_SIZE>(
-			"p_in",
-			log_size,
-			BinaryField8b::TOWER_LEVEL,
-		);
-
-		let mut round_idxs = Vec::with_capacity(N_ROUNDS);
-		for i in 0..N_ROUNDS {
-			let val: F8b = AESTowerField8b::new(i as u8).into();
-			let val: F = val.into();
-			round_idxs.push(builder.add_transparent(
-				format!("round_idxs[{i}]"),
-				Constant {
-					n_vars: log_size,
-					value: val,
-				},
-			)?);
-		}
-
-		let mut multiples_16 = Vec::with_capacity(8);
-		for i in 0..8 {
-			let val: F8b = AESTowerField8b::new(i as u8 * 0x10).into();
-			let val: F = val.into();
-			multiples_16.push(builder.add_transparent(
-				format!("multiples_16[{i}]"),
-				Constant {
-					n_vars: log_size,
-					value: val,
-				},
-			)?);
-		}
-
 		let mut rounds: Vec<PermutationRoundGadget> = Vec::with_capacity(N_ROUNDS);
-
-		builder.push_namespace("rounds[0]");
-		rounds.push(PermutationRoundGadget::new::<U, F, F8b>(
-			log_size,
-			builder,
-			round_idxs[0],
-			&multiples_16,
-			p_in,
-		)?);
-		builder.pop_namespace();
-
-		for round in 1..N_ROUNDS {
+		for round in 0..N_ROUNDS {
+			let input = rounds.last().map_or(p_in, |prev_round| prev_round.output);
 			builder.push_namespace(format!("rounds[{round}]"));
 			rounds.push(PermutationRoundGadget::new::<U, F, F8b>(
 				log_size,
 				builder,
-				round_idxs[round],
-				&multiples_16,
-				rounds[round - 1].output,
+				variant,
+				round_consts.round_idxs[round],
+				&round_consts.multiples_16,
+				input,
 			)?);
 			builder.pop_namespace();
 		}
 
 		let p_out = rounds[N_ROUNDS - 1].output;
 
-		Ok(TraceOracle {
-			p_in,
-			p_out,
-			round_idxs,
-			multiples_16,
-			rounds,
-		})
+		Ok(TraceOracle { p_out, rounds })
 	}
 
 	pub fn add_constraints<U, F, F8b>(&self, builder: &mut ConstraintSystemBuilder<U, F>)
@@ -349,6 +384,14 @@ where
 	fn binary_tower_level(&self) -> usize {
 		AESTowerField8b::TOWER_LEVEL
 	}
+
+	fn expression(&self) -> Option<ArithExpr<P::Scalar>> {
+		let mix = iter::zip(1.., self.mix_bytes)
+			.map(|(i, coeff)| ArithExpr::Const(P::Scalar::from(coeff)) * ArithExpr::Var(i))
+			.reduce(|sum, term| sum + term)
+			.expect("mix_bytes is non-empty");
+		Some(mix - ArithExpr::Var(0))
+	}
 }
 
 #[derive(Debug, Clone)]
@@ -393,6 +436,18 @@ where
 	fn binary_tower_level(&self) -> usize {
 		4
 	}
+
+	fn expression(&self) -> Option<ArithExpr<F>> {
+		let x = ArithExpr::Var(0);
+		let inv = ArithExpr::Var(1);
+		// The constant that `mul_primitive(3)` multiplies by
+		let beta = F::ONE
+			.mul_primitive(3)
+			.expect("F must be tower height at least 4 by struct invariant");
+		let non_zero_case = x.clone() * inv.clone() - ArithExpr::Const(F::ONE);
+		let zero_case = x + ArithExpr::Const(beta) * inv;
+		Some(non_zero_case * zero_case)
+	}
 }
 
 struct PermutationRoundWitness<U>
@@ -418,20 +473,13 @@ where
 		F: TowerField + ExtensionField<F8b>,
 		F8b: TowerField + From<AESTowerField8b>,
 	{
-		index.set_owned::<F8b, Box<[U]>>(iter::zip(
-			chain!(gadget
-				.with_round_consts
-				.iter()
-				.enumerate()
-				.filter(|(i, _)| { i % 8 == 0 })
-				.map(|(_, &o)| o),),
-			chain!(self
-				.with_round_consts
-				.iter()
+		// Only the bytes changed by AddRoundConstant have oracles of their own
+		index.set_owned::<F8b, _>(
+			iter::zip(gadget.with_round_consts, &self.with_round_consts)
 				.enumerate()
-				.filter(|(i, _)| { i % 8 == 0 })
-				.map(|(_, o)| o.clone())),
-		))?;
+				.filter(|&(ij, _)| gadget.variant.adds_round_const(ij))
+				.map(|(_, (oracle, column))| (oracle, column.clone())),
+		)?;
 
 		// Update sbox here
 		for (p_sub_bytes, p_sub_bytes_witness) in
@@ -477,14 +525,11 @@ where
 	}
 }
 
-struct TraceWitness<U>
+pub(crate) struct TraceWitness<U>
 where
 	U: UnderlierType + PackScalar<BinaryField1b>,
 {
-	p_in: [Box<[U]>; STATE_SIZE],
-	_p_out: [Box<[U]>; STATE_SIZE],
-	round_idxs: [Box<[U]>; N_ROUNDS],
-	multiples_16: [Box<[U]>; 8],
+	p_out: [Box<[U]>; STATE_SIZE],
 	rounds: [PermutationRoundWitness<U>; N_ROUNDS],
 }
 
@@ -502,14 +547,6 @@ where
 		F: TowerField + ExtensionField<F8b>,
 		F8b: TowerField + From<AESTowerField8b>,
 	{
-		witness.set_owned::<F8b, _>(iter::zip(
-			chain!(
-				trace_oracle.p_in,
-				trace_oracle.round_idxs.clone(),
-				trace_oracle.multiples_16.clone(),
-			),
-			chain!(self.p_in.clone(), self.round_idxs.clone(), self.multiples_16.clone(),),
-		))?;
 		for (permutation_round, permutation_round_witness) in
 			iter::zip(trace_oracle.rounds.clone(), self.rounds.iter())
 		{
@@ -519,46 +556,6 @@ where
 	}
 }
 
-fn s_box(x: AESTowerField8b) -> AESTowerField8b {
-	#[rustfmt::skip]
-	const S_BOX: [u8; 256] = [
-		0x63, 0x7c, 0x77, 0x7b, 0xf2, 0x6b, 0x6f, 0xc5,
-		0x30, 0x01, 0x67, 0x2b, 0xfe, 0xd7, 0xab, 0x76,
-		0xca, 0x82, 0xc9, 0x7d, 0xfa, 0x59, 0x47, 0xf0,
-		0xad, 0xd4, 0xa2, 0xaf, 0x9c, 0xa4, 0x72, 0xc0,
-		0xb7, 0xfd, 0x93, 0x26, 0x36, 0x3f, 0xf7, 0xcc,
-		0x34, 0xa5, 0xe5, 0xf1, 0x71, 0xd8, 0x31, 0x15,
-		0x04, 0xc7, 0x23, 0xc3, 0x18, 0x96, 0x05, 0x9a,
-		0x07, 0x12, 0x80, 0xe2, 0xeb, 0x27, 0xb2, 0x75,
-		0x09, 0x83, 0x2c, 0x1a, 0x1b, 0x6e, 0x5a, 0xa0,
-		0x52, 0x3b, 0xd6, 0xb3, 0x29, 0xe3, 0x2f, 0x84,
-		0x53, 0xd1, 0x00, 0xed, 0x20, 0xfc, 0xb1, 0x5b,
-		0x6a, 0xcb, 0xbe, 0x39, 0x4a, 0x4c, 0x58, 0xcf,
-		0xd0, 0xef, 0xaa, 0xfb, 0x43, 0x4d, 0x33, 0x85,
-		0x45, 0xf9, 0x02, 0x7f, 0x50, 0x3c, 0x9f, 0xa8,
-		0x51, 0xa3, 0x40, 0x8f, 0x92, 0x9d, 0x38, 0xf5,
-		0xbc, 0xb6, 0xda, 0x21, 0x10, 0xff, 0xf3, 0xd2,
-		0xcd, 0x0c, 0x13, 0xec, 0x5f, 0x97, 0x44, 0x17,
-		0xc4, 0xa7, 0x7e, 0x3d, 0x64, 0x5d, 0x19, 0x73,
-		0x60, 0x81, 0x4f, 0xdc, 0x22, 0x2a, 0x90, 0x88,
-		0x46, 0xee, 0xb8, 0x14, 0xde, 0x5e, 0x0b, 0xdb,
-		0xe0, 0x32, 0x3a, 0x0a, 0x49, 0x06, 0x24, 0x5c,
-		0xc2, 0xd3, 0xac, 0x62, 0x91, 0x95, 0xe4, 0x79,
-		0xe7, 0xc8, 0x37, 0x6d, 0x8d, 0xd5, 0x4e, 0xa9,
-		0x6c, 0x56, 0xf4, 0xea, 0x65, 0x7a, 0xae, 0x08,
-		0xba, 0x78, 0x25, 0x2e, 0x1c, 0xa6, 0xb4, 0xc6,
-		0xe8, 0xdd, 0x74, 0x1f, 0x4b, 0xbd, 0x8b, 0x8a,
-		0x70, 0x3e, 0xb5, 0x66, 0x48, 0x03, 0xf6, 0x0e,
-		0x61, 0x35, 0x57, 0xb9, 0x86, 0xc1, 0x1d, 0x9e,
-		0xe1, 0xf8, 0x98, 0x11, 0x69, 0xd9, 0x8e, 0x94,
-		0x9b, 0x1e, 0x87, 0xe9, 0xce, 0x55, 0x28, 0xdf,
-		0x8c, 0xa1, 0x89, 0x0d, 0xbf, 0xe6, 0x42, 0x68,
-		0x41, 0x99, 0x2d, 0x0f, 0xb0, 0x54, 0xbb, 0x16,
-	];
-	let idx = u8::from(x) as usize;
-	AESTowerField8b::from(S_BOX[idx])
-}
-
 impl<U> TraceWitness<U>
 where
 	U: UnderlierType
@@ -567,15 +564,26 @@ where
 		+ PackScalar<AESTowerField8b>
 		+ Divisible<u8>,
 {
-	pub fn generate_trace(log_size: usize) -> Self {
+	/// Generates the trace of the P permutation on the given inputs, one per row.
+	pub fn generate_trace_for_inputs(
+		log_size: usize,
+		inputs: &[PackedAESBinaryField64x8b],
+	) -> Self {
+		Self::generate_permutation_trace(PermutationVariant::P, log_size, inputs)
+	}
+
+	/// Generates the trace of the permutation `variant` on the given inputs, one per row.
+	fn generate_permutation_trace(
+		variant: PermutationVariant,
+		log_size: usize,
+		inputs: &[PackedAESBinaryField64x8b],
+	) -> Self {
+		assert_eq!(inputs.len(), 1 << log_size, "there must be exactly one input per row");
+
 		let build_trace_column_1b =
 			|| vec![U::default(); 1 << (log_size - <PackedType<U, BinaryField1b>>::LOG_WIDTH)];
-		let build_trace_column_8b =
-			|| vec![U::default(); 1 << (log_size - <PackedType<U, AESTowerField8b>>::LOG_WIDTH)];
+		let build_trace_column_8b = || new_column_8b::<U>(log_size);
 
-		let mut p_in_vec: [Vec<U>; STATE_SIZE] = array::from_fn(|_| build_trace_column_8b());
-		let mut round_idxs_vec: [Vec<U>; N_ROUNDS] = array::from_fn(|_| build_trace_column_8b());
-		let mut multiples_16_vec: [Vec<U>; 8] = array::from_fn(|_| build_trace_column_8b());
 		let mut round_outs_vec: [[Vec<U>; STATE_SIZE]; N_ROUNDS] =
 			array::from_fn(|_| array::from_fn(|_| build_trace_column_8b()));
 		let mut sub_bytes_out_vec: [[Vec<U>; STATE_SIZE]; N_ROUNDS] =
@@ -587,149 +595,71 @@ where
 		let mut with_round_consts_vec: [[Vec<U>; STATE_SIZE]; N_ROUNDS] =
 			array::from_fn(|_| array::from_fn(|_| build_trace_column_8b()));
 
-		#[allow(clippy::ptr_arg)]
-		fn cast_8b_col<U: UnderlierType + Pod>(col: &mut Vec<U>) -> &mut [AESTowerField8b] {
-			must_cast_slice_mut::<_, AESTowerField8b>(col)
-		}
-
-		fn cast_8b_cols<U: UnderlierType + Pod, const N: usize>(
-			cols: &mut [Vec<U>; N],
-		) -> [&mut [AESTowerField8b]; N] {
-			cols.each_mut().map(cast_8b_col) // |col| PackedFieldIndexable::unpack_scalars_mut(col.as_mut_slice()))
-		}
-
-		let p_in = cast_8b_cols(&mut p_in_vec);
-		let round_idxs = cast_8b_cols(&mut round_idxs_vec);
-		let multiples_16 = cast_8b_cols(&mut multiples_16_vec);
-
-		let mut rng = thread_rng();
-		let groestl_core = Groestl256Core;
-
-		for z in 0..1 << log_size {
-			// Randomly generate the initial compression input
-			let input = PackedAESBinaryField64x8b::random(&mut rng);
-			let output = groestl_core.permutation_p(input);
-
-			#[allow(clippy::needless_range_loop)]
-			for i in 0..8 {
-				multiples_16[i][z] = AESTowerField8b::new(i as u8 * 0x10);
-			}
-
-			// Assign the compression input
-			for ij in 0..STATE_SIZE {
-				let input_elems = PackedFieldIndexable::unpack_scalars(slice::from_ref(&input));
-				p_in[ij][z] = input_elems[ij];
-			}
-
-			let mut prev_round_out = input;
-
-			for r in 0..N_ROUNDS {
-				let with_round_consts = cast_8b_cols(&mut with_round_consts_vec[r]);
-				let round_out = cast_8b_cols(&mut round_outs_vec[r]);
-
-				round_idxs[r][z] = AESTowerField8b::new(r as u8);
-
-				// AddRoundConstant & SubBytes
-				#[allow(clippy::needless_range_loop)]
-				for i in 0..8 {
-					for j in 0..8 {
-						let ij = i * 8 + j;
-						let p_sub_bytes_inv = cast_8b_col(&mut sub_bytes_inv_vec[r][ij]);
-						let p_sub_bytes_out = cast_8b_col(&mut sub_bytes_out_vec[r][ij]);
-
-						let round_in =
-							PackedFieldIndexable::unpack_scalars(slice::from_ref(&prev_round_out))
-								[ij];
-
-						let with_rc = if j == 0 {
-							round_in + round_idxs[r][z] + multiples_16[i][z]
-						} else {
-							round_in
-						};
-
-						with_round_consts[ij][z] = with_rc;
-
-						let p_sbox_in: AESTowerField8b = with_rc;
-
-						p_sub_bytes_inv[z] = p_sbox_in.invert_or_zero();
-
-						let inv_bits =
-							<AESTowerField8b as ExtensionField<BinaryField1b>>::iter_bases(
-								&p_sub_bytes_inv[z],
-							);
-						for (b, bit) in inv_bits.enumerate() {
-							let p_sub_bytes_inv_bit =
-								sub_bytes_inv_bits_vec[r][ij][b].as_mut_slice();
-
-							let as_packed = PackedType::<U, BinaryField1b>::from_underliers_ref_mut(
-								p_sub_bytes_inv_bit,
-							);
-							set_packed_slice(as_packed, z, bit);
-						}
-
-						p_sub_bytes_out[z] = s_box(p_sbox_in);
-					}
-				}
-
-				// ShiftBytes & MixBytes
-				fn shift_p_func(j: usize, i: usize) -> usize {
-					let i_prime = (i + j) % 8;
-					i_prime * 8 + j
+		/// Writes the bytes of the first `n_lanes` lanes of `state` to the rows of `cols` starting
+		/// at `offset`
+		fn write_lanes<U: UnderlierType + Pod>(
+			cols: &mut [Vec<U>; STATE_SIZE],
+			state: &BitslicedState,
+			offset: usize,
+			n_lanes: usize,
+		) {
+			let lanes = transpose_out(state);
+			for (ij, col) in cols.iter_mut().enumerate() {
+				let col = must_cast_slice_mut::<_, AESTowerField8b>(col);
+				for (lane, bytes) in lanes[..n_lanes].iter().enumerate() {
+					col[offset + lane] = AESTowerField8b::new(bytes[ij]);
 				}
+			}
+		}
 
-				fn get_a_j<U>(
-					p_sub_bytes: &[Vec<U>; STATE_SIZE],
-					z: usize,
-					j: usize,
-				) -> [AESTowerField8b; 8]
-				where
-					U: UnderlierType + PackScalar<BinaryField1b> + PackScalar<AESTowerField8b>,
-					PackedType<U, AESTowerField8b>: PackedFieldIndexable,
-				{
-					array::from_fn(|i| {
-						let x = p_sub_bytes[shift_p_func(i, j)].as_slice();
-						let x_as_packed = PackedType::<U, AESTowerField8b>::from_underliers_ref(x);
-						get_packed_slice(x_as_packed, z)
-					})
-				}
-				let two = AESTowerField8b::new(2);
-				for j in 0..8 {
-					let a_j = get_a_j::<U>(&sub_bytes_out_vec[r], z, j);
-					for i in 0..8 {
-						let prev_round_out_slice = PackedFieldIndexable::unpack_scalars_mut(
-							slice::from_mut(&mut prev_round_out),
-						);
-						let ij = j * 8 + i;
-						let a_i: [AESTowerField8b; 8] = array::from_fn(|k| a_j[(i + k) % 8]);
-						// Here we are using an optimized matrix multiplication, as documented in
-						// section 4.4.2 of https://www.groestl.info/groestl-implementation-guide.pdf
-						let b_ij = two
-							* (two * (a_i[3] + a_i[4] + a_i[6] + a_i[7])
-								+ a_i[0] + a_i[1] + a_i[2]
-								+ a_i[5] + a_i[7]) + a_i[2]
-							+ a_i[4] + a_i[5] + a_i[6]
-							+ a_i[7];
-						round_out[ij][z] = b_ij;
-						prev_round_out_slice[ij] = b_ij;
+		// The permutation is evaluated on BITSLICED_LANES rows at once, so the bit planes of the
+		// bitsliced states are exactly the chunks of the bit-decomposed columns.
+		let groestl_core = Groestl256BitslicedCore;
+
+		for (chunk, chunk_inputs) in inputs.chunks(BITSLICED_LANES).enumerate() {
+			let offset = chunk * BITSLICED_LANES;
+			let n_lanes = chunk_inputs.len();
+
+			let lanes: [StateBytes; BITSLICED_LANES] = array::from_fn(|lane| {
+				chunk_inputs
+					.get(lane)
+					.map_or([0; STATE_SIZE], |input| array::from_fn(|ij| u8::from(input.get(ij))))
+			});
+			let mut state = transpose_in(&lanes);
+			let round_traces = match variant {
+				PermutationVariant::P => groestl_core.permutation_p_trace(&mut state),
+				PermutationVariant::Q => groestl_core.permutation_q_trace(&mut state),
+			};
+
+			let lane_mask = u64::MAX >> (BITSLICED_LANES - n_lanes);
+			for (r, round_trace) in round_traces.iter().enumerate() {
+				write_lanes(
+					&mut with_round_consts_vec[r],
+					&round_trace.with_round_consts,
+					offset,
+					n_lanes,
+				);
+				write_lanes(&mut sub_bytes_inv_vec[r], &round_trace.sbox_inverse, offset, n_lanes);
+				write_lanes(&mut sub_bytes_out_vec[r], &round_trace.sub_bytes, offset, n_lanes);
+				write_lanes(&mut round_outs_vec[r], &round_trace.output, offset, n_lanes);
+
+				for (ij, inverse) in round_trace.sbox_inverse.iter().enumerate() {
+					for (b, plane) in inverse.iter().enumerate() {
+						let bytes =
+							must_cast_slice_mut::<_, u8>(&mut sub_bytes_inv_bits_vec[r][ij][b]);
+						let start = offset / 8;
+						let len = (bytes.len() - start).min(8);
+						bytes[start..start + len]
+							.copy_from_slice(&(plane & lane_mask).to_le_bytes()[..len]);
 					}
 				}
 			}
-
-			// Assert correct output
-			for ij in 0..STATE_SIZE {
-				let output_elems = PackedFieldIndexable::unpack_scalars(slice::from_ref(&output));
-				let perm_out = cast_8b_cols(&mut round_outs_vec[N_ROUNDS - 1]);
-				assert_eq!(perm_out[ij][z], output_elems[ij]);
-			}
 		}
 
 		fn vec_to_arc<U: UnderlierType, const N: usize>(cols: [Vec<U>; N]) -> [Box<[U]>; N] {
 			cols.map(|x| x.into_boxed_slice())
 		}
 
-		let p_in_arc = vec_to_arc(p_in_vec);
-		let round_idxs_arc = vec_to_arc(round_idxs_vec);
-		let multiples_16_arc = vec_to_arc(multiples_16_vec);
 		let round_outs_arc: [[Box<[U]>; STATE_SIZE]; N_ROUNDS] =
 			round_outs_vec.map(|r| vec_to_arc(r));
 		let sub_bytes_out_arc: [[Box<[U]>; STATE_SIZE]; N_ROUNDS] =
@@ -742,20 +672,9 @@ where
 			with_round_consts_vec.map(|r| vec_to_arc(r));
 
 		TraceWitness {
-			p_in: p_in_arc.clone(),
-			_p_out: array::from_fn(|ij| round_outs_arc[N_ROUNDS - 1][ij].clone()),
-			round_idxs: round_idxs_arc,
-			multiples_16: multiples_16_arc,
+			p_out: array::from_fn(|ij| round_outs_arc[N_ROUNDS - 1][ij].clone()),
 			rounds: array::from_fn(|r| PermutationRoundWitness {
-				with_round_consts: array::from_fn(|ij| {
-					if ij % 8 == 0 {
-						with_round_consts_arc[r][ij].clone()
-					} else if r == 0 {
-						p_in_arc[ij].clone()
-					} else {
-						round_outs_arc[r][ij].clone()
-					}
-				}),
+				with_round_consts: with_round_consts_arc[r].clone(),
 				output: round_outs_arc[r].clone(),
 				p_sub_bytes: array::from_fn(|ij| SBoxGadgetWitness {
 					output: sub_bytes_out_arc[r][ij].clone(),
@@ -767,9 +686,140 @@ where
 	}
 }
 
-pub fn groestl_p_permutation<U, F>(
+impl<U> TraceWitness<U>
+where
+	U: UnderlierType + Pod + PackScalar<BinaryField1b>,
+{
+	/// The output state of the permutation in row `z`.
+	pub fn p_out_state(&self, z: usize) -> [AESTowerField8b; STATE_SIZE] {
+		array::from_fn(|ij| must_cast_slice::<_, AESTowerField8b>(&self.p_out[ij])[z])
+	}
+}
+
+/// Allocates a column of `2^log_size` zero bytes
+pub(crate) fn new_column_8b<U>(log_size: usize) -> Vec<U>
+where
+	U: UnderlierType + PackScalar<AESTowerField8b>,
+{
+	vec![U::default(); 1 << (log_size - <PackedType<U, AESTowerField8b>>::LOG_WIDTH)]
+}
+
+/// Adds a transparent column holding `value` in every row, along with its witness.
+pub(crate) fn add_constant_byte<U, F>(
 	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
 	log_size: usize,
+	value: u8,
+) -> Result<OracleId>
+where
+	U: UnderlierType + Pod + PackScalar<F> + PackScalar<AESTowerField8b>,
+	F: TowerField + ExtensionField<AESTowerField8b>,
+{
+	let value = AESTowerField8b::new(value);
+	let oracle = builder.add_transparent(
+		name,
+		Constant {
+			n_vars: log_size,
+			value: F::from(value),
+		},
+	)?;
+
+	if let Some(witness) = builder.witness() {
+		let mut column = new_column_8b::<U>(log_size);
+		must_cast_slice_mut::<_, AESTowerField8b>(&mut column).fill(value);
+		witness.set_owned::<AESTowerField8b, _>([(oracle, column)])?;
+	}
+
+	Ok(oracle)
+}
+
+/// Splits one state per row into the columns of the state bytes.
+fn state_columns<U>(log_size: usize, states: &[PackedAESBinaryField64x8b]) -> [Vec<U>; STATE_SIZE]
+where
+	U: UnderlierType + Pod + PackScalar<AESTowerField8b>,
+{
+	let mut columns: [Vec<U>; STATE_SIZE] = array::from_fn(|_| new_column_8b(log_size));
+	for (z, state) in states.iter().enumerate() {
+		let state = PackedFieldIndexable::unpack_scalars(slice::from_ref(state));
+		for (column, &byte) in columns.iter_mut().zip(state) {
+			must_cast_slice_mut::<_, AESTowerField8b>(column)[z] = byte;
+		}
+	}
+	columns
+}
+
+/// Reads the state of every row from the witness of the state byte columns.
+fn read_states<U, F>(
+	witness: &MultilinearExtensionIndex<U, F>,
+	log_size: usize,
+	state: &[OracleId; STATE_SIZE],
+) -> Result<Vec<PackedAESBinaryField64x8b>>
+where
+	U: UnderlierType + Pod + PackScalar<F> + PackScalar<AESTowerField8b>,
+	F: TowerField + ExtensionField<AESTowerField8b>,
+{
+	let mut states = vec![PackedAESBinaryField64x8b::default(); 1 << log_size];
+	let bytes = PackedFieldIndexable::unpack_scalars_mut(states.as_mut_slice());
+	for (ij, &oracle) in state.iter().enumerate() {
+		let column = witness.get::<AESTowerField8b>(oracle)?;
+		let column =
+			must_cast_slice::<_, AESTowerField8b>(WithUnderlier::to_underliers_ref(column.evals()));
+		for (row, &byte) in bytes.chunks_exact_mut(STATE_SIZE).zip(column) {
+			row[ij] = byte;
+		}
+	}
+	Ok(states)
+}
+
+/// Adds the byte-wise sums of the states `terms`, along with their witness.
+fn add_state_sum<U, F, const N: usize>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: &str,
+	log_size: usize,
+	terms: &[[OracleId; N]],
+) -> Result<[OracleId; N]>
+where
+	U: UnderlierType + Pod + PackScalar<F> + PackScalar<AESTowerField8b>,
+	F: TowerField + ExtensionField<AESTowerField8b>,
+{
+	let mut sum = [OracleId::default(); N];
+	for (i, sum_i) in sum.iter_mut().enumerate() {
+		*sum_i = builder.add_linear_combination(
+			format!("{name}[{i}]"),
+			log_size,
+			terms.iter().map(|term| (term[i], F::ONE)),
+		)?;
+	}
+
+	if let Some(witness) = builder.witness() {
+		let mut sum_witness = Vec::with_capacity(N);
+		for i in 0..N {
+			let mut column = new_column_8b::<U>(log_size);
+			let sum_bytes = must_cast_slice_mut::<_, AESTowerField8b>(&mut column);
+			for term in terms {
+				let term_column = witness.get::<AESTowerField8b>(term[i])?;
+				let term_bytes = must_cast_slice::<_, AESTowerField8b>(
+					WithUnderlier::to_underliers_ref(term_column.evals()),
+				);
+				for (x, &y) in sum_bytes.iter_mut().zip(term_bytes) {
+					*x += y;
+				}
+			}
+			sum_witness.push(column);
+		}
+		witness.set_owned::<AESTowerField8b, _>(iter::zip(sum, sum_witness))?;
+	}
+
+	Ok(sum)
+}
+
+/// The permutation `variant` of the states in `input`, one state per row.
+fn permutation<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	log_size: usize,
+	variant: PermutationVariant,
+	input: [OracleId; STATE_SIZE],
+	round_consts: &RoundConstantOracles,
 ) -> Result<[OracleId; STATE_SIZE]>
 where
 	U: UnderlierType
@@ -780,10 +830,12 @@ where
 		+ Divisible<u8>,
 	F: TowerField + ExtensionField<AESTowerField8b>,
 {
-	let trace_oracle = TraceOracle::new::<U, F, AESTowerField8b>(builder, log_size)?;
+	let trace_oracle =
+		TraceOracle::new::<U, F, AESTowerField8b>(builder, log_size, variant, input, round_consts)?;
 
 	if let Some(ext_index) = builder.witness() {
-		let trace_witness = TraceWitness::generate_trace(log_size);
+		let inputs = read_states(ext_index, log_size, &input)?;
+		let trace_witness = TraceWitness::generate_permutation_trace(variant, log_size, &inputs);
 		trace_witness.update_index::<F, AESTowerField8b>(&trace_oracle, ext_index)?;
 	}
 
@@ -791,3 +843,212 @@ where
 
 	Ok(trace_oracle.p_out)
 }
+
+/// The compression function `f(h, m) = P(h ⊕ m) ⊕ Q(m) ⊕ h`
+fn compression<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	log_size: usize,
+	h: [OracleId; STATE_SIZE],
+	m: [OracleId; STATE_SIZE],
+	round_consts: &RoundConstantOracles,
+) -> Result<[OracleId; STATE_SIZE]>
+where
+	U: UnderlierType
+		+ Pod
+		+ PackScalar<F>
+		+ PackScalar<BinaryField1b>
+		+ PackScalar<AESTowerField8b>
+		+ Divisible<u8>,
+	F: TowerField + ExtensionField<AESTowerField8b>,
+{
+	let p_in = add_state_sum(builder, "p_in", log_size, &[h, m])?;
+
+	builder.push_namespace("p");
+	let p_out = permutation(builder, log_size, PermutationVariant::P, p_in, round_consts)?;
+	builder.pop_namespace();
+
+	builder.push_namespace("q");
+	let q_out = permutation(builder, log_size, PermutationVariant::Q, m, round_consts)?;
+	builder.pop_namespace();
+
+	add_state_sum(builder, "output", log_size, &[p_out, q_out, h])
+}
+
+/// The output transformation `trunc_256(P(x) ⊕ x)`
+fn output_transform<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	log_size: usize,
+	x: [OracleId; STATE_SIZE],
+	round_consts: &RoundConstantOracles,
+) -> Result<[OracleId; DIGEST_SIZE]>
+where
+	U: UnderlierType
+		+ Pod
+		+ PackScalar<F>
+		+ PackScalar<BinaryField1b>
+		+ PackScalar<AESTowerField8b>
+		+ Divisible<u8>,
+	F: TowerField + ExtensionField<AESTowerField8b>,
+{
+	builder.push_namespace("p");
+	let p_out = permutation(builder, log_size, PermutationVariant::P, x, round_consts)?;
+	builder.pop_namespace();
+
+	let truncate = |state: [OracleId; STATE_SIZE]| -> [OracleId; DIGEST_SIZE] {
+		array::from_fn(|i| state[STATE_SIZE - DIGEST_SIZE + i])
+	};
+	add_state_sum(builder, "output", log_size, &[truncate(p_out), truncate(x)])
+}
+
+/// The P permutation of committed random states, one state per row.
+pub fn groestl_p_permutation<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	log_size: usize,
+) -> Result<[OracleId; STATE_SIZE]>
+where
+	U: UnderlierType
+		+ Pod
+		+ PackScalar<F>
+		+ PackScalar<BinaryField1b>
+		+ PackScalar<AESTowerField8b>
+		+ Divisible<u8>,
+	F: TowerField + ExtensionField<AESTowerField8b>,
+{
+	let p_in =
+		builder.add_committed_multiple::<STATE_SIZE>("p_in", log_size, BinaryField8b::TOWER_LEVEL);
+
+	if let Some(ext_index) = builder.witness() {
+		let mut rng = thread_rng();
+		let inputs = iter::repeat_with(|| PackedAESBinaryField64x8b::random(&mut rng))
+			.take(1 << log_size)
+			.collect::<Vec<_>>();
+		ext_index.set_owned::<AESTowerField8b, _>(iter::zip(
+			p_in,
+			state_columns::<U>(log_size, &inputs),
+		))?;
+	}
+
+	let round_consts = RoundConstantOracles::new(builder, log_size)?;
+	permutation(builder, log_size, PermutationVariant::P, p_in, &round_consts)
+}
+
+/// The Grøstl-256 compression function `f(h, m) = P(h ⊕ m) ⊕ Q(m) ⊕ h` of a chaining value `h` and
+/// a message block `m` in every row.
+pub fn groestl_compression<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	log_size: usize,
+	h: [OracleId; STATE_SIZE],
+	m: [OracleId; STATE_SIZE],
+	round_consts: &RoundConstantOracles,
+) -> Result<[OracleId; STATE_SIZE]>
+where
+	U: UnderlierType
+		+ Pod
+		+ PackScalar<F>
+		+ PackScalar<BinaryField1b>
+		+ PackScalar<AESTowerField8b>
+		+ Divisible<u8>,
+	F: TowerField + ExtensionField<AESTowerField8b>,
+{
+	builder.push_namespace(name);
+	let output = compression(builder, log_size, h, m, round_consts)?;
+	builder.pop_namespace();
+	Ok(output)
+}
+
+/// The Grøstl-256 output transformation `trunc_256(P(x) ⊕ x)` of the state `x` in every row.
+///
+/// Applied to the concatenation of two digests, this is the 2-to-1 compression of
+/// [`binius_hash::GroestlDigestCompression`].
+pub fn groestl_output_transform<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	log_size: usize,
+	x: [OracleId; STATE_SIZE],
+	round_consts: &RoundConstantOracles,
+) -> Result<[OracleId; DIGEST_SIZE]>
+where
+	U: UnderlierType
+		+ Pod
+		+ PackScalar<F>
+		+ PackScalar<BinaryField1b>
+		+ PackScalar<AESTowerField8b>
+		+ Divisible<u8>,
+	F: TowerField + ExtensionField<AESTowerField8b>,
+{
+	builder.push_namespace(name);
+	let output = output_transform(builder, log_size, x, round_consts)?;
+	builder.pop_namespace();
+	Ok(output)
+}
+
+/// The Grøstl-256 digest of the message in every row, whose bytes are the columns `message`.
+///
+/// The message length is fixed by the circuit, so the padding and the initial chaining value are
+/// constant columns.
+pub fn groestl256<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	log_size: usize,
+	message: &[OracleId],
+	round_consts: &RoundConstantOracles,
+) -> Result<[OracleId; DIGEST_SIZE]>
+where
+	U: UnderlierType
+		+ Pod
+		+ PackScalar<F>
+		+ PackScalar<BinaryField1b>
+		+ PackScalar<AESTowerField8b>
+		+ Divisible<u8>,
+	F: TowerField + ExtensionField<AESTowerField8b>,
+{
+	builder.push_namespace(name);
+
+	let mut constants = HashMap::new();
+	let mut constant =
+		|builder: &mut ConstraintSystemBuilder<U, F>, value: u8| -> Result<OracleId> {
+			if let Some(&oracle) = constants.get(&value) {
+				return Ok(oracle);
+			}
+			let oracle =
+				add_constant_byte(builder, format!("constants[{value:#04x}]"), log_size, value)?;
+			constants.insert(value, oracle);
+			Ok(oracle)
+		};
+
+	// The message is followed by 0x80, zeros and the number of blocks as a 64-bit big-endian
+	// integer, which must fit in the last block
+	let n_blocks = (message.len() + 9).div_ceil(STATE_SIZE);
+	let mut padding = vec![0u8; n_blocks * STATE_SIZE - message.len()];
+	padding[0] = 0x80;
+	let n_padding = padding.len();
+	padding[n_padding - 8..].copy_from_slice(&(n_blocks as u64).to_be_bytes());
+
+	let mut blocks = message.to_vec();
+	for &byte in &padding {
+		blocks.push(constant(builder, byte)?);
+	}
+
+	// The initial value encodes the digest length in bits
+	let mut iv = [0u8; STATE_SIZE];
+	iv[STATE_SIZE - 2..].copy_from_slice(&((8 * DIGEST_SIZE) as u16).to_be_bytes());
+	let mut h = [OracleId::default(); STATE_SIZE];
+	for (h_ij, &byte) in h.iter_mut().zip(&iv) {
+		*h_ij = constant(builder, byte)?;
+	}
+
+	for (i, block) in blocks.chunks_exact(STATE_SIZE).enumerate() {
+		let m = block.try_into().expect("chunks have the size of a block");
+		builder.push_namespace(format!("blocks[{i}]"));
+		h = compression(builder, log_size, h, m, round_consts)?;
+		builder.pop_namespace();
+	}
+
+	builder.push_namespace("output_transform");
+	let digest = output_transform(builder, log_size, h, round_consts)?;
+	builder.pop_namespace();
+
+	builder.pop_namespace();
+	Ok(digest)
+}
@@ -5,21 +5,60 @@ pub mod builder;
 pub mod groestl;
 pub mod keccakf;
 pub mod lasso;
+pub mod merkle_path;
+pub mod sha256;
 pub mod step_down;
 pub mod u32add;
 pub mod u32fib;
 pub mod unconstrained;
+pub mod vision;
 
 #[cfg(test)]
 mod tests {
 	use crate::{
-		bitwise, builder::ConstraintSystemBuilder, groestl::groestl_p_permutation,
-		keccakf::keccakf, lasso, u32add::u32add, u32fib::u32fib, unconstrained::unconstrained,
+		bitwise,
+		builder::ConstraintSystemBuilder,
+		groestl::{
+			add_constant_byte, groestl256, groestl_p_permutation, RoundConstantOracles,
+			TraceWitness, DIGEST_SIZE,
+		},
+		keccakf::keccakf,
+		lasso,
+		merkle_path::{groestl_merkle_path, MerklePath},
+		sha256::sha256,
+		u32add::u32add,
+		u32fib::u32fib,
+		unconstrained::unconstrained,
+		vision::{vision32b_permutation, vision32b_permutation_for_inputs},
+	};
+	use binius_core::{
+		constraint_system::{self, validate::validate_witness, ConstraintSystem, Proof},
+		fiat_shamir::HasherChallenger,
+		merkle_tree_vcs::BinaryMerkleTree,
+		oracle::OracleId,
+		tower::CanonicalTowerFamily,
+		transcript,
 	};
-	use binius_core::constraint_system::validate::validate_witness;
 	use binius_field::{
-		arch::OptimalUnderlier, AESTowerField16b, BinaryField128b, BinaryField1b, BinaryField8b,
+		arch::{OptimalUnderlier, OptimalUnderlier128b},
+		as_packed_field::PackedType,
+		underlier::WithUnderlier,
+		AESTowerField16b, AESTowerField8b, BinaryField128b, BinaryField1b, BinaryField32b,
+		BinaryField8b, Field, PackedAESBinaryField64x8b, PackedField, TowerField,
+	};
+	use binius_hal::make_portable_backend;
+	use binius_hash::{
+		BsGroestl256Hasher, BsGroestlDigestCompression, Groestl256, GroestlDigest,
+		GroestlDigestCompression, GroestlHasher, HashDigest, HasherDigest, Keccak256,
+		Keccak256Digest, Keccak256DigestCompression, Vision32bDigestCompression,
+		Vision32bLeafHasher, Vision32bPermutation,
 	};
+	use binius_math::DefaultEvaluationDomainFactory;
+	use binius_utils::serialization::{DeserializeBytes, SerializeBytes};
+	use bytemuck::{must_cast_slice, must_cast_slice_mut};
+	use p3_symmetric::Permutation;
+	use rand::thread_rng;
+	use std::{array, iter::repeat_with};
 
 	type U = OptimalUnderlier;
 	type F = BinaryField128b;
@@ -83,6 +122,236 @@ mod tests {
 		validate_witness(&constraint_system, boundaries, witness).unwrap();
 	}
 
+	#[test]
+	fn test_constraint_system_serialization() {
+		let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness();
+		let log_size = 14;
+		let a = unconstrained::<_, _, BinaryField1b>(&mut builder, "a", log_size).unwrap();
+		let b = unconstrained::<_, _, BinaryField1b>(&mut builder, "b", log_size).unwrap();
+		let _c = u32add(&mut builder, "u32add", log_size, a, b).unwrap();
+		let _and = bitwise::and(&mut builder, "and", log_size, a, b).unwrap();
+		let _ = u32fib(&mut builder, "u32fib", log_size).unwrap();
+
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+
+		let mut buf = Vec::new();
+		constraint_system.serialize(&mut buf).unwrap();
+		let mut read_buf = buf.as_slice();
+		let deserialized = ConstraintSystem::deserialize(&mut read_buf).unwrap();
+		assert!(read_buf.is_empty());
+
+		// The encoding is canonical, so the deserialized system encodes to the same bytes
+		let mut reserialized = Vec::new();
+		deserialized.serialize(&mut reserialized).unwrap();
+		assert_eq!(reserialized, buf);
+
+		let boundaries = vec![];
+		validate_witness(&deserialized, boundaries, witness).unwrap();
+	}
+
+	#[test]
+	fn test_groestl_constraint_system_serialization() {
+		let mut builder =
+			ConstraintSystemBuilder::<OptimalUnderlier, AESTowerField16b>::new_with_witness();
+		let log_size = 9;
+		let _state_out = groestl_p_permutation(&mut builder, log_size).unwrap();
+
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+
+		// Needs the expressions of the Grøstl MixColumn and S-box constraints
+		let mut buf = Vec::new();
+		constraint_system.serialize(&mut buf).unwrap();
+		let mut read_buf = buf.as_slice();
+		let deserialized = ConstraintSystem::deserialize(&mut read_buf).unwrap();
+		assert!(read_buf.is_empty());
+
+		// The encoding is canonical, so the deserialized system encodes to the same bytes
+		let mut reserialized = Vec::new();
+		deserialized.serialize(&mut reserialized).unwrap();
+		assert_eq!(reserialized, buf);
+
+		let boundaries = vec![];
+		validate_witness(&deserialized, boundaries, witness).unwrap();
+	}
+
+	#[test]
+	fn test_proof_serialization() {
+		type U = OptimalUnderlier128b;
+		const LOG_INV_RATE: usize = 1;
+		const SECURITY_BITS: usize = 100;
+
+		fn roundtrip<T: SerializeBytes + DeserializeBytes>(value: &T) -> T {
+			let mut buf = Vec::new();
+			value.serialize(&mut buf).unwrap();
+			let mut read_buf = buf.as_slice();
+			let deserialized = T::deserialize(&mut read_buf).unwrap();
+			assert!(read_buf.is_empty());
+			deserialized
+		}
+
+		let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness();
+		let log_size = 10;
+		let a = unconstrained::<_, _, BinaryField1b>(&mut builder, "a", log_size).unwrap();
+		let b = unconstrained::<_, _, BinaryField1b>(&mut builder, "b", log_size).unwrap();
+		let _c = u32add(&mut builder, "u32add", log_size, a, b).unwrap();
+		let _and = bitwise::and(&mut builder, "and", log_size, a, b).unwrap();
+
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+
+		let domain_factory = DefaultEvaluationDomainFactory::default();
+		let backend = make_portable_backend();
+		let proof =
+			constraint_system::prove::<
+				U,
+				CanonicalTowerFamily,
+				_,
+				_,
+				GroestlHasher<BinaryField128b>,
+				GroestlDigestCompression<BinaryField8b>,
+				HasherChallenger<groestl_crypto::Groestl256>,
+				_,
+			>(
+				&constraint_system, LOG_INV_RATE, SECURITY_BITS, witness, &domain_factory, &backend
+			)
+			.unwrap();
+
+		// The verifier only sees the encoded constraint system and proof
+		let constraint_system = roundtrip(&constraint_system);
+		let proof = roundtrip(&proof);
+		constraint_system::verify::<
+			U,
+			CanonicalTowerFamily,
+			_,
+			_,
+			_,
+			_,
+			HasherChallenger<groestl_crypto::Groestl256>,
+		>(&constraint_system, LOG_INV_RATE, SECURITY_BITS, &domain_factory, proof)
+		.unwrap();
+	}
+
+	#[test]
+	fn test_proof_config_check() {
+		type U = OptimalUnderlier128b;
+		type Challenger = HasherChallenger<groestl_crypto::Groestl256>;
+		const LOG_INV_RATE: usize = 1;
+		const SECURITY_BITS: usize = 100;
+
+		let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness();
+		let log_size = 10;
+		let a = unconstrained::<_, _, BinaryField1b>(&mut builder, "a", log_size).unwrap();
+		let b = unconstrained::<_, _, BinaryField1b>(&mut builder, "b", log_size).unwrap();
+		let _c = u32add(&mut builder, "u32add", log_size, a, b).unwrap();
+
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+
+		let domain_factory = DefaultEvaluationDomainFactory::default();
+		let backend = make_portable_backend();
+		let proof =
+			constraint_system::prove::<
+				U,
+				CanonicalTowerFamily,
+				_,
+				_,
+				GroestlHasher<BinaryField128b>,
+				GroestlDigestCompression<BinaryField8b>,
+				Challenger,
+				_,
+			>(
+				&constraint_system, LOG_INV_RATE, SECURITY_BITS, witness, &domain_factory, &backend
+			)
+			.unwrap();
+		let mut buf = Vec::new();
+		proof.serialize(&mut buf).unwrap();
+
+		// The bitsliced Grøstl-256 computes the same digests, so it verifies the proof
+		let proof = Proof::<
+			BinaryField128b,
+			GroestlDigest<BinaryField8b>,
+			BsGroestl256Hasher<BinaryField128b, BinaryField8b>,
+			BsGroestlDigestCompression<BinaryField8b>,
+		>::deserialize(&mut buf.as_slice())
+		.unwrap();
+		constraint_system::verify::<U, CanonicalTowerFamily, _, _, _, _, Challenger>(
+			&constraint_system,
+			LOG_INV_RATE,
+			SECURITY_BITS,
+			&domain_factory,
+			proof,
+		)
+		.unwrap();
+
+		// Keccak-256 digests have the same size but a different configuration
+		let proof = Proof::<
+			BinaryField128b,
+			Keccak256Digest,
+			Keccak256<BinaryField128b>,
+			Keccak256DigestCompression,
+		>::deserialize(&mut buf.as_slice())
+		.unwrap();
+		let result = constraint_system::verify::<U, CanonicalTowerFamily, _, _, _, _, Challenger>(
+			&constraint_system,
+			LOG_INV_RATE,
+			SECURITY_BITS,
+			&domain_factory,
+			proof,
+		);
+		assert!(matches!(
+			result,
+			Err(constraint_system::error::Error::TranscriptError(
+				transcript::Error::ConfigMismatch { .. }
+			))
+		));
+	}
+
+	#[test]
+	fn test_prove_verify_vision_merkle_pcs() {
+		type U = OptimalUnderlier128b;
+		type Challenger = HasherChallenger<groestl_crypto::Groestl256>;
+		const LOG_INV_RATE: usize = 1;
+		const SECURITY_BITS: usize = 100;
+
+		let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness();
+		let log_size = 10;
+		let a = unconstrained::<_, _, BinaryField1b>(&mut builder, "a", log_size).unwrap();
+		let b = unconstrained::<_, _, BinaryField1b>(&mut builder, "b", log_size).unwrap();
+		let _c = u32add(&mut builder, "u32add", log_size, a, b).unwrap();
+
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+
+		// The standard PCSs commit with Vision-32b Merkle trees instead of Grøstl ones
+		let domain_factory = DefaultEvaluationDomainFactory::default();
+		let backend = make_portable_backend();
+		let proof =
+			constraint_system::prove::<
+				U,
+				CanonicalTowerFamily,
+				_,
+				_,
+				Vision32bLeafHasher<BinaryField128b>,
+				Vision32bDigestCompression,
+				Challenger,
+				_,
+			>(
+				&constraint_system, LOG_INV_RATE, SECURITY_BITS, witness, &domain_factory, &backend
+			)
+			.unwrap();
+
+		constraint_system::verify::<U, CanonicalTowerFamily, _, _, _, _, Challenger>(
+			&constraint_system,
+			LOG_INV_RATE,
+			SECURITY_BITS,
+			&domain_factory,
+			proof,
+		)
+		.unwrap();
+	}
+
 	#[test]
 	fn test_keccakf() {
 		let mut builder = ConstraintSystemBuilder::<U, BinaryField1b>::new_with_witness();
@@ -95,6 +364,53 @@ mod tests {
 		validate_witness(&constraint_system, boundaries, witness).unwrap();
 	}
 
+	#[test]
+	fn test_vision32b() {
+		let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness();
+		let log_size = 9;
+		let _state_out = vision32b_permutation(&mut builder, log_size).unwrap();
+
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+		let boundaries = vec![];
+		validate_witness(&constraint_system, boundaries, witness).unwrap();
+	}
+
+	#[test]
+	fn test_vision32b_known_inputs() {
+		let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness();
+		let log_size = 9;
+		let known_inputs = [
+			[BinaryField32b::ZERO; 24],
+			array::from_fn(|j| BinaryField32b::new(j as u32)),
+			[BinaryField32b::new(0xffffffff); 24],
+		];
+		let inputs = (0..1 << (log_size - 3))
+			.map(|i| known_inputs[i % known_inputs.len()])
+			.collect::<Vec<_>>();
+		let state_out = vision32b_permutation_for_inputs(&mut builder, log_size, &inputs).unwrap();
+
+		let witness = builder.take_witness().unwrap();
+		let vision = Vision32bPermutation::default();
+		for (j, &oracle) in state_out.iter().enumerate() {
+			let column = witness.get::<BinaryField32b>(oracle).unwrap();
+			let column = must_cast_slice::<_, BinaryField32b>(WithUnderlier::to_underliers_ref(
+				column.evals(),
+			));
+			for (perm_i, input) in inputs.iter().enumerate() {
+				assert_eq!(
+					column[(perm_i << 3) | 7],
+					vision.permute(*input)[j],
+					"state_out[{j}] of permutation {perm_i} differs from the expected output"
+				);
+			}
+		}
+
+		let constraint_system = builder.build().unwrap();
+		let boundaries = vec![];
+		validate_witness(&constraint_system, boundaries, witness).unwrap();
+	}
+
 	#[test]
 	fn test_groestl() {
 		let mut builder =
@@ -107,4 +423,231 @@ mod tests {
 		let boundaries = vec![];
 		validate_witness(&constraint_system, boundaries, witness).unwrap();
 	}
+
+	#[test]
+	fn test_groestl_witness_known_answers() {
+		// The P permutation vectors of the known-answer tests of binius_hash
+		let vectors = include_str!("../../hash/tests/vectors/groestl256_pq.txt");
+		let decode = |line: &str, key: &str| -> Option<[u8; 64]> {
+			let hex = line.strip_prefix(key)?.trim();
+			Some(array::from_fn(|i| u8::from_str_radix(&hex[2 * i..2 * i + 2], 16).unwrap()))
+		};
+		let inputs = vectors
+			.lines()
+			.filter_map(|line| decode(line, "In ="))
+			.collect::<Vec<_>>();
+		let outputs = vectors
+			.lines()
+			.filter_map(|line| decode(line, "P ="))
+			.collect::<Vec<_>>();
+		assert!(!inputs.is_empty());
+		assert_eq!(inputs.len(), outputs.len());
+
+		// Every row holds one of the vectors, cycling through them
+		let log_size = 9;
+		let rows = (0..1 << log_size)
+			.map(|z| {
+				PackedAESBinaryField64x8b::from_fn(|i| {
+					AESTowerField8b::new(inputs[z % inputs.len()][i])
+				})
+			})
+			.collect::<Vec<_>>();
+		let trace = TraceWitness::<U>::generate_trace_for_inputs(log_size, &rows);
+
+		for z in 0..1 << log_size {
+			let actual = trace.p_out_state(z).map(u8::from);
+			let expected = outputs[z % outputs.len()];
+			assert_eq!(
+				actual,
+				expected,
+				"P witness of vector #{} in row {z} differs from the expected output",
+				z % outputs.len()
+			);
+		}
+	}
+
+	#[test]
+	fn test_groestl256() {
+		let log_size = 9;
+		// The longest message whose padding fits in a single block, messages around the block
+		// boundary whose padding spills into a second block, and several blocks
+		for message_len in [0, 55, 56, 60, 63, 64, 130] {
+			let mut builder =
+				ConstraintSystemBuilder::<OptimalUnderlier, AESTowerField16b>::new_with_witness();
+			let message = (0..message_len)
+				.map(|i| {
+					builder.add_committed(
+						format!("message[{i}]"),
+						log_size,
+						AESTowerField8b::TOWER_LEVEL,
+					)
+				})
+				.collect::<Vec<OracleId>>();
+
+			let mut rng = thread_rng();
+			let messages = (0..1 << log_size)
+				.map(|_| {
+					repeat_with(|| AESTowerField8b::random(&mut rng))
+						.take(message_len)
+						.collect::<Vec<_>>()
+				})
+				.collect::<Vec<_>>();
+			let witness = builder.witness().unwrap();
+			for (i, &oracle) in message.iter().enumerate() {
+				let mut column = vec![
+					U::default();
+					1 << (log_size - <PackedType<U, AESTowerField8b>>::LOG_WIDTH)
+				];
+				for (byte, message) in must_cast_slice_mut::<_, AESTowerField8b>(&mut column)
+					.iter_mut()
+					.zip(&messages)
+				{
+					*byte = message[i];
+				}
+				witness
+					.set_owned::<AESTowerField8b, _>([(oracle, column)])
+					.unwrap();
+			}
+
+			let round_consts = RoundConstantOracles::new(&mut builder, log_size).unwrap();
+			let digest =
+				groestl256(&mut builder, "groestl256", log_size, &message, &round_consts).unwrap();
+
+			let witness = builder.take_witness().unwrap();
+			let expected = messages
+				.iter()
+				.map(|message| {
+					HasherDigest::<AESTowerField8b, Groestl256<_, AESTowerField8b>>::hash(message)
+				})
+				.collect::<Vec<_>>();
+			for (i, &oracle) in digest.iter().enumerate() {
+				let column = witness.get::<AESTowerField8b>(oracle).unwrap();
+				let column = must_cast_slice::<_, AESTowerField8b>(
+					WithUnderlier::to_underliers_ref(column.evals()),
+				);
+				for (z, expected) in expected.iter().enumerate() {
+					assert_eq!(column[z], expected.get(i), "digest byte {i} differs in row {z}");
+				}
+			}
+
+			let constraint_system = builder.build().unwrap();
+			let boundaries = vec![];
+			validate_witness(&constraint_system, boundaries, witness).unwrap();
+		}
+	}
+
+	#[test]
+	fn test_groestl_merkle_path() {
+		let log_size = 9;
+		let tree_log_len = 4;
+		let mut rng = thread_rng();
+		let batch_size = 3;
+		let elements = repeat_with(|| AESTowerField8b::random(&mut rng))
+			.take(batch_size << tree_log_len)
+			.collect::<Vec<_>>();
+		let tree = BinaryMerkleTree::build::<_, Groestl256<_, AESTowerField8b>, _>(
+			&GroestlDigestCompression::<AESTowerField8b>::default(),
+			&elements,
+			batch_size,
+		)
+		.unwrap();
+		let root = tree.root();
+
+		let mut builder =
+			ConstraintSystemBuilder::<OptimalUnderlier, AESTowerField16b>::new_with_witness();
+		let path = MerklePath::new(&mut builder, "path", log_size, tree_log_len);
+		let indices = (0..1 << log_size)
+			.map(|z| z % (1 << tree_log_len))
+			.collect::<Vec<_>>();
+		path.fill_witness(builder.witness().unwrap(), log_size, &tree, 0, &indices)
+			.unwrap();
+		let mut root_oracles = [OracleId::default(); DIGEST_SIZE];
+		for (i, root_i) in root_oracles.iter_mut().enumerate() {
+			*root_i = add_constant_byte(
+				&mut builder,
+				format!("root[{i}]"),
+				log_size,
+				u8::from(root.get(i)),
+			)
+			.unwrap();
+		}
+		let round_consts = RoundConstantOracles::new(&mut builder, log_size).unwrap();
+		groestl_merkle_path(
+			&mut builder,
+			"merkle_path",
+			log_size,
+			&path,
+			root_oracles,
+			&round_consts,
+		)
+		.unwrap();
+
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+		let boundaries = vec![];
+		validate_witness(&constraint_system, boundaries, witness).unwrap();
+	}
+
+	#[test]
+	fn test_sha256() {
+		// Known answers from FIPS 180-2, for messages of whole words
+		let vectors: [(&[u8], [u32; 8]); 2] = [
+			(
+				b"",
+				[
+					0xe3b0c442, 0x98fc1c14, 0x9afbf4c8, 0x996fb924, 0x27ae41e4, 0x649b934c,
+					0xa495991b, 0x7852b855,
+				],
+			),
+			(
+				b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq",
+				[
+					0x248d6a61, 0xd20638b8, 0xe5c02693, 0x0c3e6039, 0xa33ce459, 0x64ff2167,
+					0xf6ecedd4, 0x19db06c1,
+				],
+			),
+		];
+
+		let log_size = 10;
+		for (message, expected) in vectors {
+			let words = message
+				.chunks_exact(4)
+				.map(|word| u32::from_be_bytes(word.try_into().unwrap()))
+				.collect::<Vec<_>>();
+
+			let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness();
+			let message_oracles = (0..words.len())
+				.map(|i| {
+					builder.add_committed(
+						format!("message[{i}]"),
+						log_size,
+						BinaryField1b::TOWER_LEVEL,
+					)
+				})
+				.collect::<Vec<_>>();
+			let witness = builder.witness().unwrap();
+			for (&oracle, &word) in message_oracles.iter().zip(&words) {
+				let mut column =
+					vec![U::default(); 1 << (log_size - <PackedType<U, BinaryField1b>>::LOG_WIDTH)];
+				must_cast_slice_mut::<_, u32>(&mut column).fill(word);
+				witness
+					.set_owned::<BinaryField1b, _>([(oracle, column)])
+					.unwrap();
+			}
+
+			let digest = sha256(&mut builder, "sha256", log_size, &message_oracles).unwrap();
+
+			let witness = builder.take_witness().unwrap();
+			for (&oracle, &expected) in digest.iter().zip(&expected) {
+				let column = witness.get::<BinaryField1b>(oracle).unwrap();
+				let column =
+					must_cast_slice::<_, u32>(WithUnderlier::to_underliers_ref(column.evals()));
+				assert!(column.iter().all(|&word| word == expected));
+			}
+
+			let constraint_system = builder.build().unwrap();
+			let boundaries = vec![];
+			validate_witness(&constraint_system, boundaries, witness).unwrap();
+		}
+	}
 }
@@ -0,0 +1,243 @@
+// Copyright 2024 Irreducible Inc.
+
+use crate::{
+	builder::ConstraintSystemBuilder,
+	groestl::{groestl_output_transform, new_column_8b, RoundConstantOracles, DIGEST_SIZE},
+};
+use anyhow::Result;
+use binius_core::{
+	merkle_tree_vcs::BinaryMerkleTree, oracle::OracleId, witness::MultilinearExtensionIndex,
+};
+use binius_field::{
+	as_packed_field::{PackScalar, PackedType},
+	underlier::{Divisible, UnderlierType, WithUnderlier},
+	AESTowerField8b, BinaryField1b, ExtensionField, PackedField, TowerField,
+};
+use binius_hash::GroestlDigest;
+use binius_macros::composition_poly;
+use bytemuck::{must_cast_slice, must_cast_slice_mut, Pod};
+use itertools::chain;
+use std::{array, iter};
+
+/// The committed columns of a Merkle path from a leaf digest to the root, one path per row.
+#[derive(Debug, Clone)]
+pub struct MerklePath {
+	/// The bytes of the leaf digest
+	pub leaf: [OracleId; DIGEST_SIZE],
+	/// The bits of the leaf index, starting from the least significant one
+	pub index_bits: Vec<OracleId>,
+	/// The bytes of the sibling digests, starting from the leaf level
+	pub siblings: Vec<[OracleId; DIGEST_SIZE]>,
+}
+
+impl MerklePath {
+	/// Commits the columns of paths through a tree of `depth` levels.
+	pub fn new<U, F>(
+		builder: &mut ConstraintSystemBuilder<U, F>,
+		name: impl ToString,
+		log_size: usize,
+		depth: usize,
+	) -> Self
+	where
+		U: UnderlierType + PackScalar<F>,
+		F: TowerField,
+	{
+		builder.push_namespace(name);
+		let leaf = builder.add_committed_multiple("leaf", log_size, AESTowerField8b::TOWER_LEVEL);
+		let index_bits = (0..depth)
+			.map(|i| {
+				builder.add_committed(
+					format!("index_bits[{i}]"),
+					log_size,
+					BinaryField1b::TOWER_LEVEL,
+				)
+			})
+			.collect();
+		let siblings = (0..depth)
+			.map(|i| {
+				builder.add_committed_multiple(
+					format!("siblings[{i}]"),
+					log_size,
+					AESTowerField8b::TOWER_LEVEL,
+				)
+			})
+			.collect();
+		builder.pop_namespace();
+
+		Self {
+			leaf,
+			index_bits,
+			siblings,
+		}
+	}
+
+	/// Fills the witness with the paths of `tree` from the leaves `indices` up to the layer at
+	/// `layer_depth`, one per row.
+	pub fn fill_witness<U, F>(
+		&self,
+		witness: &mut MultilinearExtensionIndex<U, F>,
+		log_size: usize,
+		tree: &BinaryMerkleTree<GroestlDigest<AESTowerField8b>>,
+		layer_depth: usize,
+		indices: &[usize],
+	) -> Result<()>
+	where
+		U: UnderlierType
+			+ Pod
+			+ PackScalar<F>
+			+ PackScalar<AESTowerField8b>
+			+ PackScalar<BinaryField1b>,
+		F: TowerField + ExtensionField<AESTowerField8b>,
+	{
+		assert_eq!(indices.len(), 1 << log_size, "there must be exactly one leaf index per row");
+
+		let depth = self.siblings.len();
+		let leaves = tree.layer(tree.log_len)?;
+		let mut leaf: [Vec<U>; DIGEST_SIZE] = array::from_fn(|_| new_column_8b(log_size));
+		let mut index_bits =
+			vec![
+				vec![U::default(); 1 << (log_size - <PackedType<U, BinaryField1b>>::LOG_WIDTH)];
+				depth
+			];
+		let mut siblings =
+			vec![array::from_fn::<_, DIGEST_SIZE, _>(|_| new_column_8b::<U>(log_size)); depth];
+
+		for (z, &index) in indices.iter().enumerate() {
+			let branch = tree.branch(index, layer_depth)?;
+			if branch.len() != depth {
+				anyhow::bail!("the tree has branches of length {}, expected {depth}", branch.len());
+			}
+
+			let leaf_digest = leaves[index];
+			for (i, column) in leaf.iter_mut().enumerate() {
+				must_cast_slice_mut::<_, AESTowerField8b>(column)[z] = leaf_digest.get(i);
+			}
+			for (level, (bits, sibling_digest)) in iter::zip(&mut index_bits, &branch).enumerate() {
+				must_cast_slice_mut::<_, u8>(bits)[z / 8] |=
+					(((index >> level) & 1) << (z % 8)) as u8;
+				for (i, column) in siblings[level].iter_mut().enumerate() {
+					must_cast_slice_mut::<_, AESTowerField8b>(column)[z] = sibling_digest.get(i);
+				}
+			}
+		}
+
+		witness.set_owned::<AESTowerField8b, _>(iter::zip(
+			chain!(self.leaf, self.siblings.iter().flatten().copied()),
+			chain!(leaf, siblings.into_iter().flatten()),
+		))?;
+		witness.set_owned::<BinaryField1b, _>(iter::zip(self.index_bits.clone(), index_bits))?;
+		Ok(())
+	}
+}
+
+/// Constrains every row of `path` to be a Merkle path to the node in the same row of `root`, where
+/// the nodes are compressed with [`binius_hash::GroestlDigestCompression`].
+///
+/// The columns of `root` and their witness are provided by the caller, so the paths may end at the
+/// root of the tree or at any layer below it.
+pub fn groestl_merkle_path<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	log_size: usize,
+	path: &MerklePath,
+	root: [OracleId; DIGEST_SIZE],
+	round_consts: &RoundConstantOracles,
+) -> Result<()>
+where
+	U: UnderlierType
+		+ Pod
+		+ PackScalar<F>
+		+ PackScalar<BinaryField1b>
+		+ PackScalar<AESTowerField8b>
+		+ Divisible<u8>,
+	F: TowerField + ExtensionField<AESTowerField8b>,
+{
+	builder.push_namespace(name);
+
+	let mut node = path.leaf;
+	for (level, (&bit, &sibling)) in iter::zip(&path.index_bits, &path.siblings).enumerate() {
+		builder.push_namespace(format!("levels[{level}]"));
+
+		// The node is the left child when its index bit is zero
+		let left = builder.add_committed_multiple::<DIGEST_SIZE>(
+			"left",
+			log_size,
+			AESTowerField8b::TOWER_LEVEL,
+		);
+		let mut right = [OracleId::default(); DIGEST_SIZE];
+		for (i, right_i) in right.iter_mut().enumerate() {
+			*right_i = builder.add_linear_combination(
+				format!("right[{i}]"),
+				log_size,
+				[(left[i], F::ONE), (node[i], F::ONE), (sibling[i], F::ONE)],
+			)?;
+		}
+
+		if let Some(witness) = builder.witness() {
+			let bits = witness.get::<BinaryField1b>(bit)?;
+			let bits = must_cast_slice::<_, u8>(WithUnderlier::to_underliers_ref(bits.evals()));
+
+			let mut left_witness = Vec::with_capacity(DIGEST_SIZE);
+			let mut right_witness = Vec::with_capacity(DIGEST_SIZE);
+			for i in 0..DIGEST_SIZE {
+				let node_i = witness.get::<AESTowerField8b>(node[i])?;
+				let node_i = must_cast_slice::<_, AESTowerField8b>(
+					WithUnderlier::to_underliers_ref(node_i.evals()),
+				);
+				let sibling_i = witness.get::<AESTowerField8b>(sibling[i])?;
+				let sibling_i = must_cast_slice::<_, AESTowerField8b>(
+					WithUnderlier::to_underliers_ref(sibling_i.evals()),
+				);
+
+				let mut left_column = new_column_8b::<U>(log_size);
+				let mut right_column = new_column_8b::<U>(log_size);
+				for (z, (left_z, right_z)) in iter::zip(
+					must_cast_slice_mut::<_, AESTowerField8b>(&mut left_column),
+					must_cast_slice_mut::<_, AESTowerField8b>(&mut right_column),
+				)
+				.enumerate()
+				{
+					(*left_z, *right_z) = if (bits[z / 8] >> (z % 8)) & 1 == 0 {
+						(node_i[z], sibling_i[z])
+					} else {
+						(sibling_i[z], node_i[z])
+					};
+				}
+				left_witness.push(left_column);
+				right_witness.push(right_column);
+			}
+
+			witness.set_owned::<AESTowerField8b, _>(iter::zip(
+				chain!(left, right),
+				chain!(left_witness, right_witness),
+			))?;
+		}
+
+		for i in 0..DIGEST_SIZE {
+			builder.assert_zero(
+				[bit, node[i], sibling[i], left[i]],
+				composition_poly!(
+					[bit, node, sibling, left] = left - node - bit * (node + sibling)
+				),
+			);
+		}
+
+		let state = array::from_fn(|ij| {
+			if ij < DIGEST_SIZE {
+				left[ij]
+			} else {
+				right[ij - DIGEST_SIZE]
+			}
+		});
+		node = groestl_output_transform(builder, "compression", log_size, state, round_consts)?;
+
+		builder.pop_namespace();
+	}
+
+	for (&node_i, &root_i) in iter::zip(&node, &root) {
+		builder.assert_zero([node_i, root_i], composition_poly!([node, root] = node - root));
+	}
+
+	builder.pop_namespace();
+	Ok(())
+}
@@ -0,0 +1,348 @@
+// Copyright 2024 Irreducible Inc.
+
+//! SHA-256 gadgets.
+//!
+//! Every 32-bit word of the state and the message is a chunk of 32 contiguous rows of a 1-bit
+//! column, as in [`u32add`], so a column of `2^log_size` rows holds `2^(log_size - 5)` independent
+//! instances.
+//!
+//! For SHA-256 specification and pseudocode, see
+//! [FIPS 180-4](https://csrc.nist.gov/pubs/fips/180-4/upd1/final).
+
+use crate::{bitwise, builder::ConstraintSystemBuilder, u32add::u32add};
+use anyhow::Result;
+use binius_core::{
+	oracle::{OracleId, ShiftVariant},
+	transparent::multilinear_extension::MultilinearExtensionTransparent,
+};
+use binius_field::{
+	as_packed_field::{PackScalar, PackedType},
+	underlier::{UnderlierType, WithUnderlier},
+	BinaryField1b, PackedField, TowerField,
+};
+use binius_utils::checked_arithmetics::checked_log_2;
+use bytemuck::{must_cast_slice, must_cast_slice_mut, Pod};
+use std::{array, collections::HashMap};
+
+const LOG_U32_BITS: usize = checked_log_2(32);
+
+/// SHA-256 round constants, K
+const ROUND_CONSTS_K: [u32; 64] = [
+	0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
+	0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
+	0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
+	0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967,
+	0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85,
+	0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070,
+	0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+	0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
+];
+
+/// SHA-256 initial hash value, H(0)
+const INIT: [u32; 8] = [
+	0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+];
+
+#[derive(Debug, Clone, Copy)]
+enum Shift {
+	RotateRight(u32),
+	ShiftRight(u32),
+}
+
+impl Shift {
+	fn apply(self, x: u32) -> u32 {
+		match self {
+			Self::RotateRight(r) => x.rotate_right(r),
+			Self::ShiftRight(s) => x >> s,
+		}
+	}
+
+	/// The offset and variant of the shifted oracle computing this shift on every word.
+	fn oracle_shift(self) -> (usize, ShiftVariant) {
+		match self {
+			Self::RotateRight(r) => (32 - r as usize, ShiftVariant::CircularLeft),
+			Self::ShiftRight(s) => (s as usize, ShiftVariant::LogicalRight),
+		}
+	}
+}
+
+const BIG_SIGMA_0: [Shift; 3] = [
+	Shift::RotateRight(2),
+	Shift::RotateRight(13),
+	Shift::RotateRight(22),
+];
+const BIG_SIGMA_1: [Shift; 3] = [
+	Shift::RotateRight(6),
+	Shift::RotateRight(11),
+	Shift::RotateRight(25),
+];
+const SMALL_SIGMA_0: [Shift; 3] = [
+	Shift::RotateRight(7),
+	Shift::RotateRight(18),
+	Shift::ShiftRight(3),
+];
+const SMALL_SIGMA_1: [Shift; 3] = [
+	Shift::RotateRight(17),
+	Shift::RotateRight(19),
+	Shift::ShiftRight(10),
+];
+
+/// Fills the witness of `oracle` by applying `f` to the 32-bit words of `inputs`.
+fn fill_u32_witness<U, F, const N: usize>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	log_size: usize,
+	oracle: OracleId,
+	inputs: [OracleId; N],
+	f: impl Fn([u32; N]) -> u32,
+) -> Result<()>
+where
+	U: UnderlierType + Pod + PackScalar<F> + PackScalar<BinaryField1b>,
+	F: TowerField,
+{
+	if let Some(witness) = builder.witness() {
+		let len = 1 << (log_size - <PackedType<U, BinaryField1b>>::LOG_WIDTH);
+		let mut output = vec![U::default(); len].into_boxed_slice();
+		{
+			let inputs = inputs
+				.iter()
+				.map(|&input| witness.get::<BinaryField1b>(input))
+				.collect::<Result<Vec<_>, _>>()?;
+			let inputs = inputs
+				.iter()
+				.map(|input| {
+					must_cast_slice::<_, u32>(WithUnderlier::to_underliers_ref(input.evals()))
+				})
+				.collect::<Vec<_>>();
+			for (z, output) in must_cast_slice_mut::<_, u32>(&mut output)
+				.iter_mut()
+				.enumerate()
+			{
+				*output = f(array::from_fn(|i| inputs[i][z]));
+			}
+		}
+		witness.set_owned::<BinaryField1b, _>([(oracle, output)])?;
+	}
+	Ok(())
+}
+
+/// Adds a transparent column holding `value` in every 32-bit word, along with its witness.
+fn u32_constant<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	log_size: usize,
+	value: u32,
+) -> Result<OracleId>
+where
+	U: UnderlierType + Pod + PackScalar<F> + PackScalar<BinaryField1b>,
+	F: TowerField,
+{
+	let name = name.to_string();
+	let log_width = <PackedType<U, BinaryField1b>>::LOG_WIDTH;
+
+	let mut single_witness = [U::default()];
+	must_cast_slice_mut::<_, u32>(&mut single_witness).fill(value);
+	let single = builder.add_transparent(
+		format!("{name}_single"),
+		MultilinearExtensionTransparent::<_, PackedType<U, F>, _>::from_values(
+			single_witness
+				.map(PackedType::<U, BinaryField1b>::from_underlier)
+				.to_vec(),
+		)?,
+	)?;
+	let repeated = builder.add_repeating(name, single, log_size - log_width)?;
+
+	if let Some(witness) = builder.witness() {
+		let mut repeated_witness = vec![U::default(); 1 << (log_size - log_width)];
+		must_cast_slice_mut::<_, u32>(&mut repeated_witness).fill(value);
+		witness.set_owned::<BinaryField1b, _>([
+			(single, single_witness.to_vec()),
+			(repeated, repeated_witness),
+		])?;
+	}
+
+	Ok(repeated)
+}
+
+/// The XOR of the three `shifts` of every word of `x`, which is one of the Σ and σ functions.
+fn sigma<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	log_size: usize,
+	x: OracleId,
+	shifts: [Shift; 3],
+) -> Result<OracleId>
+where
+	U: UnderlierType + Pod + PackScalar<F> + PackScalar<BinaryField1b>,
+	F: TowerField,
+{
+	builder.push_namespace(name);
+	let mut terms = [OracleId::default(); 3];
+	for (i, (term, shift)) in terms.iter_mut().zip(shifts).enumerate() {
+		let (offset, variant) = shift.oracle_shift();
+		*term = builder.add_shifted(format!("terms[{i}]"), x, offset, LOG_U32_BITS, variant)?;
+		fill_u32_witness(builder, log_size, *term, [x], |[x]| shift.apply(x))?;
+	}
+	let out = builder.add_linear_combination("out", log_size, terms.map(|term| (term, F::ONE)))?;
+	fill_u32_witness(builder, log_size, out, terms, |[x, y, z]| x ^ y ^ z)?;
+	builder.pop_namespace();
+	Ok(out)
+}
+
+/// The compression function, with the round constants shared by all compressions of a circuit
+fn compress<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	log_size: usize,
+	round_consts: &[OracleId; 64],
+	state: [OracleId; 8],
+	block: [OracleId; 16],
+) -> Result<[OracleId; 8]>
+where
+	U: UnderlierType + Pod + PackScalar<F> + PackScalar<BinaryField1b>,
+	F: TowerField,
+{
+	// Message schedule
+	let mut w = block.to_vec();
+	for t in 16..64 {
+		builder.push_namespace(format!("w[{t}]"));
+		let s0 = sigma(builder, "s0", log_size, w[t - 15], SMALL_SIGMA_0)?;
+		let s1 = sigma(builder, "s1", log_size, w[t - 2], SMALL_SIGMA_1)?;
+		let sum = u32add(builder, "sum[0]", log_size, w[t - 16], s0)?;
+		let sum = u32add(builder, "sum[1]", log_size, sum, w[t - 7])?;
+		w.push(u32add(builder, "sum[2]", log_size, sum, s1)?);
+		builder.pop_namespace();
+	}
+
+	let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = state;
+	for t in 0..64 {
+		builder.push_namespace(format!("rounds[{t}]"));
+
+		let big_s1 = sigma(builder, "big_s1", log_size, e, BIG_SIGMA_1)?;
+		// Ch(e, f, g) = g ⊕ (e ∧ (f ⊕ g))
+		let f_xor_g = bitwise::xor(builder, "f_xor_g", log_size, f, g)?;
+		let e_and_f_xor_g = bitwise::and(builder, "e_and_f_xor_g", log_size, e, f_xor_g)?;
+		let ch = bitwise::xor(builder, "ch", log_size, g, e_and_f_xor_g)?;
+
+		let temp1 = u32add(builder, "temp1[0]", log_size, h, big_s1)?;
+		let temp1 = u32add(builder, "temp1[1]", log_size, temp1, ch)?;
+		let temp1 = u32add(builder, "temp1[2]", log_size, temp1, round_consts[t])?;
+		let temp1 = u32add(builder, "temp1[3]", log_size, temp1, w[t])?;
+
+		let big_s0 = sigma(builder, "big_s0", log_size, a, BIG_SIGMA_0)?;
+		// Maj(a, b, c) = b ⊕ ((a ⊕ b) ∧ (b ⊕ c))
+		let a_xor_b = bitwise::xor(builder, "a_xor_b", log_size, a, b)?;
+		let b_xor_c = bitwise::xor(builder, "b_xor_c", log_size, b, c)?;
+		let xors_and = bitwise::and(builder, "xors_and", log_size, a_xor_b, b_xor_c)?;
+		let maj = bitwise::xor(builder, "maj", log_size, b, xors_and)?;
+
+		let temp2 = u32add(builder, "temp2", log_size, big_s0, maj)?;
+
+		let next_e = u32add(builder, "e", log_size, d, temp1)?;
+		let next_a = u32add(builder, "a", log_size, temp1, temp2)?;
+		(h, g, f, e, d, c, b, a) = (g, f, e, next_e, c, b, a, next_a);
+
+		builder.pop_namespace();
+	}
+
+	let working = [a, b, c, d, e, f, g, h];
+	let mut output = [OracleId::default(); 8];
+	for (i, output_i) in output.iter_mut().enumerate() {
+		*output_i = u32add(builder, format!("output[{i}]"), log_size, state[i], working[i])?;
+	}
+	Ok(output)
+}
+
+fn add_round_consts<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	log_size: usize,
+) -> Result<[OracleId; 64]>
+where
+	U: UnderlierType + Pod + PackScalar<F> + PackScalar<BinaryField1b>,
+	F: TowerField,
+{
+	let mut round_consts = [OracleId::default(); 64];
+	for (t, (round_const, &k)) in round_consts.iter_mut().zip(&ROUND_CONSTS_K).enumerate() {
+		*round_const = u32_constant(builder, format!("round_consts[{t}]"), log_size, k)?;
+	}
+	Ok(round_consts)
+}
+
+/// The SHA-256 compression of the chaining value `state` with the message `block` in every
+/// 32-bit word.
+pub fn sha256_compress<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	log_size: usize,
+	state: [OracleId; 8],
+	block: [OracleId; 16],
+) -> Result<[OracleId; 8]>
+where
+	U: UnderlierType + Pod + PackScalar<F> + PackScalar<BinaryField1b>,
+	F: TowerField,
+{
+	builder.push_namespace(name);
+	let round_consts = add_round_consts(builder, log_size)?;
+	let output = compress(builder, log_size, &round_consts, state, block)?;
+	builder.pop_namespace();
+	Ok(output)
+}
+
+/// The SHA-256 digest of the message in every 32-bit word of the columns `message`.
+///
+/// The words of the message and the digest are the big-endian encodings of their bytes, so the
+/// message length is a multiple of 4 bytes. The length is fixed by the circuit, so the padding and
+/// the initial hash value are constant columns.
+pub fn sha256<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	log_size: usize,
+	message: &[OracleId],
+) -> Result<[OracleId; 8]>
+where
+	U: UnderlierType + Pod + PackScalar<F> + PackScalar<BinaryField1b>,
+	F: TowerField,
+{
+	builder.push_namespace(name);
+	let round_consts = add_round_consts(builder, log_size)?;
+
+	let mut constants = HashMap::new();
+	let mut constant = |builder: &mut ConstraintSystemBuilder<U, F>,
+	                    value: u32|
+	 -> Result<OracleId> {
+		if let Some(&oracle) = constants.get(&value) {
+			return Ok(oracle);
+		}
+		let oracle = u32_constant(builder, format!("constants[{value:#010x}]"), log_size, value)?;
+		constants.insert(value, oracle);
+		Ok(oracle)
+	};
+
+	// The message is followed by a one bit, zeros and the message length in bits as a 64-bit
+	// big-endian integer, which must fit in the last block
+	let n_blocks = (message.len() + 3).div_ceil(16);
+	let mut padding = vec![0u32; n_blocks * 16 - message.len()];
+	padding[0] = 0x80000000;
+	let n_padding = padding.len();
+	let n_bits = 32 * message.len() as u64;
+	padding[n_padding - 2] = (n_bits >> 32) as u32;
+	padding[n_padding - 1] = n_bits as u32;
+
+	let mut blocks = message.to_vec();
+	for &word in &padding {
+		blocks.push(constant(builder, word)?);
+	}
+
+	let mut state = [OracleId::default(); 8];
+	for (state_i, &init) in state.iter_mut().zip(&INIT) {
+		*state_i = constant(builder, init)?;
+	}
+
+	for (i, block) in blocks.chunks_exact(16).enumerate() {
+		let block = block.try_into().expect("chunks have the size of a block");
+		builder.push_namespace(format!("blocks[{i}]"));
+		state = compress(builder, log_size, &round_consts, state, block)?;
+		builder.pop_namespace();
+	}
+
+	builder.pop_namespace();
+	Ok(state)
+}
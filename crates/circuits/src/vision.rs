@@ -0,0 +1,543 @@
+// Copyright 2024 Irreducible Inc.
+
+//! Gadget for the [Vision Mark-32] permutation.
+//!
+//! The arithmetization uses columns of 32-bit binary tower field elements. Every row of the trace
+//! attests to the validity of 2 Vision rounds, so a permutation of 16 rounds spans a block of 8
+//! contiguous rows.
+//!
+//! [Vision Mark-32]: https://eprint.iacr.org/2024/633
+
+use crate::builder::ConstraintSystemBuilder;
+use anyhow::Result;
+use binius_core::{
+	oracle::{OracleId, ShiftVariant},
+	transparent::multilinear_extension::MultilinearExtensionTransparent,
+};
+use binius_field::{
+	arithmetic_traits::{InvertOrZero, Square},
+	as_packed_field::{PackScalar, PackedType},
+	linear_transformation::Transformation,
+	underlier::{UnderlierType, WithUnderlier},
+	AESTowerField32b, BinaryField1b, BinaryField32b, ExtensionField, Field,
+	PackedAESBinaryField8x32b, PackedField, TowerField,
+};
+use binius_hash::{
+	vision_constants::{
+		MDS_TRANS, NUM_ROUNDS, ROUND_KEYS, SBOX_FWD_CONST, SBOX_FWD_TRANS, SBOX_INV_CONST,
+	},
+	Vision32bPermutation, INV_PACKED_TRANS_AES,
+};
+use binius_macros::composition_poly;
+use binius_utils::checked_arithmetics::checked_log_2;
+use bytemuck::{must_cast_slice_mut, Pod};
+use itertools::chain;
+use p3_symmetric::Permutation;
+use rand::thread_rng;
+use std::{array, iter};
+
+const STATE_SIZE: usize = 24;
+const ROWS_PER_PERMUTATION: usize = NUM_ROUNDS;
+const LOG_ROWS_PER_PERMUTATION: usize = checked_log_2(ROWS_PER_PERMUTATION);
+
+/// The Vision Mark-32 permutation of committed random states.
+///
+/// Each permutation occupies a block of 8 rows, the first of which holds its input in `state_in`.
+/// Returns the `state_out` columns, which hold the output of each permutation in the last row of
+/// its block.
+pub fn vision32b_permutation<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	log_size: usize,
+) -> Result<[OracleId; 24]>
+where
+	U: UnderlierType + Pod + PackScalar<F> + PackScalar<BinaryField32b> + PackScalar<BinaryField1b>,
+	F: TowerField + ExtensionField<BinaryField32b>,
+{
+	let inputs = if builder.witness().is_some() {
+		let mut rng = thread_rng();
+		(0..1 << (log_size - LOG_ROWS_PER_PERMUTATION))
+			.map(|_| array::from_fn(|_| <BinaryField32b as Field>::random(&mut rng)))
+			.collect()
+	} else {
+		Vec::new()
+	};
+	vision32b_permutation_for_inputs(builder, log_size, &inputs)
+}
+
+/// The Vision Mark-32 permutation of the committed states `inputs`.
+///
+/// Lays out one permutation per block of 8 rows, as [`vision32b_permutation`] does. `inputs` is
+/// only read when the builder has a witness, in which case it must hold exactly one state per
+/// block.
+pub fn vision32b_permutation_for_inputs<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	log_size: usize,
+	inputs: &[[BinaryField32b; 24]],
+) -> Result<[OracleId; 24]>
+where
+	U: UnderlierType + Pod + PackScalar<F> + PackScalar<BinaryField32b> + PackScalar<BinaryField1b>,
+	F: TowerField + ExtensionField<BinaryField32b>,
+{
+	builder.push_namespace("vision32b_permutation");
+
+	let round_0_consts: [OracleId; 24] = add_state_oracles(|j| {
+		add_round_pattern(
+			builder,
+			format!("round_0_consts[{j}]"),
+			log_size,
+			array::from_fn(|round_i| {
+				if round_i == 0 {
+					ROUND_KEYS[0][j]
+				} else {
+					BinaryField32b::ZERO
+				}
+			}),
+		)
+	})?;
+	let even_round_consts: [OracleId; 24] = add_state_oracles(|j| {
+		add_round_pattern(
+			builder,
+			format!("even_round_consts[{j}]"),
+			log_size,
+			array::from_fn(|round_i| ROUND_KEYS[1 + 2 * round_i][j]),
+		)
+	})?;
+	let odd_round_consts: [OracleId; 24] = add_state_oracles(|j| {
+		add_round_pattern(
+			builder,
+			format!("odd_round_consts[{j}]"),
+			log_size,
+			array::from_fn(|round_i| ROUND_KEYS[2 + 2 * round_i][j]),
+		)
+	})?;
+
+	// Every byte holds the selector bits of the rows of a permutation, all but the last set
+	let log_width_1b = <PackedType<U, BinaryField1b>>::LOG_WIDTH;
+	let mut round_selector_single_witness = [U::default()];
+	must_cast_slice_mut::<_, u8>(&mut round_selector_single_witness).fill(0x7f);
+	let round_selector_single = builder.add_transparent(
+		"round_selector_single",
+		MultilinearExtensionTransparent::<_, PackedType<U, F>, _>::from_values(
+			round_selector_single_witness
+				.map(PackedType::<U, BinaryField1b>::from_underlier)
+				.to_vec(),
+		)?,
+	)?;
+	let round_selector =
+		builder.add_repeating("round_selector", round_selector_single, log_size - log_width_1b)?;
+
+	let state_in: [OracleId; 24] =
+		builder.add_committed_multiple("state_in", log_size, BinaryField32b::TOWER_LEVEL);
+	let prod_0: [OracleId; 24] =
+		builder.add_committed_multiple("prod_evens", log_size, BinaryField32b::TOWER_LEVEL);
+	let s_box_out_0: [OracleId; 24] =
+		builder.add_committed_multiple("sbox_out_evens", log_size, BinaryField32b::TOWER_LEVEL);
+	let s_box_pow2_0: [OracleId; 24] = builder.add_committed_multiple(
+		"sbox_out_pow2_evens",
+		log_size,
+		BinaryField32b::TOWER_LEVEL,
+	);
+	let s_box_pow4_0: [OracleId; 24] = builder.add_committed_multiple(
+		"sbox_out_pow4_evens",
+		log_size,
+		BinaryField32b::TOWER_LEVEL,
+	);
+	let inv_1: [OracleId; 24] =
+		builder.add_committed_multiple("inv_odds", log_size, BinaryField32b::TOWER_LEVEL);
+	let prod_1: [OracleId; 24] =
+		builder.add_committed_multiple("prod_odds", log_size, BinaryField32b::TOWER_LEVEL);
+	let inv_pow2_1: [OracleId; 24] =
+		builder.add_committed_multiple("inv_pow2_odds", log_size, BinaryField32b::TOWER_LEVEL);
+	let inv_pow4_1: [OracleId; 24] =
+		builder.add_committed_multiple("inv_pow4_odds", log_size, BinaryField32b::TOWER_LEVEL);
+
+	let round_begin: [OracleId; 24] = add_state_oracles(|j| {
+		builder.add_linear_combination(
+			format!("round_begin[{j}]"),
+			log_size,
+			[(state_in[j], F::ONE), (round_0_consts[j], F::ONE)],
+		)
+	})?;
+	// The even rounds commit to the output of the inverse S-box, so its input is the forward
+	// affine transformation of that output
+	let inv_0: [OracleId; 24] = add_state_oracles(|j| {
+		add_sbox_fwd_affine(
+			builder,
+			format!("inv_evens[{j}]"),
+			log_size,
+			[s_box_out_0[j], s_box_pow2_0[j], s_box_pow4_0[j]],
+		)
+	})?;
+	let mds_out_0 = add_mds(builder, "mds_out_evens", log_size, &s_box_out_0)?;
+	let round_out_0: [OracleId; 24] = add_state_oracles(|j| {
+		builder.add_linear_combination(
+			format!("round_out_evens[{j}]"),
+			log_size,
+			[(mds_out_0[j], F::ONE), (even_round_consts[j], F::ONE)],
+		)
+	})?;
+	let s_box_out_1: [OracleId; 24] = add_state_oracles(|j| {
+		add_sbox_fwd_affine(
+			builder,
+			format!("sbox_out_odds[{j}]"),
+			log_size,
+			[inv_1[j], inv_pow2_1[j], inv_pow4_1[j]],
+		)
+	})?;
+	let mds_out_1 = add_mds(builder, "mds_out_odds", log_size, &s_box_out_1)?;
+	let state_out: [OracleId; 24] = add_state_oracles(|j| {
+		builder.add_linear_combination(
+			format!("state_out[{j}]"),
+			log_size,
+			[(mds_out_1[j], F::ONE), (odd_round_consts[j], F::ONE)],
+		)
+	})?;
+	let next_state_in: [OracleId; 24] = add_state_oracles(|j| {
+		builder.add_shifted(
+			format!("next_state_in[{j}]"),
+			state_in[j],
+			1,
+			LOG_ROWS_PER_PERMUTATION,
+			ShiftVariant::LogicalRight,
+		)
+	})?;
+
+	if let Some(witness) = builder.witness() {
+		let log_width = <PackedType<U, BinaryField32b>>::LOG_WIDTH;
+		let build_trace_column = || vec![U::default(); 1 << (log_size - log_width)];
+		let build_trace_columns = || array::from_fn::<_, 24, _>(|_| build_trace_column());
+
+		let mut round_selector_witness = vec![U::default(); 1 << (log_size - log_width_1b)];
+		must_cast_slice_mut::<_, u8>(&mut round_selector_witness).fill(0x7f);
+
+		let mut state_in_witness = build_trace_columns();
+		let mut round_begin_witness = build_trace_columns();
+		let mut inv_0_witness = build_trace_columns();
+		let mut prod_0_witness = build_trace_columns();
+		let mut s_box_out_0_witness = build_trace_columns();
+		let mut s_box_pow2_0_witness = build_trace_columns();
+		let mut s_box_pow4_0_witness = build_trace_columns();
+		let mut mds_out_0_witness = build_trace_columns();
+		let mut round_out_0_witness = build_trace_columns();
+		let mut inv_1_witness = build_trace_columns();
+		let mut prod_1_witness = build_trace_columns();
+		let mut inv_pow2_1_witness = build_trace_columns();
+		let mut inv_pow4_1_witness = build_trace_columns();
+		let mut s_box_out_1_witness = build_trace_columns();
+		let mut mds_out_1_witness = build_trace_columns();
+		let mut state_out_witness = build_trace_columns();
+		let mut next_state_in_witness = build_trace_columns();
+
+		fn cast_32b_cols<U: Pod, const N: usize>(
+			cols: &mut [Vec<U>; N],
+		) -> [&mut [BinaryField32b]; N] {
+			cols.each_mut()
+				.map(|col| must_cast_slice_mut::<_, BinaryField32b>(col.as_mut_slice()))
+		}
+
+		let state_in_32b = cast_32b_cols(&mut state_in_witness);
+		let round_begin_32b = cast_32b_cols(&mut round_begin_witness);
+		let inv_0_32b = cast_32b_cols(&mut inv_0_witness);
+		let prod_0_32b = cast_32b_cols(&mut prod_0_witness);
+		let s_box_out_0_32b = cast_32b_cols(&mut s_box_out_0_witness);
+		let s_box_pow2_0_32b = cast_32b_cols(&mut s_box_pow2_0_witness);
+		let s_box_pow4_0_32b = cast_32b_cols(&mut s_box_pow4_0_witness);
+		let mds_out_0_32b = cast_32b_cols(&mut mds_out_0_witness);
+		let round_out_0_32b = cast_32b_cols(&mut round_out_0_witness);
+		let inv_1_32b = cast_32b_cols(&mut inv_1_witness);
+		let prod_1_32b = cast_32b_cols(&mut prod_1_witness);
+		let inv_pow2_1_32b = cast_32b_cols(&mut inv_pow2_1_witness);
+		let inv_pow4_1_32b = cast_32b_cols(&mut inv_pow4_1_witness);
+		let s_box_out_1_32b = cast_32b_cols(&mut s_box_out_1_witness);
+		let mds_out_1_32b = cast_32b_cols(&mut mds_out_1_witness);
+		let state_out_32b = cast_32b_cols(&mut state_out_witness);
+		let next_state_in_32b = cast_32b_cols(&mut next_state_in_witness);
+
+		if inputs.len() != 1 << (log_size - LOG_ROWS_PER_PERMUTATION) {
+			anyhow::bail!(
+				"expected {} permutation inputs, got {}",
+				1 << (log_size - LOG_ROWS_PER_PERMUTATION),
+				inputs.len()
+			);
+		}
+
+		let vision = Vision32bPermutation::default();
+
+		for perm_i in 0..1 << (log_size - LOG_ROWS_PER_PERMUTATION) {
+			let i = perm_i << LOG_ROWS_PER_PERMUTATION;
+
+			let input = inputs[perm_i];
+			let output = vision.permute(input);
+
+			let mut state = input;
+			for round_i in 0..ROWS_PER_PERMUTATION {
+				let i = i | round_i;
+
+				// Round 2 * round_i
+				let round_begin: [BinaryField32b; 24] = array::from_fn(|j| {
+					if round_i == 0 {
+						state[j] + ROUND_KEYS[0][j]
+					} else {
+						state[j]
+					}
+				});
+				let inv_0 = round_begin.map(InvertOrZero::invert_or_zero);
+				let s_box_out_0 = sbox_inv_affine(inv_0);
+				let s_box_pow2_0 = s_box_out_0.map(Square::square);
+				let s_box_pow4_0 = s_box_pow2_0.map(Square::square);
+				let mds_out_0 = mds(&s_box_out_0);
+				let round_out_0: [BinaryField32b; 24] =
+					array::from_fn(|j| mds_out_0[j] + ROUND_KEYS[1 + 2 * round_i][j]);
+
+				// Round 2 * round_i + 1
+				let inv_1 = round_out_0.map(InvertOrZero::invert_or_zero);
+				let inv_pow2_1 = inv_1.map(Square::square);
+				let inv_pow4_1 = inv_pow2_1.map(Square::square);
+				let s_box_out_1: [BinaryField32b; 24] =
+					array::from_fn(|j| sbox_fwd_affine(inv_1[j], inv_pow2_1[j], inv_pow4_1[j]));
+				let mds_out_1 = mds(&s_box_out_1);
+				let state_out: [BinaryField32b; 24] =
+					array::from_fn(|j| mds_out_1[j] + ROUND_KEYS[2 + 2 * round_i][j]);
+
+				for j in 0..STATE_SIZE {
+					state_in_32b[j][i] = state[j];
+					round_begin_32b[j][i] = round_begin[j];
+					inv_0_32b[j][i] = inv_0[j];
+					prod_0_32b[j][i] = round_begin[j] * inv_0[j];
+					s_box_out_0_32b[j][i] = s_box_out_0[j];
+					s_box_pow2_0_32b[j][i] = s_box_pow2_0[j];
+					s_box_pow4_0_32b[j][i] = s_box_pow4_0[j];
+					mds_out_0_32b[j][i] = mds_out_0[j];
+					round_out_0_32b[j][i] = round_out_0[j];
+					inv_1_32b[j][i] = inv_1[j];
+					prod_1_32b[j][i] = round_out_0[j] * inv_1[j];
+					inv_pow2_1_32b[j][i] = inv_pow2_1[j];
+					inv_pow4_1_32b[j][i] = inv_pow4_1[j];
+					s_box_out_1_32b[j][i] = s_box_out_1[j];
+					mds_out_1_32b[j][i] = mds_out_1[j];
+					state_out_32b[j][i] = state_out[j];
+					if round_i < ROWS_PER_PERMUTATION - 1 {
+						next_state_in_32b[j][i] = state_out[j];
+					}
+				}
+
+				state = state_out;
+			}
+
+			// Assert correct output
+			assert_eq!(state, output);
+		}
+
+		witness.set_owned::<BinaryField1b, _>([
+			(round_selector_single, round_selector_single_witness.to_vec()),
+			(round_selector, round_selector_witness),
+		])?;
+		witness.set_owned::<BinaryField32b, _>(iter::zip(
+			chain!(
+				state_in,
+				round_begin,
+				inv_0,
+				prod_0,
+				s_box_out_0,
+				s_box_pow2_0,
+				s_box_pow4_0,
+				mds_out_0,
+				round_out_0,
+				inv_1,
+				prod_1,
+				inv_pow2_1,
+				inv_pow4_1,
+				s_box_out_1,
+				mds_out_1,
+				state_out,
+				next_state_in,
+			),
+			chain!(
+				state_in_witness,
+				round_begin_witness,
+				inv_0_witness,
+				prod_0_witness,
+				s_box_out_0_witness,
+				s_box_pow2_0_witness,
+				s_box_pow4_0_witness,
+				mds_out_0_witness,
+				round_out_0_witness,
+				inv_1_witness,
+				prod_1_witness,
+				inv_pow2_1_witness,
+				inv_pow4_1_witness,
+				s_box_out_1_witness,
+				mds_out_1_witness,
+				state_out_witness,
+				next_state_in_witness,
+			),
+		))?;
+	}
+
+	// If y is the inverse of x, or both are zero, then x*y - prod == 0, x*(prod - 1) == 0 and
+	// y*(prod - 1) == 0
+	let product = composition_poly!([x, inv, prod] = x * inv - prod);
+	let prod_implies_input_zero = composition_poly!([x, prod] = x * (prod - 1));
+	let prod_implies_inverse_zero = composition_poly!([inv, prod] = inv * (prod - 1));
+	let square = composition_poly!([x, x_pow2] = x * x - x_pow2);
+	let consistency = composition_poly!(
+		[state_out, next_state_in, select] = (state_out - next_state_in) * select
+	);
+	for j in 0..STATE_SIZE {
+		for (x, inv, prod_x) in [
+			(round_begin[j], inv_0[j], prod_0[j]),
+			(round_out_0[j], inv_1[j], prod_1[j]),
+		] {
+			builder.assert_zero([x, inv, prod_x], product);
+			builder.assert_zero([x, prod_x], prod_implies_input_zero);
+			builder.assert_zero([inv, prod_x], prod_implies_inverse_zero);
+		}
+
+		for (x, x_pow2) in [
+			(s_box_out_0[j], s_box_pow2_0[j]),
+			(s_box_pow2_0[j], s_box_pow4_0[j]),
+			(inv_1[j], inv_pow2_1[j]),
+			(inv_pow2_1[j], inv_pow4_1[j]),
+		] {
+			builder.assert_zero([x, x_pow2], square);
+		}
+
+		builder.assert_zero([state_out[j], next_state_in[j], round_selector], consistency);
+	}
+
+	builder.pop_namespace();
+	Ok(state_out)
+}
+
+/// Adds a transparent column repeating `values` in every block of rows of a permutation, along
+/// with its witness.
+fn add_round_pattern<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	log_size: usize,
+	values: [BinaryField32b; ROWS_PER_PERMUTATION],
+) -> Result<OracleId>
+where
+	U: UnderlierType + Pod + PackScalar<F> + PackScalar<BinaryField32b>,
+	F: TowerField + ExtensionField<BinaryField32b>,
+{
+	let name = name.to_string();
+	let log_width = <PackedType<U, BinaryField32b>>::LOG_WIDTH;
+	// The single copy spans at least one packed element
+	let log_single = LOG_ROWS_PER_PERMUTATION.max(log_width);
+	let fill = |column: &mut [U]| {
+		for (z, value) in must_cast_slice_mut::<_, BinaryField32b>(column)
+			.iter_mut()
+			.enumerate()
+		{
+			*value = values[z % ROWS_PER_PERMUTATION];
+		}
+	};
+
+	let mut single_witness = vec![U::default(); 1 << (log_single - log_width)];
+	fill(&mut single_witness);
+	let single = builder.add_transparent(
+		format!("{name}_single"),
+		MultilinearExtensionTransparent::<_, PackedType<U, F>, _>::from_values(
+			single_witness
+				.iter()
+				.copied()
+				.map(PackedType::<U, BinaryField32b>::from_underlier)
+				.collect::<Vec<_>>(),
+		)?,
+	)?;
+	let repeated = builder.add_repeating(name, single, log_size - log_single)?;
+
+	if let Some(witness) = builder.witness() {
+		let mut repeated_witness = vec![U::default(); 1 << (log_size - log_width)];
+		fill(&mut repeated_witness);
+		witness.set_owned::<BinaryField32b, _>([
+			(single, single_witness),
+			(repeated, repeated_witness),
+		])?;
+	}
+
+	Ok(repeated)
+}
+
+/// Adds the forward S-box affine transformation of `x`, given the columns of `x`, `x^2` and `x^4`.
+///
+/// The witness is filled by the caller.
+fn add_sbox_fwd_affine<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	log_size: usize,
+	x_pows: [OracleId; 3],
+) -> Result<OracleId>
+where
+	U: UnderlierType + PackScalar<F>,
+	F: TowerField + ExtensionField<BinaryField32b>,
+{
+	Ok(builder.add_linear_combination_with_offset(
+		name,
+		log_size,
+		F::from(SBOX_FWD_CONST),
+		iter::zip(x_pows, SBOX_FWD_TRANS.map(F::from)),
+	)?)
+}
+
+/// Adds the product of the MDS matrix with the state `x`.
+///
+/// The witness is filled by the caller.
+fn add_mds<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: &str,
+	log_size: usize,
+	x: &[OracleId; 24],
+) -> Result<[OracleId; 24]>
+where
+	U: UnderlierType + PackScalar<F>,
+	F: TowerField + ExtensionField<BinaryField32b>,
+{
+	Ok(add_state_oracles(|row| {
+		builder.add_linear_combination(
+			format!("{name}[{row}]"),
+			log_size,
+			iter::zip(x.iter().copied(), MDS_TRANS[row])
+				.map(|(x_i, coeff)| (x_i, F::from(BinaryField32b::new(coeff as u32)))),
+		)
+	})?)
+}
+
+/// Adds one oracle per state element, stopping at the first error.
+fn add_state_oracles<E>(
+	mut add: impl FnMut(usize) -> Result<OracleId, E>,
+) -> Result<[OracleId; STATE_SIZE], E> {
+	let mut ids = [OracleId::default(); STATE_SIZE];
+	for (j, id) in ids.iter_mut().enumerate() {
+		*id = add(j)?;
+	}
+	Ok(ids)
+}
+
+fn sbox_fwd_affine(
+	x: BinaryField32b,
+	x_pow2: BinaryField32b,
+	x_pow4: BinaryField32b,
+) -> BinaryField32b {
+	SBOX_FWD_CONST + SBOX_FWD_TRANS[0] * x + SBOX_FWD_TRANS[1] * x_pow2 + SBOX_FWD_TRANS[2] * x_pow4
+}
+
+/// The inverse S-box affine transformation, evaluated in the AES basis.
+fn sbox_inv_affine(x: [BinaryField32b; 24]) -> [BinaryField32b; 24] {
+	let packed: [PackedAESBinaryField8x32b; 3] = array::from_fn(|arr_idx| {
+		PackedAESBinaryField8x32b::from_fn(|pack_idx| {
+			AESTowerField32b::from(x[pack_idx + arr_idx * 8])
+		})
+	});
+	let transformed = packed.map(|chunk| INV_PACKED_TRANS_AES.transform(&chunk));
+	array::from_fn(|j| BinaryField32b::from(transformed[j / 8].get(j % 8)) + SBOX_INV_CONST)
+}
+
+fn mds(x: &[BinaryField32b; 24]) -> [BinaryField32b; 24] {
+	array::from_fn(|row| {
+		iter::zip(x, MDS_TRANS[row])
+			.map(|(&x_i, coeff)| x_i * BinaryField32b::new(coeff as u32))
+			.sum()
+	})
+}
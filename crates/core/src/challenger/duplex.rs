@@ -2,13 +2,12 @@
 
 use super::field_challenger::{FieldChallenger, FieldChallengerHelper};
 use binius_field::Field;
+use binius_hash::DuplexSponge;
 use p3_symmetric::CryptographicPermutation;
 
 #[derive(Debug, Clone)]
 struct DuplexSpongeChallenger<F, Perm, const RATE: usize, const STATE_SIZE: usize> {
-	permutation: Perm,
-	sponge_state: [F; STATE_SIZE],
-	index: usize,
+	sponge: DuplexSponge<F, Perm, RATE, STATE_SIZE>,
 }
 
 impl<F, Perm, const RATE: usize, const STATE_SIZE: usize> Default
@@ -19,9 +18,7 @@ where
 {
 	fn default() -> Self {
 		Self {
-			permutation: Perm::default(),
-			sponge_state: [F::default(); STATE_SIZE],
-			index: 0,
+			sponge: DuplexSponge::default(),
 		}
 	}
 }
@@ -34,22 +31,12 @@ where
 {
 	const RATE: usize = RATE;
 
-	// TODO: Think about padding
 	fn sample(&mut self, output: &mut [F]) {
-		self.permutation.permute_mut(&mut self.sponge_state);
-		self.index = 0;
-		output.copy_from_slice(&self.sponge_state[..RATE]);
+		self.sponge.squeeze(output);
 	}
 
 	fn observe(&mut self, input: &[F]) {
-		for &elem in input {
-			if self.index == RATE {
-				self.permutation.permute_mut(&mut self.sponge_state);
-				self.index = 0;
-			}
-			self.sponge_state[self.index] = elem;
-			self.index += 1;
-		}
+		self.sponge.absorb(input);
 	}
 }
 
@@ -67,9 +54,10 @@ where
 mod tests {
 	use super::*;
 	use binius_field::{
-		BinaryField128b, BinaryField32b, BinaryField64b, PackedBinaryField4x64b, PackedField,
+		BinaryField128b, BinaryField32b, BinaryField64b, BinaryField8b, PackedBinaryField4x64b,
+		PackedField,
 	};
-	use binius_hash::Vision32bPermutation;
+	use binius_hash::{GroestlP, Vision32bPermutation};
 	use p3_challenger::{CanObserve, CanSample, CanSampleBits};
 	use rand::{thread_rng, Rng};
 
@@ -98,6 +86,22 @@ mod tests {
 		challenger.observe(obs);
 	}
 
+	#[test]
+	fn test_duplex_challenger_with_groestl_permutation() {
+		let new_groestl_challenger = || new::<BinaryField8b, GroestlP, 32, 64>();
+		let obs = PackedBinaryField4x64b::from_fn(|i| BinaryField64b::new(i as u64));
+
+		let mut challenger = new_groestl_challenger();
+		challenger.observe(obs);
+		let a: BinaryField128b = challenger.sample();
+		let b: BinaryField128b = challenger.sample();
+		assert_ne!(a, b);
+
+		let mut challenger = new_groestl_challenger();
+		challenger.observe(obs);
+		assert_eq!(CanSample::<BinaryField128b>::sample(&mut challenger), a);
+	}
+
 	#[test]
 	fn test_duplex_challenger_can_sample_bits() {
 		let mut challenger = new_vision_32b_challenger();
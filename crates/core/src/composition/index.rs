@@ -2,7 +2,7 @@
 
 use crate::polynomial::Error;
 use binius_field::PackedField;
-use binius_math::CompositionPoly;
+use binius_math::{ArithExpr, CompositionPoly};
 use binius_utils::bail;
 use std::fmt::Debug;
 
@@ -58,6 +58,10 @@ impl<P: PackedField, C: CompositionPoly<P>, const N: usize> CompositionPoly<P>
 		self.composition.binary_tower_level()
 	}
 
+	fn expression(&self) -> Option<ArithExpr<P::Scalar>> {
+		self.composition.expression()?.remap_vars(&self.indices)
+	}
+
 	fn batch_evaluate(
 		&self,
 		batch_query: &[&[P]],
@@ -1,7 +1,7 @@
 // Copyright 2024 Irreducible Inc.
 
 use binius_field::PackedField;
-use binius_math::CompositionPoly;
+use binius_math::{ArithExpr, CompositionPoly};
 use binius_utils::bail;
 
 #[derive(Debug, Copy, Clone)]
@@ -36,6 +36,12 @@ impl<P: PackedField, const N: usize> CompositionPoly<P> for ProductComposition<N
 	fn binary_tower_level(&self) -> usize {
 		0
 	}
+
+	fn expression(&self) -> Option<ArithExpr<P::Scalar>> {
+		(0..N)
+			.map(ArithExpr::Var)
+			.reduce(|product, var| product * var)
+	}
 }
 
 pub type BivariateProduct = ProductComposition<2>;
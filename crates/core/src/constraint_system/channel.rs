@@ -52,6 +52,8 @@
 use super::error::{Error, VerificationError};
 use crate::{oracle::OracleId, witness::MultilinearExtensionIndex};
 use binius_field::{as_packed_field::PackScalar, underlier::UnderlierType, TowerField};
+use binius_utils::serialization::{DeserializeBytes, Error as SerializationError, SerializeBytes};
+use bytes::{Buf, BufMut};
 use std::{collections::HashMap, hash::Hash};
 
 pub type ChannelId = usize;
@@ -63,6 +65,24 @@ pub struct Flush {
 	pub direction: FlushDirection,
 }
 
+impl SerializeBytes for Flush {
+	fn serialize(&self, mut write_buf: impl BufMut) -> Result<(), SerializationError> {
+		self.oracles.serialize(&mut write_buf)?;
+		self.channel_id.serialize(&mut write_buf)?;
+		self.direction.serialize(write_buf)
+	}
+}
+
+impl DeserializeBytes for Flush {
+	fn deserialize(mut read_buf: impl Buf) -> Result<Self, SerializationError> {
+		Ok(Self {
+			oracles: Vec::deserialize(&mut read_buf)?,
+			channel_id: ChannelId::deserialize(&mut read_buf)?,
+			direction: FlushDirection::deserialize(read_buf)?,
+		})
+	}
+}
+
 #[derive(Debug, Clone)]
 pub struct Boundary<F: TowerField> {
 	pub values: Vec<F>,
@@ -77,6 +97,29 @@ pub enum FlushDirection {
 	Pull,
 }
 
+impl SerializeBytes for FlushDirection {
+	fn serialize(&self, write_buf: impl BufMut) -> Result<(), SerializationError> {
+		let index: u8 = match self {
+			FlushDirection::Push => 0,
+			FlushDirection::Pull => 1,
+		};
+		index.serialize(write_buf)
+	}
+}
+
+impl DeserializeBytes for FlushDirection {
+	fn deserialize(read_buf: impl Buf) -> Result<Self, SerializationError> {
+		match u8::deserialize(read_buf)? {
+			0 => Ok(FlushDirection::Push),
+			1 => Ok(FlushDirection::Pull),
+			index => Err(SerializationError::UnknownEnumVariant {
+				name: "FlushDirection",
+				index,
+			}),
+		}
+	}
+}
+
 pub fn validate_witness<U, F>(
 	witness: &MultilinearExtensionIndex<U, F>,
 	flushes: &[Flush],
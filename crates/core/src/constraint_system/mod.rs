@@ -7,7 +7,10 @@ mod prove;
 pub mod validate;
 mod verify;
 
-use binius_field::{PackedField, TowerField};
+use binius_field::{deserialize_canonical_vec, serialize_canonical_slice, PackedField, TowerField};
+use binius_hash::{hash_algorithm_id, HashAlgorithmId};
+use binius_utils::serialization::{DeserializeBytes, Error as SerializationError, SerializeBytes};
+use bytes::{Buf, BufMut};
 use channel::{ChannelId, Flush};
 pub use prove::prove;
 pub use verify::verify;
@@ -19,6 +22,8 @@ use crate::{
 	protocols::{
 		gkr_gpa::GrandProductBatchProof, greedy_evalcheck::GreedyEvalcheckProof, sumcheck,
 	},
+	tower::TowerFamily,
+	transcript::{ProofConfig, ProofEnvelope},
 };
 
 /// Contains the 3 things that place constraints on witness data in Binius
@@ -37,6 +42,59 @@ pub struct ConstraintSystem<P: PackedField<Scalar: TowerField>> {
 	pub max_channel_id: ChannelId,
 }
 
+/// A constraint system is encoded with its compositions as arithmetic expressions, see
+/// [`crate::oracle::Constraint`], so that a verifier can load a circuit without the code that
+/// built it.
+impl<P: PackedField<Scalar: TowerField>> SerializeBytes for ConstraintSystem<P> {
+	fn serialize(&self, mut write_buf: impl BufMut) -> Result<(), SerializationError> {
+		self.oracles.serialize(&mut write_buf)?;
+		self.table_constraints.serialize(&mut write_buf)?;
+		self.non_zero_oracle_ids.serialize(&mut write_buf)?;
+		self.flushes.serialize(&mut write_buf)?;
+		self.max_channel_id.serialize(write_buf)
+	}
+}
+
+impl<P: PackedField<Scalar: TowerField>> DeserializeBytes for ConstraintSystem<P> {
+	fn deserialize(mut read_buf: impl Buf) -> Result<Self, SerializationError> {
+		let constraint_system = Self {
+			oracles: MultilinearOracleSet::deserialize(&mut read_buf)?,
+			table_constraints: Vec::deserialize(&mut read_buf)?,
+			non_zero_oracle_ids: Vec::deserialize(&mut read_buf)?,
+			flushes: Vec::deserialize(&mut read_buf)?,
+			max_channel_id: ChannelId::deserialize(read_buf)?,
+		};
+
+		let oracle_ids = constraint_system
+			.table_constraints
+			.iter()
+			.flat_map(|constraint_set| &constraint_set.oracle_ids)
+			.chain(&constraint_system.non_zero_oracle_ids)
+			.chain(
+				constraint_system
+					.flushes
+					.iter()
+					.flat_map(|flush| &flush.oracles),
+			);
+		for &id in oracle_ids {
+			if !constraint_system.oracles.is_valid_oracle_id(id) {
+				return Err(SerializationError::InvalidValue(format!("invalid oracle ID {id}")));
+			}
+		}
+		if let Some(flush) = constraint_system
+			.flushes
+			.iter()
+			.find(|flush| flush.channel_id > constraint_system.max_channel_id)
+		{
+			return Err(SerializationError::InvalidValue(format!(
+				"flush channel ID {} exceeds the maximum channel ID {}",
+				flush.channel_id, constraint_system.max_channel_id
+			)));
+		}
+		Ok(constraint_system)
+	}
+}
+
 /// Constraint system proof with the standard PCS.
 pub type Proof<F, Digest, Hash, Compress> = ProofGenericPCS<
 	F,
@@ -53,6 +111,60 @@ pub struct ProofGenericPCS<F: TowerField, PCSComm, PCSProof> {
 	pub zerocheck_proof: sumcheck::Proof<F>,
 	pub greedy_evalcheck_proof: GreedyEvalcheckProof<F>,
 	pub pcs_proofs: Vec<PCSProof>,
-	pub transcript: Vec<u8>,
-	pub advice: Vec<u8>,
+	/// The transcript and advice, tagged with the configuration the proof was produced with
+	pub envelope: ProofEnvelope,
+}
+
+impl<F, PCSComm, PCSProof> SerializeBytes for ProofGenericPCS<F, PCSComm, PCSProof>
+where
+	F: TowerField,
+	PCSComm: SerializeBytes,
+	PCSProof: SerializeBytes,
+{
+	fn serialize(&self, mut write_buf: impl BufMut) -> Result<(), SerializationError> {
+		self.commitments.serialize(&mut write_buf)?;
+		serialize_canonical_slice(&self.flush_products, &mut write_buf)?;
+		self.prodcheck_proof.serialize(&mut write_buf)?;
+		self.zerocheck_proof.serialize(&mut write_buf)?;
+		self.greedy_evalcheck_proof.serialize(&mut write_buf)?;
+		self.pcs_proofs.serialize(&mut write_buf)?;
+		self.envelope.encode().serialize(write_buf)
+	}
+}
+
+impl<F, PCSComm, PCSProof> DeserializeBytes for ProofGenericPCS<F, PCSComm, PCSProof>
+where
+	F: TowerField,
+	PCSComm: DeserializeBytes,
+	PCSProof: DeserializeBytes,
+{
+	fn deserialize(mut read_buf: impl Buf) -> Result<Self, SerializationError> {
+		Ok(Self {
+			commitments: Vec::deserialize(&mut read_buf)?,
+			flush_products: deserialize_canonical_vec(&mut read_buf)?,
+			prodcheck_proof: GrandProductBatchProof::deserialize(&mut read_buf)?,
+			zerocheck_proof: sumcheck::Proof::deserialize(&mut read_buf)?,
+			greedy_evalcheck_proof: GreedyEvalcheckProof::deserialize(&mut read_buf)?,
+			pcs_proofs: Vec::deserialize(&mut read_buf)?,
+			envelope: ProofEnvelope::decode(&Vec::<u8>::deserialize(read_buf)?)
+				.map_err(|err| SerializationError::InvalidValue(err.to_string()))?,
+		})
+	}
+}
+
+/// The configuration recorded in the [`ProofEnvelope`] of proofs with the standard FRI PCS.
+///
+/// The configuration is built from the [`HashAlgorithmId`]s of the hash and compression
+/// functions, so that implementations of the same algorithms produce interchangeable proofs.
+pub fn standard_proof_config<Tower, Hash, Compress>() -> ProofConfig
+where
+	Tower: TowerFamily,
+	Hash: HashAlgorithmId,
+	Compress: HashAlgorithmId,
+{
+	ProofConfig::new(
+		format!("{}/b128", Tower::ID),
+		hash_algorithm_id::<Hash>(),
+		format!("fri/{}", hash_algorithm_id::<Compress>()),
+	)
 }
@@ -2,6 +2,7 @@
 
 use super::{
 	error::Error,
+	standard_proof_config,
 	verify::{make_flush_oracles, make_standard_pcss},
 	ConstraintSystem, Proof, ProofGenericPCS,
 };
@@ -19,7 +20,7 @@ use crate::{
 		},
 	},
 	tower::{PackedTop, TowerFamily, TowerUnderlier},
-	transcript::{AdviceWriter, CanWrite, TranscriptWriter},
+	transcript::{AdviceWriter, CanWrite, ProofConfig, ProofEnvelope, TranscriptWriter},
 	witness::MultilinearExtensionIndex,
 };
 use binius_field::{
@@ -28,13 +29,12 @@ use binius_field::{
 	ExtensionField, Field, PackedField, PackedFieldIndexable, RepackedExtension, TowerField,
 };
 use binius_hal::ComputationBackend;
-use binius_hash::Hasher;
+use binius_hash::{HashAlgorithmId, MultiHasher, MultiPseudoCompressionFunction};
 use binius_math::{
 	EvaluationDomainFactory, MLEDirectAdapter, MultilinearExtension, MultilinearPoly,
 };
 use binius_utils::bail;
 use itertools::izip;
-use p3_symmetric::PseudoCompressionFunction;
 use rayon::prelude::*;
 use std::cmp::Reverse;
 use tracing::instrument;
@@ -55,8 +55,8 @@ where
 	Tower::B128: PackedTop<Tower>,
 	DomainFactory: EvaluationDomainFactory<Tower::B8>,
 	Digest: PackedField<Scalar: TowerField>,
-	Hash: Hasher<Tower::B128, Digest = Digest> + Send + Sync,
-	Compress: PseudoCompressionFunction<Digest, 2> + Default + Sync,
+	Hash: MultiHasher<Tower::B128, Digest = Digest> + HashAlgorithmId + Send + Sync,
+	Compress: MultiPseudoCompressionFunction<Digest, 2> + HashAlgorithmId + Default + Sync,
 	Challenger_: Challenger + Default,
 	Backend: ComputationBackend,
 	PackedType<U, Tower::B128>:
@@ -74,6 +74,7 @@ where
 		&pcss,
 		domain_factory,
 		backend,
+		standard_proof_config::<Tower, Hash, Compress>(),
 	)
 }
 
@@ -85,6 +86,7 @@ fn prove_with_pcs<U, Tower, FDomain, PCSFamily, DomainFactory, Challenger_, Dige
 	pcss: &[TowerPCS<Tower, U, PCSFamily>],
 	domain_factory: DomainFactory,
 	backend: &Backend,
+	config: ProofConfig,
 ) -> Result<ProofGenericPCS<Tower::B128, PCSFamily::Commitment, PCSFamily::Proof>, Error>
 where
 	U: TowerUnderlier<Tower> + PackScalar<FDomain>,
@@ -328,8 +330,7 @@ where
 		zerocheck_proof,
 		greedy_evalcheck_proof,
 		pcs_proofs,
-		transcript: transcript.finalize(),
-		advice: advice.finalize(),
+		envelope: ProofEnvelope::new(config, transcript.finalize(), advice.finalize()),
 	})
 }
 
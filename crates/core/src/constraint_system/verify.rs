@@ -2,7 +2,7 @@
 
 use super::{
 	error::{Error, VerificationError},
-	ConstraintSystem, Proof, ProofGenericPCS,
+	standard_proof_config, ConstraintSystem, Proof, ProofGenericPCS,
 };
 use crate::{
 	challenger::{CanObserve, CanSample},
@@ -22,7 +22,7 @@ use crate::{
 		sumcheck::{self, constraint_set_zerocheck_claim, zerocheck},
 	},
 	tower::{PackedTop, TowerFamily, TowerUnderlier},
-	transcript::{AdviceReader, TranscriptReader},
+	transcript::{AdviceReader, ProofConfig, ProofEnvelope, TranscriptReader},
 };
 use binius_field::{
 	as_packed_field::{PackScalar, PackedType},
@@ -30,12 +30,11 @@ use binius_field::{
 	TowerField,
 };
 use binius_hal::make_portable_backend;
-use binius_hash::Hasher;
+use binius_hash::{HashAlgorithmId, MultiHasher, MultiPseudoCompressionFunction};
 use binius_math::EvaluationDomainFactory;
 use binius_ntt::NTTOptions;
 use binius_utils::bail;
 use itertools::{izip, Itertools};
-use p3_symmetric::PseudoCompressionFunction;
 use p3_util::log2_ceil_usize;
 use std::cmp::Reverse;
 use tracing::instrument;
@@ -55,8 +54,8 @@ where
 	Tower::B128: PackedTop<Tower>,
 	DomainFactory: EvaluationDomainFactory<Tower::B8>,
 	Digest: PackedField<Scalar: TowerField>,
-	Hash: Hasher<Tower::B128, Digest = Digest> + Send + Sync,
-	Compress: PseudoCompressionFunction<Digest, 2> + Default + Sync,
+	Hash: MultiHasher<Tower::B128, Digest = Digest> + HashAlgorithmId + Send + Sync,
+	Compress: MultiPseudoCompressionFunction<Digest, 2> + HashAlgorithmId + Default + Sync,
 	Challenger_: Challenger + Default,
 	PackedType<U, Tower::B128>:
 		PackedTop<Tower> + PackedFieldIndexable + RepackedExtension<PackedType<U, Tower::B128>>,
@@ -67,7 +66,12 @@ where
 		&constraint_system.oracles,
 		domain_factory,
 	)?;
-	verify_with_pcs::<_, _, _, Challenger_, Digest>(constraint_system, proof, &pcss)
+	verify_with_pcs::<_, _, _, Challenger_, Digest>(
+		constraint_system,
+		proof,
+		&pcss,
+		&standard_proof_config::<Tower, Hash, Compress>(),
+	)
 }
 
 /// Verifies a proof against a constraint system with provided PCSs.
@@ -75,6 +79,7 @@ fn verify_with_pcs<U, Tower, PCSFamily, Challenger_, Digest>(
 	constraint_system: &ConstraintSystem<PackedType<U, FExt<Tower>>>,
 	proof: ProofGenericPCS<FExt<Tower>, PCSFamily::Commitment, PCSFamily::Proof>,
 	pcss: &[TowerPCS<Tower, U, PCSFamily>],
+	config: &ProofConfig,
 ) -> Result<(), Error>
 where
 	U: TowerUnderlier<Tower>,
@@ -108,10 +113,14 @@ where
 		zerocheck_proof,
 		greedy_evalcheck_proof,
 		pcs_proofs,
-		transcript,
-		advice,
+		envelope,
 	} = proof;
 
+	envelope.check_config(config)?;
+	let ProofEnvelope {
+		transcript, advice, ..
+	} = envelope;
+
 	let mut transcript = TranscriptReader::<Challenger_>::new(transcript);
 	let advice = AdviceReader::new(advice);
 
@@ -227,8 +236,8 @@ where
 	Tower::B128: PackedTop<Tower>,
 	DomainFactory: EvaluationDomainFactory<Tower::B8>,
 	Digest: PackedField,
-	Hash: Hasher<Tower::B128, Digest = Digest> + Send + Sync,
-	Compress: PseudoCompressionFunction<Digest, 2> + Default + Sync,
+	Hash: MultiHasher<Tower::B128, Digest = Digest> + Send + Sync,
+	Compress: MultiPseudoCompressionFunction<Digest, 2> + Default + Sync,
 	PackedType<U, Tower::B128>: PackedTop<Tower> + PackedFieldIndexable,
 {
 	oracles
@@ -298,8 +307,8 @@ where
 	F: TowerField,
 	DomainFactory: EvaluationDomainFactory<Tower::B8>,
 	Digest: PackedField,
-	Hash: Hasher<Tower::B128, Digest = Digest> + Send + Sync,
-	Compress: PseudoCompressionFunction<Digest, 2> + Default + Sync,
+	Hash: MultiHasher<Tower::B128, Digest = Digest> + Send + Sync,
+	Compress: MultiPseudoCompressionFunction<Digest, 2> + Default + Sync,
 	PackedType<U, Tower::B128>: PackedTop<Tower> + PackedFieldIndexable,
 {
 	let merkle_prover = BinaryMerkleTreeProver::<_, Hash, _>::new(Compress::default());
@@ -1,6 +1,7 @@
 // Copyright 2023-2024 Irreducible Inc.
 #![feature(step_trait)]
 #![feature(get_many_mut)]
+#![feature(slice_as_chunks)]
 // This is to silence clippy errors around suspicious usage of XOR
 // in our arithmetic. This is safe to do becasue we're operating
 // over binary fields.
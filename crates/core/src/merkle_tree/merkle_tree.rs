@@ -4,17 +4,20 @@ use super::{
 	error::{Error, VerificationError},
 	vcs::VectorCommitScheme,
 };
-use crate::challenger::{field_challenger::FieldChallengerHelper, FieldChallenger};
+use crate::{
+	challenger::{field_challenger::FieldChallengerHelper, FieldChallenger},
+	merkle_tree_vcs::jobs::{job_len, write_digests},
+};
 use binius_field::{
 	BinaryField, ExtensionField, Field, PackedExtension, PackedField, PackedFieldIndexable,
 };
-use binius_hash::Hasher;
+use binius_hash::{MultiHasher, MultiPseudoCompressionFunction};
 use binius_utils::bail;
 use p3_challenger::CanObserve;
-use p3_symmetric::PseudoCompressionFunction;
 use p3_util::log2_strict_usize;
 use rayon::prelude::*;
 use std::{
+	iter,
 	marker::PhantomData,
 	mem::{self, MaybeUninit},
 	ops::Range,
@@ -54,8 +57,8 @@ where
 	) -> Result<Self, Error>
 	where
 		T: Sync,
-		H: Hasher<T, Digest = D> + Send,
-		C: PseudoCompressionFunction<D, 2> + Sync,
+		H: MultiHasher<T, Digest = D> + Send,
+		C: MultiPseudoCompressionFunction<D, 2> + Sync,
 	{
 		Self::build(
 			compression,
@@ -73,8 +76,8 @@ where
 	) -> Result<Self, Error>
 	where
 		T: Sync,
-		H: Hasher<T, Digest = D> + Send,
-		C: PseudoCompressionFunction<D, 2> + Sync,
+		H: MultiHasher<T, Digest = D> + Send,
+		C: MultiPseudoCompressionFunction<D, 2> + Sync,
 	{
 		Self::build(
 			compression,
@@ -92,8 +95,8 @@ where
 		batch_size: usize,
 	) -> Result<Self, Error>
 	where
-		H: Hasher<T, Digest = D> + Send,
-		C: PseudoCompressionFunction<D, 2> + Sync,
+		H: MultiHasher<T, Digest = D> + Send,
+		C: MultiPseudoCompressionFunction<D, 2> + Sync,
 		ParIter: IndexedParallelIterator<Item: IntoIterator<Item = T>>,
 	{
 		Self::build(
@@ -112,7 +115,7 @@ where
 		cap_height: usize,
 	) -> Result<Self, Error>
 	where
-		C: PseudoCompressionFunction<D, 2> + Sync,
+		C: MultiPseudoCompressionFunction<D, 2> + Sync,
 	{
 		if cap_height > log_len {
 			bail!(Error::IncorrectCapHeight);
@@ -204,20 +207,16 @@ where
 	#[tracing::instrument("MerkleTree::compress_layer", skip_all, level = "debug")]
 	fn compress_layer<C>(compression: &C, prev_layer: &[D], next_layer: &mut [MaybeUninit<D>])
 	where
-		C: PseudoCompressionFunction<D, 2> + Sync,
+		C: MultiPseudoCompressionFunction<D, 2> + Sync,
 	{
+		let job_len = job_len(C::BATCH_WIDTH);
 		prev_layer
-			.par_chunks_exact(2)
-			.zip(next_layer.par_iter_mut())
-			.for_each(|(prev_pair, next_digest)| {
-				next_digest.write(
-					compression.compress(
-						prev_pair
-							.try_into()
-							.expect("prev_pair is an chunk of exactly 2 elements"),
-					),
-				);
-			})
+			.par_chunks(2 * job_len)
+			.zip(next_layer.par_chunks_mut(job_len))
+			.for_each(|(prev_pairs, next_digests)| {
+				let (pairs, _) = prev_pairs.as_chunks::<2>();
+				write_digests(next_digests, compression.compress_many(pairs));
+			});
 	}
 }
 
@@ -234,7 +233,7 @@ fn hash_strided<T, H>(
 ) -> Result<usize, Error>
 where
 	T: Sync,
-	H: Hasher<T> + Send,
+	H: MultiHasher<T> + Send,
 	H::Digest: Send,
 {
 	let leaves = leaves
@@ -250,15 +249,26 @@ where
 		})
 		.collect::<Result<Vec<_>, _>>()?;
 
-	digests
-		.par_iter_mut()
-		.enumerate()
-		.for_each_init(H::new, |hasher, (i, digest)| {
-			for elems in leaves.iter() {
-				hasher.update(slice::from_ref(&elems[i]))
+	// Every message consists of one part per vector, or of a single empty part if there are none
+	let parts_per_message = leaves.len().max(1);
+	let job_len = job_len(H::BATCH_WIDTH);
+	digests.par_chunks_mut(job_len).enumerate().for_each_init(
+		Vec::new,
+		|parts, (job_index, digests)| {
+			let start = job_index * job_len;
+			parts.clear();
+			for i in start..start + digests.len() {
+				if leaves.is_empty() {
+					parts.push(&[][..]);
+				}
+				parts.extend(leaves.iter().map(|elems| slice::from_ref(&elems[i])));
 			}
-			hasher.finalize_into_reset(digest);
-		});
+			let messages = parts
+				.chunks(parts_per_message)
+				.map(|message| message.iter().copied());
+			write_digests(digests, H::hash_many_parts(messages));
+		},
+	);
 
 	Ok(leaves.len())
 }
@@ -275,7 +285,7 @@ fn hash_interleaved<T, H>(
 ) -> Result<usize, Error>
 where
 	T: Sync,
-	H: Hasher<T> + Send,
+	H: MultiHasher<T> + Send,
 	H::Digest: Send,
 {
 	if elems.len() % digests.len() != 0 {
@@ -286,12 +296,12 @@ where
 
 	let batch_size = elems.len() / digests.len();
 
+	let job_len = job_len(H::BATCH_WIDTH);
 	digests
-		.par_iter_mut()
-		.zip(elems.par_chunks(batch_size))
-		.for_each_init(H::new, |hasher, (digest, elems)| {
-			hasher.update(elems);
-			hasher.finalize_into_reset(digest);
+		.par_chunks_mut(job_len)
+		.zip(elems.par_chunks(batch_size * job_len))
+		.for_each(|(digests, elems)| {
+			write_digests(digests, H::hash_many_parts(elems.chunks(batch_size).map(iter::once)));
 		});
 
 	Ok(batch_size)
@@ -303,19 +313,30 @@ fn hash_iterated<T, H, ParIter>(
 	batch_size: usize,
 ) -> Result<usize, Error>
 where
-	H: Hasher<T> + Send,
+	H: MultiHasher<T> + Send,
 	H::Digest: Send,
 	ParIter: IndexedParallelIterator<Item: IntoIterator<Item = T>>,
 {
+	let job_len = job_len(H::BATCH_WIDTH);
 	digests
-		.par_iter_mut()
-		.zip(iterated_chunks)
-		.for_each_init(H::new, |hasher, (digest, elems)| {
-			for elem in elems {
-				hasher.update(std::slice::from_ref(&elem));
-			}
-			hasher.finalize_into_reset(digest);
-		});
+		.par_chunks_mut(job_len)
+		.zip(iterated_chunks.chunks(job_len))
+		.for_each_init(
+			|| (Vec::new(), Vec::new()),
+			|(elems, offsets), (digests, chunks)| {
+				elems.clear();
+				offsets.clear();
+				offsets.push(0);
+				for chunk in chunks {
+					elems.extend(chunk);
+					offsets.push(elems.len());
+				}
+				let messages = offsets
+					.windows(2)
+					.map(|bounds| iter::once(&elems[bounds[0]..bounds[1]]));
+				write_digests(digests, H::hash_many_parts(messages));
+			},
+		);
 
 	Ok(batch_size)
 }
@@ -348,8 +369,8 @@ impl<P, D, H, C> VectorCommitScheme<P> for MerkleTreeVCS<P, D, H, C>
 where
 	P: PackedField + Sync,
 	D: PackedField + Send + Sync,
-	H: Hasher<P, Digest = D> + Send,
-	C: PseudoCompressionFunction<D, 2> + Sync,
+	H: MultiHasher<P, Digest = D> + Send,
+	C: MultiPseudoCompressionFunction<D, 2> + Sync,
 {
 	type Commitment = MerkleCap<D>;
 	type Committed = MerkleTree<D>;
@@ -582,7 +603,9 @@ mod tests {
 	use super::*;
 	use assert_matches::assert_matches;
 	use binius_field::{BinaryField16b, BinaryField8b};
-	use binius_hash::{GroestlDigestCompression, GroestlHasher};
+	use binius_hash::{
+		BsGroestl256Hasher, BsGroestlDigestCompression, GroestlDigestCompression, GroestlHasher,
+	};
 	use rand::{rngs::StdRng, SeedableRng};
 	use std::iter::repeat_with;
 
@@ -656,6 +679,35 @@ mod tests {
 		}
 	}
 
+	#[test]
+	fn test_merkle_vcs_batched_hasher_matches_commitment() {
+		let mut rng = StdRng::seed_from_u64(0);
+
+		// Several vectors, so that every leaf is hashed from one part per vector
+		let vecs = repeat_with(|| {
+			repeat_with(|| Field::random(&mut rng))
+				.take(256)
+				.collect::<Vec<BinaryField16b>>()
+		})
+		.take(3)
+		.collect::<Vec<_>>();
+
+		let vcs = <MerkleTreeVCS<_, _, GroestlHasher<_>, _>>::new(
+			8,
+			2,
+			GroestlDigestCompression::<BinaryField8b>::default(),
+		);
+		let bs_vcs = <MerkleTreeVCS<_, _, BsGroestl256Hasher<_, BinaryField8b>, _>>::new(
+			8,
+			2,
+			BsGroestlDigestCompression::<BinaryField8b>::default(),
+		);
+
+		let (commitment, _) = vcs.commit_batch(&vecs).unwrap();
+		let (bs_commitment, _) = bs_vcs.commit_batch(&vecs).unwrap();
+		assert_eq!(bs_commitment, commitment);
+	}
+
 	#[test]
 	fn test_merkle_vcs_commit_prove_range_open_correctly() {
 		let mut rng = StdRng::seed_from_u64(0);
@@ -884,4 +936,4 @@ mod tests {
 		assert_eq!(vcs.proof_size(1), 4 * 32);
 		assert_eq!(vcs.proof_size(2), 4 * 32);
 	}
-}
\ No newline at end of file
+}
@@ -1,12 +1,14 @@
 // Copyright 2024 Irreducible Inc.
 
-use super::errors::Error;
-use binius_hash::Hasher;
+use super::{
+	errors::Error,
+	jobs::{job_len, write_digests},
+};
+use binius_hash::{MultiHasher, MultiPseudoCompressionFunction};
 use binius_utils::bail;
-use p3_symmetric::PseudoCompressionFunction;
 use p3_util::log2_strict_usize;
 use rayon::{prelude::*, slice::ParallelSlice};
-use std::{fmt::Debug, mem::MaybeUninit};
+use std::{fmt::Debug, iter, mem::MaybeUninit};
 use tracing::instrument;
 
 /// A binary Merkle tree that commits batches of vectors.
@@ -14,6 +16,10 @@ use tracing::instrument;
 /// The vector entries at each index in a batch are hashed together into leaf digests. Then a
 /// Merkle tree is constructed over the leaf digests. The implementation requires that the vector
 /// lengths are all equal to each other and a power of two.
+///
+/// Leaves and inner nodes are hashed in groups of [`MultiHasher::BATCH_WIDTH`] and
+/// [`MultiPseudoCompressionFunction::BATCH_WIDTH`] respectively, so that batched hash
+/// implementations are fed full batches. The resulting tree does not depend on the grouping.
 #[derive(Debug, Clone)]
 pub struct BinaryMerkleTree<D> {
 	/// Base-2 logarithm of the number of leaves
@@ -29,8 +35,8 @@ where
 	pub fn build<T, H, C>(compression: &C, elements: &[T], batch_size: usize) -> Result<Self, Error>
 	where
 		T: Sync,
-		H: Hasher<T, Digest = D> + Send,
-		C: PseudoCompressionFunction<D, 2> + Sync,
+		H: MultiHasher<T, Digest = D> + Send,
+		C: MultiPseudoCompressionFunction<D, 2> + Sync,
 	{
 		if elements.len() % batch_size != 0 {
 			bail!(Error::IncorrectBatchSize);
@@ -58,7 +64,7 @@ where
 		log_len: usize,
 	) -> Result<Self, Error>
 	where
-		C: PseudoCompressionFunction<D, 2> + Sync,
+		C: MultiPseudoCompressionFunction<D, 2> + Sync,
 	{
 		let total_length = (1 << (log_len + 1)) - 1;
 		let mut inner_nodes = Vec::with_capacity(total_length);
@@ -103,8 +109,8 @@ where
 		log_len: usize,
 	) -> Result<Self, Error>
 	where
-		H: Hasher<T, Digest = D> + Send,
-		C: PseudoCompressionFunction<D, 2> + Sync,
+		H: MultiHasher<T, Digest = D> + Send,
+		C: MultiPseudoCompressionFunction<D, 2> + Sync,
 		ParIter: IndexedParallelIterator<Item: IntoIterator<Item = T>>,
 	{
 		Self::internal_build(
@@ -153,20 +159,16 @@ where
 	#[tracing::instrument("MerkleTree::compress_layer", skip_all, level = "debug")]
 	fn compress_layer<C>(compression: &C, prev_layer: &[D], next_layer: &mut [MaybeUninit<D>])
 	where
-		C: PseudoCompressionFunction<D, 2> + Sync,
+		C: MultiPseudoCompressionFunction<D, 2> + Sync,
 	{
+		let job_len = job_len(C::BATCH_WIDTH);
 		prev_layer
-			.par_chunks_exact(2)
-			.zip(next_layer.par_iter_mut())
-			.for_each(|(prev_pair, next_digest)| {
-				next_digest.write(
-					compression.compress(
-						prev_pair
-							.try_into()
-							.expect("prev_pair is an chunk of exactly 2 elements"),
-					),
-				);
-			})
+			.par_chunks(2 * job_len)
+			.zip(next_layer.par_chunks_mut(job_len))
+			.for_each(|(prev_pairs, next_digests)| {
+				let (pairs, _) = prev_pairs.as_chunks::<2>();
+				write_digests(next_digests, compression.compress_many(pairs));
+			});
 	}
 }
 
@@ -179,7 +181,7 @@ where
 fn hash_interleaved<T, H>(elems: &[T], digests: &mut [MaybeUninit<H::Digest>]) -> Result<(), Error>
 where
 	T: Sync,
-	H: Hasher<T> + Send,
+	H: MultiHasher<T> + Send,
 	H::Digest: Send,
 {
 	if elems.len() % digests.len() != 0 {
@@ -188,12 +190,13 @@ where
 		});
 	}
 	let batch_size = elems.len() / digests.len();
+
+	let job_len = job_len(H::BATCH_WIDTH);
 	digests
-		.par_iter_mut()
-		.zip(elems.par_chunks(batch_size))
-		.for_each_init(H::new, |hasher, (digest, elems)| {
-			hasher.update(elems);
-			hasher.finalize_into_reset(digest);
+		.par_chunks_mut(job_len)
+		.zip(elems.par_chunks(batch_size * job_len))
+		.for_each(|(digests, elems)| {
+			write_digests(digests, H::hash_many_parts(elems.chunks(batch_size).map(iter::once)));
 		});
 	Ok(())
 }
@@ -203,19 +206,30 @@ fn hash_iterated<T, H, ParIter>(
 	digests: &mut [MaybeUninit<H::Digest>],
 ) -> Result<(), Error>
 where
-	H: Hasher<T> + Send,
+	H: MultiHasher<T> + Send,
 	H::Digest: Send,
 	ParIter: IndexedParallelIterator<Item: IntoIterator<Item = T>>,
 {
+	let job_len = job_len(H::BATCH_WIDTH);
 	digests
-		.par_iter_mut()
-		.zip(iterated_chunks)
-		.for_each_init(H::new, |hasher, (digest, elems)| {
-			for elem in elems {
-				hasher.update(std::slice::from_ref(&elem));
-			}
-			hasher.finalize_into_reset(digest);
-		});
+		.par_chunks_mut(job_len)
+		.zip(iterated_chunks.chunks(job_len))
+		.for_each_init(
+			|| (Vec::new(), Vec::new()),
+			|(elems, offsets), (digests, chunks)| {
+				elems.clear();
+				offsets.clear();
+				offsets.push(0);
+				for chunk in chunks {
+					elems.extend(chunk);
+					offsets.push(elems.len());
+				}
+				let messages = offsets
+					.windows(2)
+					.map(|bounds| iter::once(&elems[bounds[0]..bounds[1]]));
+				write_digests(digests, H::hash_many_parts(messages));
+			},
+		);
 	Ok(())
 }
 
@@ -0,0 +1,28 @@
+// Copyright 2024 Irreducible Inc.
+
+//! Splitting of the hashing work of the Merkle tree builders into parallel jobs.
+//!
+//! Both [`BinaryMerkleTree`](super::BinaryMerkleTree) and the legacy
+//! [`MerkleTreeVCS`](crate::merkle_tree::MerkleTreeVCS) hash the leaves and compress the inner
+//! nodes in jobs of [`job_len`] messages.
+
+use std::mem::MaybeUninit;
+
+/// The minimum number of messages hashed by each parallel job
+const MIN_JOB_LEN: usize = 64;
+
+/// The number of messages hashed by each parallel job: whole batches of `batch_width` messages
+/// and at least [`MIN_JOB_LEN`] of them, so that unbatched hash functions hash many messages per
+/// job as well.
+pub(crate) fn job_len(batch_width: usize) -> usize {
+	let batch_width = batch_width.max(1);
+	MIN_JOB_LEN.div_ceil(batch_width) * batch_width
+}
+
+/// Initializes `out` with the digests computed by a job
+pub(crate) fn write_digests<D>(out: &mut [MaybeUninit<D>], digests: Vec<D>) {
+	debug_assert_eq!(out.len(), digests.len());
+	for (out, digest) in out.iter_mut().zip(digests) {
+		out.write(digest);
+	}
+}
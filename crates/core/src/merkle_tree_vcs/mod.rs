@@ -2,14 +2,17 @@
 
 mod binary_merkle_tree;
 mod errors;
+pub(crate) mod jobs;
 #[allow(clippy::module_inception)]
 mod merkle_tree_vcs;
 mod prover;
 mod scheme;
 #[cfg(test)]
 mod tests;
+mod vision;
 
 pub use binary_merkle_tree::*;
 pub use merkle_tree_vcs::*;
 pub use prover::BinaryMerkleTreeProver;
 pub use scheme::BinaryMerkleTreeScheme;
+pub use vision::*;
@@ -7,8 +7,7 @@ use super::{
 	scheme::BinaryMerkleTreeScheme,
 };
 use binius_field::PackedField;
-use binius_hash::Hasher;
-use p3_symmetric::PseudoCompressionFunction;
+use binius_hash::{MultiHasher, MultiPseudoCompressionFunction};
 use rayon::iter::IndexedParallelIterator;
 use std::marker::PhantomData;
 
@@ -34,8 +33,8 @@ impl<T, D, H, C> MerkleTreeProver<T> for BinaryMerkleTreeProver<D, H, C>
 where
 	D: PackedField,
 	T: Sync,
-	H: Hasher<T, Digest = D> + Send,
-	C: PseudoCompressionFunction<D, 2> + Sync,
+	H: MultiHasher<T, Digest = D> + Send,
+	C: MultiPseudoCompressionFunction<D, 2> + Sync,
 {
 	type Scheme = BinaryMerkleTreeScheme<D, H, C>;
 
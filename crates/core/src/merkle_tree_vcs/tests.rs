@@ -1,10 +1,14 @@
 // Copyright 2024 Irreducible Inc.
 
-use super::{BinaryMerkleTreeProver, MerkleTreeProver, MerkleTreeScheme};
-use binius_field::{BinaryField16b, BinaryField8b, Field};
-use binius_hash::{GroestlDigestCompression, GroestlHasher};
+use super::{BinaryMerkleTreeProver, MerkleTreeProver, MerkleTreeScheme, VisionMerkleTreeProver};
+use binius_field::{BinaryField128b, BinaryField16b, BinaryField8b, Field};
+use binius_hash::{
+	BsGroestl256Hasher, BsGroestlDigestCompression, GroestlDigestCompression, GroestlHasher,
+	Vision32bDigestCompression,
+};
 use core::slice;
 use rand::{rngs::StdRng, SeedableRng};
+use rayon::prelude::*;
 use std::iter::repeat_with;
 
 #[test]
@@ -82,3 +86,67 @@ fn test_binary_merkle_vcs_verify_vector() {
 		.verify_vector(&commitment.root, &data, 1)
 		.unwrap();
 }
+
+#[test]
+fn test_binary_merkle_vcs_batched_hasher_matches_root() {
+	let mut rng = StdRng::seed_from_u64(0);
+
+	let mr_prover =
+		BinaryMerkleTreeProver::<_, GroestlHasher<_>, _>::new(GroestlDigestCompression::<
+			BinaryField8b,
+		>::default());
+	let bs_mr_prover = BinaryMerkleTreeProver::<_, BsGroestl256Hasher<_, BinaryField8b>, _>::new(
+		BsGroestlDigestCompression::<BinaryField8b>::default(),
+	);
+
+	// Both a partial and several full bitsliced batches of leaves
+	for log_len in [5, 8] {
+		let data = repeat_with(|| Field::random(&mut rng))
+			.take(3 << log_len)
+			.collect::<Vec<BinaryField16b>>();
+		let (commitment, _) = mr_prover.commit(&data, 3).unwrap();
+		let (bs_commitment, _) = bs_mr_prover.commit(&data, 3).unwrap();
+		assert_eq!(commitment, bs_commitment);
+
+		let (bs_commitment, _) = bs_mr_prover
+			.commit_iterated(data.par_chunks_exact(3).map(|chunk| chunk.to_vec()), log_len)
+			.unwrap();
+		assert_eq!(commitment, bs_commitment);
+	}
+}
+
+#[test]
+fn test_vision_merkle_vcs_commit_prove_open_correctly() {
+	let mut rng = StdRng::seed_from_u64(0);
+
+	let mr_prover = VisionMerkleTreeProver::<BinaryField128b>::new(Vision32bDigestCompression);
+
+	// Several full batches of the multi-lane hasher and a partial one
+	for log_len in [2, 7] {
+		let data = repeat_with(|| Field::random(&mut rng))
+			.take(3 << log_len)
+			.collect::<Vec<BinaryField128b>>();
+		let (commitment, tree) = mr_prover.commit(&data, 3).unwrap();
+		assert_eq!(commitment.root, tree.root());
+
+		// The scheme hashes leaves and folds layers one at a time
+		mr_prover
+			.scheme()
+			.verify_vector(&commitment.root, &data, 3)
+			.unwrap();
+
+		let layer_depth = 1;
+		let layer = mr_prover.layer(&tree, layer_depth).unwrap();
+		mr_prover
+			.scheme()
+			.verify_layer(&commitment.root, layer_depth, layer)
+			.unwrap();
+		for (i, values) in data.chunks_exact(3).enumerate() {
+			let proof = mr_prover.prove_opening(&tree, layer_depth, i).unwrap();
+			mr_prover
+				.scheme()
+				.verify_opening(i, values, layer_depth, log_len, layer, proof)
+				.unwrap();
+		}
+	}
+}
@@ -0,0 +1,15 @@
+// Copyright 2024 Irreducible Inc.
+
+//! Binary Merkle trees over Vision-32b, whose openings are cheap to verify inside a Binius circuit.
+
+use super::{prover::BinaryMerkleTreeProver, scheme::BinaryMerkleTreeScheme};
+use binius_hash::{Vision32bDigest, Vision32bDigestCompression, Vision32bLeafHasher};
+
+/// A [`BinaryMerkleTreeScheme`] that hashes the leaves and the inner nodes with Vision-32b.
+pub type VisionMerkleTreeScheme<T> =
+	BinaryMerkleTreeScheme<Vision32bDigest, Vision32bLeafHasher<T>, Vision32bDigestCompression>;
+
+/// The prover of [`VisionMerkleTreeScheme`], which hashes the leaves and inner nodes of every layer
+/// with the multi-lane Vision-32b.
+pub type VisionMerkleTreeProver<T> =
+	BinaryMerkleTreeProver<Vision32bDigest, Vision32bLeafHasher<T>, Vision32bDigestCompression>;
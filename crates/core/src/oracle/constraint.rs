@@ -1,10 +1,14 @@
 // Copyright 2024 Irreducible Inc.
 
 use super::{Error, MultilinearOracleSet, OracleId};
-use crate::composition::index_composition;
-use binius_field::{Field, PackedField, TowerField};
-use binius_math::CompositionPoly;
-use binius_utils::bail;
+use crate::{composition::index_composition, polynomial::ArithCircuitPoly};
+use binius_field::{deserialize_canonical, serialize_canonical, Field, PackedField, TowerField};
+use binius_math::{ArithExpr, CompositionPoly};
+use binius_utils::{
+	bail,
+	serialization::{DeserializeBytes, Error as SerializationError, SerializeBytes},
+};
+use bytes::{Buf, BufMut};
 use itertools::Itertools;
 use std::sync::Arc;
 
@@ -37,6 +41,58 @@ impl<F: Field> ConstraintPredicate<F> {
 	}
 }
 
+/// The composition of a constraint is encoded as its [`CompositionPoly::expression`] and is
+/// deserialized into an [`ArithCircuitPoly`].
+impl<P: PackedField<Scalar: TowerField>> SerializeBytes for Constraint<P> {
+	fn serialize(&self, mut write_buf: impl BufMut) -> Result<(), SerializationError> {
+		let expr = self.composition.expression().ok_or_else(|| {
+			SerializationError::SerializationNotImplemented(format!("{:?}", self.composition))
+		})?;
+		self.composition.n_vars().serialize(&mut write_buf)?;
+		expr.serialize(&mut write_buf)?;
+		self.predicate.serialize(write_buf)
+	}
+}
+
+impl<P: PackedField<Scalar: TowerField>> DeserializeBytes for Constraint<P> {
+	fn deserialize(mut read_buf: impl Buf) -> Result<Self, SerializationError> {
+		let n_vars = usize::deserialize(&mut read_buf)?;
+		let expr = ArithExpr::<P::Scalar>::deserialize(&mut read_buf)?;
+		let predicate = ConstraintPredicate::deserialize(read_buf)?;
+		let composition = ArithCircuitPoly::<P::Scalar>::with_n_vars(n_vars, expr)
+			.map_err(|err| SerializationError::InvalidValue(err.to_string()))?;
+		Ok(Self {
+			composition: Arc::new(composition),
+			predicate,
+		})
+	}
+}
+
+impl<F: TowerField> SerializeBytes for ConstraintPredicate<F> {
+	fn serialize(&self, mut write_buf: impl BufMut) -> Result<(), SerializationError> {
+		match self {
+			ConstraintPredicate::Sum(sum) => {
+				0u8.serialize(&mut write_buf)?;
+				serialize_canonical(*sum, write_buf)
+			}
+			ConstraintPredicate::Zero => 1u8.serialize(write_buf),
+		}
+	}
+}
+
+impl<F: TowerField> DeserializeBytes for ConstraintPredicate<F> {
+	fn deserialize(mut read_buf: impl Buf) -> Result<Self, SerializationError> {
+		match u8::deserialize(&mut read_buf)? {
+			0 => Ok(ConstraintPredicate::Sum(deserialize_canonical(read_buf)?)),
+			1 => Ok(ConstraintPredicate::Zero),
+			index => Err(SerializationError::UnknownEnumVariant {
+				name: "ConstraintPredicate",
+				index,
+			}),
+		}
+	}
+}
+
 /// Constraint set is a group of constraints that operate over the same set of oracle-identified multilinears
 #[derive(Debug, Clone)]
 pub struct ConstraintSet<P: PackedField> {
@@ -45,6 +101,36 @@ pub struct ConstraintSet<P: PackedField> {
 	pub constraints: Vec<Constraint<P>>,
 }
 
+impl<P: PackedField<Scalar: TowerField>> SerializeBytes for ConstraintSet<P> {
+	fn serialize(&self, mut write_buf: impl BufMut) -> Result<(), SerializationError> {
+		self.n_vars.serialize(&mut write_buf)?;
+		self.oracle_ids.serialize(&mut write_buf)?;
+		self.constraints.serialize(write_buf)
+	}
+}
+
+impl<P: PackedField<Scalar: TowerField>> DeserializeBytes for ConstraintSet<P> {
+	fn deserialize(mut read_buf: impl Buf) -> Result<Self, SerializationError> {
+		let n_vars = usize::deserialize(&mut read_buf)?;
+		let oracle_ids = Vec::<OracleId>::deserialize(&mut read_buf)?;
+		let constraints = Vec::<Constraint<P>>::deserialize(read_buf)?;
+		for constraint in &constraints {
+			if constraint.composition.n_vars() != oracle_ids.len() {
+				return Err(SerializationError::InvalidValue(format!(
+					"constraint composition has {} variables, but the set has {} oracles",
+					constraint.composition.n_vars(),
+					oracle_ids.len()
+				)));
+			}
+		}
+		Ok(Self {
+			n_vars,
+			oracle_ids,
+			constraints,
+		})
+	}
+}
+
 // A deferred constraint constructor that instantiates index composition after the superset of oracles is known
 #[allow(clippy::type_complexity)]
 struct ConstraintThunk<P: PackedField> {
@@ -3,9 +3,17 @@
 use crate::{
 	oracle::{BatchId, CommittedBatch, CommittedId, CompositePolyOracle, Error},
 	polynomial::{Error as PolynomialError, IdentityCompositionPoly, MultivariatePoly},
+	transparent::serialization::deserialize_transparent,
 };
-use binius_field::{Field, TowerField};
-use binius_utils::bail;
+use binius_field::{
+	deserialize_canonical, deserialize_canonical_vec, serialize_canonical,
+	serialize_canonical_slice, Field, TowerField,
+};
+use binius_utils::{
+	bail,
+	serialization::{DeserializeBytes, Error as SerializationError, SerializeBytes},
+};
+use bytes::{Buf, BufMut};
 use getset::{CopyGetters, Getters};
 use std::{array, fmt::Debug, sync::Arc};
 
@@ -572,6 +580,251 @@ impl<F: TowerField> MultilinearOracleSet<F> {
 	}
 }
 
+/// The oracle set is encoded as its committed batches followed by its oracles in order of
+/// addition. Transparent polynomials are encoded with
+/// [`MultivariatePoly::erased_serialize`], so serialization fails if the set contains a
+/// transparent polynomial that does not support it.
+impl<F: TowerField> SerializeBytes for MultilinearOracleSet<F> {
+	fn serialize(&self, mut write_buf: impl BufMut) -> Result<(), SerializationError> {
+		self.batches.len().serialize(&mut write_buf)?;
+		for batch in &self.batches {
+			batch.n_vars.serialize(&mut write_buf)?;
+			batch.tower_level.serialize(&mut write_buf)?;
+		}
+
+		self.oracles.len().serialize(&mut write_buf)?;
+		for oracle in &self.oracles {
+			oracle.serialize(&mut write_buf)?;
+		}
+		Ok(())
+	}
+}
+
+/// Deserialization replays the additions of the encoded oracles, so the same validation applies
+/// as when the set is built in code.
+impl<F: TowerField> DeserializeBytes for MultilinearOracleSet<F> {
+	fn deserialize(mut read_buf: impl Buf) -> Result<Self, SerializationError> {
+		let mut oracles = Self::new();
+
+		let n_batches = usize::deserialize(&mut read_buf)?;
+		for _ in 0..n_batches {
+			let n_vars = usize::deserialize(&mut read_buf)?;
+			let tower_level = usize::deserialize(&mut read_buf)?;
+			if tower_level > F::TOWER_LEVEL {
+				return Err(SerializationError::InvalidValue(format!(
+					"committed batch tower level {tower_level} exceeds the field"
+				)));
+			}
+			oracles.add_committed_batch(n_vars, tower_level);
+		}
+
+		let n_oracles = usize::deserialize(&mut read_buf)?;
+		for _ in 0..n_oracles {
+			oracles.deserialize_oracle(&mut read_buf)?;
+		}
+		Ok(oracles)
+	}
+}
+
+impl<F: TowerField> MultilinearOracleSet<F> {
+	fn deserialize_oracle(
+		&mut self,
+		mut read_buf: impl Buf,
+	) -> Result<OracleId, SerializationError> {
+		let variant = u8::deserialize(&mut read_buf)?;
+		let oracle = match variant {
+			0 => {
+				let poly = deserialize_transparent::<F>(&mut read_buf)?;
+				let name = Option::<String>::deserialize(&mut read_buf)?;
+				if poly.binary_tower_level() > F::TOWER_LEVEL {
+					return Err(invalid_oracle(Error::TowerLevelTooHigh {
+						tower_level: poly.binary_tower_level(),
+					}));
+				}
+				return Ok(self.add_to_set(MultilinearOracleMeta::Transparent { poly, name }));
+			}
+			1 => {
+				let batch_id = usize::deserialize(&mut read_buf)?;
+				let name = Option::<String>::deserialize(&mut read_buf)?;
+				if batch_id >= self.batches.len() {
+					return Err(SerializationError::InvalidValue(format!(
+						"committed oracle refers to missing batch {batch_id}"
+					)));
+				}
+				return Ok(self.add_named_opt(name).committed(batch_id));
+			}
+			2 => {
+				let inner_id = self.deserialize_oracle_id(&mut read_buf)?;
+				let log_count = usize::deserialize(&mut read_buf)?;
+				let name = Option::<String>::deserialize(&mut read_buf)?;
+				self.add_named_opt(name).repeating(inner_id, log_count)
+			}
+			3 => {
+				let inner_id = self.deserialize_oracle_id(&mut read_buf)?;
+				let offset = usize::deserialize(&mut read_buf)?;
+				let block_bits = usize::deserialize(&mut read_buf)?;
+				let variant = ShiftVariant::deserialize(&mut read_buf)?;
+				let name = Option::<String>::deserialize(&mut read_buf)?;
+				self.add_named_opt(name)
+					.shifted(inner_id, offset, block_bits, variant)
+			}
+			4 => {
+				let inner_id = self.deserialize_oracle_id(&mut read_buf)?;
+				let log_degree = usize::deserialize(&mut read_buf)?;
+				let name = Option::<String>::deserialize(&mut read_buf)?;
+				self.add_named_opt(name).packed(inner_id, log_degree)
+			}
+			5 => {
+				let inner_id = self.deserialize_oracle_id(&mut read_buf)?;
+				let values = deserialize_canonical_vec(&mut read_buf)?;
+				let variant = ProjectionVariant::deserialize(&mut read_buf)?;
+				let name = Option::<String>::deserialize(&mut read_buf)?;
+				self.add_named_opt(name)
+					.projected(inner_id, values, variant)
+			}
+			6 => {
+				let n_vars = usize::deserialize(&mut read_buf)?;
+				let offset = deserialize_canonical(&mut read_buf)?;
+				let n_inner = usize::deserialize(&mut read_buf)?;
+				let inner = (0..n_inner)
+					.map(|_| {
+						let inner_id = usize::deserialize(&mut read_buf)?;
+						let coeff = deserialize_canonical(&mut read_buf)?;
+						Ok((inner_id, coeff))
+					})
+					.collect::<Result<Vec<_>, SerializationError>>()?;
+				let name = Option::<String>::deserialize(&mut read_buf)?;
+				self.add_named_opt(name)
+					.linear_combination_with_offset(n_vars, offset, inner)
+			}
+			7 => {
+				let inner_id = self.deserialize_oracle_id(&mut read_buf)?;
+				let n_vars = usize::deserialize(&mut read_buf)?;
+				let name = Option::<String>::deserialize(&mut read_buf)?;
+				self.add_named_opt(name).zero_padded(inner_id, n_vars)
+			}
+			index => {
+				return Err(SerializationError::UnknownEnumVariant {
+					name: "MultilinearOracleMeta",
+					index,
+				})
+			}
+		};
+		oracle.map_err(invalid_oracle)
+	}
+
+	/// Reads the ID of a previously added oracle.
+	fn deserialize_oracle_id(&self, read_buf: impl Buf) -> Result<OracleId, SerializationError> {
+		let id = usize::deserialize(read_buf)?;
+		if !self.is_valid_oracle_id(id) {
+			return Err(invalid_oracle(Error::InvalidOracleId(id)));
+		}
+		Ok(id)
+	}
+
+	fn add_named_opt(&mut self, name: Option<String>) -> MultilinearOracleSetAddition<F> {
+		MultilinearOracleSetAddition {
+			name,
+			mut_ref: self,
+		}
+	}
+}
+
+fn invalid_oracle(err: Error) -> SerializationError {
+	SerializationError::InvalidValue(err.to_string())
+}
+
+impl<F: TowerField> SerializeBytes for MultilinearOracleMeta<F> {
+	fn serialize(&self, mut write_buf: impl BufMut) -> Result<(), SerializationError> {
+		use MultilinearOracleMeta::*;
+		let name = match self {
+			Transparent { poly, name } => {
+				0u8.serialize(&mut write_buf)?;
+				poly.erased_serialize(&mut write_buf)?;
+				name
+			}
+			Committed { committed_id, name } => {
+				1u8.serialize(&mut write_buf)?;
+				committed_id.batch_id.serialize(&mut write_buf)?;
+				name
+			}
+			Repeating {
+				inner_id,
+				log_count,
+				name,
+			} => {
+				2u8.serialize(&mut write_buf)?;
+				inner_id.serialize(&mut write_buf)?;
+				log_count.serialize(&mut write_buf)?;
+				name
+			}
+			Shifted {
+				inner_id,
+				offset,
+				block_bits,
+				variant,
+				name,
+			} => {
+				3u8.serialize(&mut write_buf)?;
+				inner_id.serialize(&mut write_buf)?;
+				offset.serialize(&mut write_buf)?;
+				block_bits.serialize(&mut write_buf)?;
+				variant.serialize(&mut write_buf)?;
+				name
+			}
+			Packed {
+				inner_id,
+				log_degree,
+				name,
+			} => {
+				4u8.serialize(&mut write_buf)?;
+				inner_id.serialize(&mut write_buf)?;
+				log_degree.serialize(&mut write_buf)?;
+				name
+			}
+			Projected {
+				inner_id,
+				values,
+				variant,
+				name,
+			} => {
+				5u8.serialize(&mut write_buf)?;
+				inner_id.serialize(&mut write_buf)?;
+				serialize_canonical_slice(values, &mut write_buf)?;
+				variant.serialize(&mut write_buf)?;
+				name
+			}
+			LinearCombination {
+				n_vars,
+				offset,
+				inner,
+				name,
+			} => {
+				6u8.serialize(&mut write_buf)?;
+				n_vars.serialize(&mut write_buf)?;
+				serialize_canonical(*offset, &mut write_buf)?;
+				inner.len().serialize(&mut write_buf)?;
+				for (inner_id, coeff) in inner {
+					inner_id.serialize(&mut write_buf)?;
+					serialize_canonical(*coeff, &mut write_buf)?;
+				}
+				name
+			}
+			ZeroPadded {
+				inner_id,
+				n_vars,
+				name,
+			} => {
+				7u8.serialize(&mut write_buf)?;
+				inner_id.serialize(&mut write_buf)?;
+				n_vars.serialize(&mut write_buf)?;
+				name
+			}
+		};
+		name.serialize(write_buf)
+	}
+}
+
 /// A multilinear polynomial oracle in the polynomial IOP model.
 ///
 /// In the multilinear polynomial IOP model, a prover sends multilinear polynomials to an oracle,
@@ -681,6 +934,29 @@ pub enum ProjectionVariant {
 	LastVars,
 }
 
+impl SerializeBytes for ProjectionVariant {
+	fn serialize(&self, write_buf: impl BufMut) -> Result<(), SerializationError> {
+		let index: u8 = match self {
+			ProjectionVariant::FirstVars => 0,
+			ProjectionVariant::LastVars => 1,
+		};
+		index.serialize(write_buf)
+	}
+}
+
+impl DeserializeBytes for ProjectionVariant {
+	fn deserialize(read_buf: impl Buf) -> Result<Self, SerializationError> {
+		match u8::deserialize(read_buf)? {
+			0 => Ok(ProjectionVariant::FirstVars),
+			1 => Ok(ProjectionVariant::LastVars),
+			index => Err(SerializationError::UnknownEnumVariant {
+				name: "ProjectionVariant",
+				index,
+			}),
+		}
+	}
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Getters, CopyGetters)]
 pub struct Projected<F: Field> {
 	#[get = "pub"]
@@ -721,6 +997,31 @@ pub enum ShiftVariant {
 	LogicalRight,
 }
 
+impl SerializeBytes for ShiftVariant {
+	fn serialize(&self, write_buf: impl BufMut) -> Result<(), SerializationError> {
+		let index: u8 = match self {
+			ShiftVariant::CircularLeft => 0,
+			ShiftVariant::LogicalLeft => 1,
+			ShiftVariant::LogicalRight => 2,
+		};
+		index.serialize(write_buf)
+	}
+}
+
+impl DeserializeBytes for ShiftVariant {
+	fn deserialize(read_buf: impl Buf) -> Result<Self, SerializationError> {
+		match u8::deserialize(read_buf)? {
+			0 => Ok(ShiftVariant::CircularLeft),
+			1 => Ok(ShiftVariant::LogicalLeft),
+			2 => Ok(ShiftVariant::LogicalRight),
+			index => Err(SerializationError::UnknownEnumVariant {
+				name: "ShiftVariant",
+				index,
+			}),
+		}
+	}
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Getters, CopyGetters)]
 pub struct Shifted<F: Field> {
 	inner: Box<MultilinearPolyOracle<F>>,
@@ -10,8 +10,12 @@ use crate::transcript::{CanRead, CanWrite};
 use binius_field::{util::inner_product_unchecked, ExtensionField, Field, PackedField, TowerField};
 use binius_hal::ComputationBackend;
 use binius_math::{MultilinearExtension, MultilinearQuery};
-use binius_utils::bail;
+use binius_utils::{
+	bail,
+	serialization::{DeserializeBytes, Error as SerializationError, SerializeBytes},
+};
 use bytemuck::zeroed_vec;
+use bytes::{Buf, BufMut};
 use std::{marker::PhantomData, ops::Deref};
 use tracing::instrument;
 
@@ -273,6 +277,18 @@ where
 #[derive(Debug, Clone)]
 pub struct Proof<Inner>(Inner);
 
+impl<Inner: SerializeBytes> SerializeBytes for Proof<Inner> {
+	fn serialize(&self, write_buf: impl BufMut) -> Result<(), SerializationError> {
+		self.0.serialize(write_buf)
+	}
+}
+
+impl<Inner: DeserializeBytes> DeserializeBytes for Proof<Inner> {
+	fn deserialize(read_buf: impl Buf) -> Result<Self, SerializationError> {
+		Inner::deserialize(read_buf).map(Self)
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -21,13 +21,18 @@ use crate::{
 	transparent::ring_switch::RingSwitchEqInd,
 };
 use binius_field::{
-	packed::iter_packed_slice, BinaryField, ExtensionField, Field, PackedExtension, PackedField,
-	PackedFieldIndexable, TowerField,
+	deserialize_canonical_vec, packed::iter_packed_slice, serialize_canonical_slice, BinaryField,
+	ExtensionField, Field, PackedExtension, PackedField, PackedFieldIndexable, TowerField,
 };
 use binius_hal::{ComputationBackend, ComputationBackendExt};
 use binius_math::{EvaluationDomainFactory, MLEDirectAdapter, MultilinearExtension};
 use binius_ntt::NTTOptions;
-use binius_utils::{bail, checked_arithmetics::checked_log_2};
+use binius_utils::{
+	bail,
+	checked_arithmetics::checked_log_2,
+	serialization::{DeserializeBytes, Error as SerializationError, SerializeBytes},
+};
+use bytes::{Buf, BufMut};
 use std::{fmt::Debug, iter, marker::PhantomData, mem, ops::Deref};
 use tracing::instrument;
 
@@ -589,6 +594,38 @@ where
 	fri_proof: fri::FRIProof<F, VCS>,
 }
 
+impl<F, VCS> SerializeBytes for Proof<F, VCS>
+where
+	F: TowerField,
+	VCS: MerkleTreeScheme<F>,
+	VCS::Digest: Clone + Debug + SerializeBytes,
+	VCS::Proof: Clone + Debug + SerializeBytes,
+{
+	fn serialize(&self, mut write_buf: impl BufMut) -> Result<(), SerializationError> {
+		serialize_canonical_slice(&self.sumcheck_eval, &mut write_buf)?;
+		self.sumcheck_rounds.serialize(&mut write_buf)?;
+		self.fri_commitments.serialize(&mut write_buf)?;
+		self.fri_proof.serialize(write_buf)
+	}
+}
+
+impl<F, VCS> DeserializeBytes for Proof<F, VCS>
+where
+	F: TowerField,
+	VCS: MerkleTreeScheme<F>,
+	VCS::Digest: Clone + Debug + DeserializeBytes,
+	VCS::Proof: Clone + Debug + DeserializeBytes,
+{
+	fn deserialize(mut read_buf: impl Buf) -> Result<Self, SerializationError> {
+		Ok(Self {
+			sumcheck_eval: deserialize_canonical_vec(&mut read_buf)?,
+			sumcheck_rounds: Vec::deserialize(&mut read_buf)?,
+			fri_commitments: Vec::deserialize(&mut read_buf)?,
+			fri_proof: fri::FRIProof::deserialize(read_buf)?,
+		})
+	}
+}
+
 /// Heuristic for estimating the optimal arity (with respect to proof size) for the FRI-based PCS.
 ///
 /// `log_block_length` is the log block length of the packed Reed-Solomon code, i.e., $\ell - \kappa + \mathcal R$.
@@ -668,7 +705,7 @@ mod tests {
 	use super::*;
 	use crate::{
 		fiat_shamir::HasherChallenger,
-		merkle_tree_vcs::BinaryMerkleTreeProver,
+		merkle_tree_vcs::{BinaryMerkleTreeProver, VisionMerkleTreeProver},
 		transcript::{AdviceWriter, TranscriptWriter},
 	};
 	use binius_field::{
@@ -678,16 +715,19 @@ mod tests {
 		BinaryField128b, BinaryField16b, BinaryField1b, BinaryField32b, BinaryField8b,
 	};
 	use binius_hal::make_portable_backend;
-	use binius_hash::{GroestlDigestCompression, GroestlHasher};
+	use binius_hash::{
+		GroestlDigest, GroestlDigestCompression, GroestlHasher, Vision32bDigestCompression,
+	};
 	use binius_math::IsomorphicEvaluationDomainFactory;
 	use groestl_crypto::Groestl256;
 	use iter::repeat_with;
 	use rand::{prelude::StdRng, SeedableRng};
 
-	fn test_commit_prove_verify_success<U, F, FA, FE>(
+	fn test_commit_prove_verify_success<U, F, FA, FE, MerkleProver, VCS>(
 		n_vars: usize,
 		log_inv_rate: usize,
 		fold_arities: &[usize],
+		merkle_prover: MerkleProver,
 	) where
 		U: UnderlierType
 			+ PackScalar<F>
@@ -707,6 +747,8 @@ mod tests {
 			+ PackedExtension<BinaryField8b, PackedSubfield: PackedFieldIndexable>,
 		PackedType<U, FA>: PackedFieldIndexable,
 		PackedType<U, FE>: PackedFieldIndexable,
+		MerkleProver: MerkleTreeProver<FE, Scheme = VCS> + Sync,
+		VCS: MerkleTreeScheme<FE, Digest: PackedField<Scalar: TowerField>, Proof: Clone + Debug>,
 	{
 		let mut rng = StdRng::seed_from_u64(0);
 		let backend = make_portable_backend();
@@ -726,10 +768,6 @@ mod tests {
 		let eval_query = backend.multilinear_query::<FE>(&eval_point).unwrap();
 		let eval = multilin.evaluate(&eval_query).unwrap();
 
-		let merkle_prover = BinaryMerkleTreeProver::<_, GroestlHasher<_>, _>::new(
-			GroestlDigestCompression::default(),
-		);
-
 		let domain_factory = IsomorphicEvaluationDomainFactory::<BinaryField8b>::default();
 		let pcs = FRIPCS::<F, BinaryField8b, FA, PackedType<U, FE>, _, _, _>::new(
 			n_vars,
@@ -772,6 +810,14 @@ mod tests {
 		.unwrap();
 	}
 
+	fn groestl_merkle_prover() -> BinaryMerkleTreeProver<
+		GroestlDigest<BinaryField8b>,
+		GroestlHasher<BinaryField128b>,
+		GroestlDigestCompression<BinaryField8b>,
+	> {
+		BinaryMerkleTreeProver::new(GroestlDigestCompression::default())
+	}
+
 	#[test]
 	fn test_commit_prove_verify_success_1b_128b() {
 		test_commit_prove_verify_success::<
@@ -779,7 +825,9 @@ mod tests {
 			BinaryField1b,
 			BinaryField16b,
 			BinaryField128b,
-		>(18, 2, &[3, 3, 3]);
+			_,
+			_,
+		>(18, 2, &[3, 3, 3], groestl_merkle_prover());
 	}
 
 	#[test]
@@ -789,7 +837,21 @@ mod tests {
 			BinaryField32b,
 			BinaryField16b,
 			BinaryField128b,
-		>(12, 2, &[3, 3, 3]);
+			_,
+			_,
+		>(12, 2, &[3, 3, 3], groestl_merkle_prover());
+	}
+
+	#[test]
+	fn test_commit_prove_verify_success_vision_merkle() {
+		test_commit_prove_verify_success::<
+			<PackedBinaryPolyval1x128b as WithUnderlier>::Underlier,
+			BinaryField32b,
+			BinaryField16b,
+			BinaryField128b,
+			_,
+			_,
+		>(12, 2, &[3, 3, 3], VisionMerkleTreeProver::new(Vision32bDigestCompression));
 	}
 
 	#[test]
@@ -19,7 +19,8 @@ use binius_field::{
 };
 use binius_hal::{ComputationBackend, ComputationBackendExt};
 use binius_hash::{
-	GroestlDigest, GroestlDigestCompression, GroestlHasher, HashDigest, HasherDigest,
+	BsGroestl256Hasher, BsGroestlDigestCompression, GroestlDigest, GroestlHasher, HashDigest,
+	HasherDigest,
 };
 use binius_math::MultilinearExtension;
 use binius_ntt::{NTTOptions, ThreadingSettings};
@@ -142,11 +143,15 @@ where
 	_ext_marker: PhantomData<FE>,
 }
 
+/// The Merkle tree of [`TensorPCS::new_using_groestl_merkle_tree`] hashes its leaves and nodes
+/// with the bitsliced Grøstl-256, which commits to the same trees as [`GroestlHasher`] and
+/// [`GroestlDigestCompression`](binius_hash::GroestlDigestCompression) but hashes them many at
+/// a time.
 type GroestlMerkleTreeVCS = MerkleTreeVCS<
 	GroestlDigest<BinaryField8b>,
 	GroestlDigest<BinaryField8b>,
-	GroestlHasher<GroestlDigest<BinaryField8b>>,
-	GroestlDigestCompression<BinaryField8b>,
+	BsGroestl256Hasher<GroestlDigest<BinaryField8b>, BinaryField8b>,
+	BsGroestlDigestCompression<BinaryField8b>,
 >;
 
 impl<U, F, FA, FI, FE, LC>
@@ -192,7 +197,7 @@ where
 			MerkleTreeVCS::new(
 				log_len,
 				cap_height,
-				GroestlDigestCompression::<BinaryField8b>::default(),
+				BsGroestlDigestCompression::<BinaryField8b>::default(),
 			),
 		)
 	}
@@ -3,113 +3,48 @@
 use binius_field::{ExtensionField, Field, PackedField, TowerField};
 use binius_math::{CompositionPoly, Error};
 use stackalloc::{helpers::slice_assume_init, stackalloc_uninit};
-use std::{
-	cmp::max,
-	mem::MaybeUninit,
-	ops::{Add, Mul, Sub},
-	sync::Arc,
-};
+use std::{mem::MaybeUninit, sync::Arc};
 
 /// Represents an arithmetic expression that can be evaluated symbolically.
-pub enum Expr<F: Field> {
-	Const(F),
-	Var(usize),
-	Add(Box<Expr<F>>, Box<Expr<F>>),
-	Mul(Box<Expr<F>>, Box<Expr<F>>),
-	Pow(Box<Expr<F>>, u64),
-}
-
-impl<F: Field> Expr<F> {
-	pub fn n_vars(&self) -> usize {
-		match self {
-			Expr::Const(_) => 0,
-			Expr::Var(index) => *index + 1,
-			Expr::Add(left, right) | Expr::Mul(left, right) => max(left.n_vars(), right.n_vars()),
-			Expr::Pow(id, _) => id.n_vars(),
-		}
-	}
-
-	pub fn degree(&self) -> usize {
-		match self {
-			Expr::Const(_) => 0,
-			Expr::Var(_) => 1,
-			Expr::Add(left, right) => max(left.degree(), right.degree()),
-			Expr::Mul(left, right) => left.degree() + right.degree(),
-			Expr::Pow(_, exp) => *exp as usize,
-		}
-	}
-
-	pub fn pow(self, exp: u64) -> Self {
-		Expr::Pow(Box::new(self), exp)
-	}
-
-	/// Convert the expression to a sequence of arithmetic operations that can be evaluated in sequence.
-	fn to_circuit(&self) -> Vec<CircuitStep<F>> {
-		let mut result = Vec::new();
-
-		fn to_circuit_inner<F: Field>(
-			expr: &Expr<F>,
-			result: &mut Vec<CircuitStep<F>>,
-		) -> CircuitStepArgument<F> {
-			match expr {
-				Expr::Const(value) => CircuitStepArgument::Const(*value),
-				Expr::Var(index) => CircuitStepArgument::Expr(CircuitNode::Var(*index)),
-				Expr::Add(left, right) => {
-					let left = to_circuit_inner(left, result);
-					let right = to_circuit_inner(right, result);
-					result.push(CircuitStep::Add(left, right));
-					CircuitStepArgument::Expr(CircuitNode::Slot(result.len() - 1))
-				}
-				Expr::Mul(left, right) => {
-					let left = to_circuit_inner(left, result);
-					let right = to_circuit_inner(right, result);
-					result.push(CircuitStep::Mul(left, right));
-					CircuitStepArgument::Expr(CircuitNode::Slot(result.len() - 1))
-				}
-				Expr::Pow(id, exp) => {
-					let id = to_circuit_inner(id, result);
-					result.push(CircuitStep::Pow(id, *exp));
-					CircuitStepArgument::Expr(CircuitNode::Slot(result.len() - 1))
-				}
+pub use binius_math::ArithExpr as Expr;
+
+/// Convert the expression to a sequence of arithmetic operations that can be evaluated in sequence.
+fn to_circuit<F: Field>(expr: &Expr<F>) -> Vec<CircuitStep<F>> {
+	let mut result = Vec::new();
+
+	fn to_circuit_inner<F: Field>(
+		expr: &Expr<F>,
+		result: &mut Vec<CircuitStep<F>>,
+	) -> CircuitStepArgument<F> {
+		match expr {
+			Expr::Const(value) => CircuitStepArgument::Const(*value),
+			Expr::Var(index) => CircuitStepArgument::Expr(CircuitNode::Var(*index)),
+			Expr::Add(left, right) => {
+				let left = to_circuit_inner(left, result);
+				let right = to_circuit_inner(right, result);
+				result.push(CircuitStep::Add(left, right));
+				CircuitStepArgument::Expr(CircuitNode::Slot(result.len() - 1))
+			}
+			Expr::Mul(left, right) => {
+				let left = to_circuit_inner(left, result);
+				let right = to_circuit_inner(right, result);
+				result.push(CircuitStep::Mul(left, right));
+				CircuitStepArgument::Expr(CircuitNode::Slot(result.len() - 1))
+			}
+			Expr::Pow(id, exp) => {
+				let id = to_circuit_inner(id, result);
+				result.push(CircuitStep::Pow(id, *exp));
+				CircuitStepArgument::Expr(CircuitNode::Slot(result.len() - 1))
 			}
 		}
-
-		to_circuit_inner(self, &mut result);
-		result
-	}
-}
-
-impl<F> Add for Expr<F>
-where
-	F: Field,
-{
-	type Output = Self;
-
-	fn add(self, rhs: Self) -> Self {
-		Expr::Add(Box::new(self), Box::new(rhs))
-	}
-}
-
-impl<F> Sub for Expr<F>
-where
-	F: Field,
-{
-	type Output = Self;
-
-	fn sub(self, rhs: Self) -> Self {
-		Expr::Add(Box::new(self), Box::new(rhs))
 	}
-}
 
-impl<F> Mul for Expr<F>
-where
-	F: Field,
-{
-	type Output = Self;
-
-	fn mul(self, rhs: Self) -> Self {
-		Expr::Mul(Box::new(self), Box::new(rhs))
+	let root = to_circuit_inner(expr, &mut result);
+	// A bare variable or constant has no steps, but evaluation reads the result of the last one.
+	if result.is_empty() {
+		result.push(CircuitStep::Add(root, CircuitStepArgument::Const(F::ZERO)));
 	}
+	result
 }
 
 /// Input of the circuit calculation step
@@ -161,6 +96,8 @@ enum CircuitStep<F: Field> {
 /// The advantage over a hard coded CompositionPoly is that this can be constructed and manipulated dynamically at runtime.
 #[derive(Debug)]
 pub struct ArithCircuitPoly<F: TowerField> {
+	/// The expression the circuit was built from
+	expr: Arc<Expr<F>>,
 	/// The last expression is the "top level expression" which depends on previous entries
 	exprs: Arc<[CircuitStep<F>]>,
 	degree: usize,
@@ -171,14 +108,30 @@ impl<F: TowerField> ArithCircuitPoly<F> {
 	pub fn new(expr: Expr<F>) -> Self {
 		let degree = expr.degree();
 		let n_vars = expr.n_vars();
-		let exprs = expr.to_circuit().into();
+		let exprs = to_circuit(&expr).into();
 
 		Self {
+			expr: Arc::new(expr),
 			exprs,
 			degree,
 			n_vars,
 		}
 	}
+
+	/// Constructs an [`ArithCircuitPoly`] with the given number of variables.
+	///
+	/// The number of variables may be greater than the number of variables actually read in the
+	/// arithmetic expression.
+	pub fn with_n_vars(n_vars: usize, expr: Expr<F>) -> Result<Self, Error> {
+		let circuit = Self::new(expr);
+		if n_vars < circuit.n_vars {
+			return Err(Error::IncorrectNumberOfVariables {
+				expected: circuit.n_vars,
+				actual: n_vars,
+			});
+		}
+		Ok(Self { n_vars, ..circuit })
+	}
 }
 
 impl<F: TowerField, P: PackedField<Scalar: ExtensionField<F>>> CompositionPoly<P>
@@ -196,6 +149,10 @@ impl<F: TowerField, P: PackedField<Scalar: ExtensionField<F>>> CompositionPoly<P
 		F::TOWER_LEVEL
 	}
 
+	fn expression(&self) -> Option<Expr<P::Scalar>> {
+		Some(self.expr.convert_field())
+	}
+
 	fn evaluate(&self, query: &[P]) -> Result<P, Error> {
 		if query.len() != self.n_vars {
 			return Err(Error::IncorrectQuerySize {
@@ -526,4 +483,32 @@ mod tests {
 			.unwrap();
 		assert_eq!(sparse_result, vec![expected1, expected2, expected3]);
 	}
+
+	#[test]
+	fn test_bare_var_and_const() {
+		type F = BinaryField8b;
+		type P = PackedBinaryField8x16b;
+
+		let query = [
+			P::from_scalars(felts!(BinaryField16b[0, 1, 2, 3, 4, 5, 6, 7])),
+			P::from_scalars(felts!(BinaryField16b[100, 101, 102, 103, 104, 105, 106, 107])),
+		];
+
+		// x1
+		let circuit = &ArithCircuitPoly::<F>::new(Expr::Var(1)) as &dyn CompositionPoly<P>;
+		assert_eq!(circuit.degree(), 1);
+		assert_eq!(circuit.n_vars(), 2);
+		assert_eq!(circuit.evaluate(&query).unwrap(), query[1]);
+		let mut sparse_result = vec![P::zero(); 1];
+		circuit
+			.batch_evaluate(&[&[query[0]], &[query[1]]], &mut sparse_result)
+			.unwrap();
+		assert_eq!(sparse_result, vec![query[1]]);
+
+		// 123
+		let circuit =
+			&ArithCircuitPoly::<F>::new(Expr::Const(F::new(123))) as &dyn CompositionPoly<P>;
+		assert_eq!(circuit.n_vars(), 0);
+		assert_eq!(circuit.evaluate(&[]).unwrap(), P::broadcast(BinaryField16b::new(123)));
+	}
 }
@@ -2,11 +2,16 @@
 
 use super::error::Error;
 use binius_field::{Field, PackedField};
-use binius_math::{CompositionPoly, MLEDirectAdapter, MultilinearPoly, MultilinearQueryRef};
-use binius_utils::bail;
+use binius_math::{
+	ArithExpr, CompositionPoly, MLEDirectAdapter, MultilinearPoly, MultilinearQueryRef,
+};
+use binius_utils::{bail, serialization::Error as SerializationError};
+use bytes::BufMut;
 use itertools::Itertools;
 use rand::{rngs::StdRng, SeedableRng};
-use std::{borrow::Borrow, fmt::Debug, iter::repeat_with, marker::PhantomData, sync::Arc};
+use std::{
+	any::type_name, borrow::Borrow, fmt::Debug, iter::repeat_with, marker::PhantomData, sync::Arc,
+};
 
 /// A multivariate polynomial over a binary tower field.
 ///
@@ -24,6 +29,14 @@ pub trait MultivariatePoly<P>: Debug + Send + Sync {
 
 	/// Returns the maximum binary tower level of all constants in the arithmetic expression.
 	fn binary_tower_level(&self) -> usize;
+
+	/// Serializes the polynomial together with a tag identifying its type, so that
+	/// [`deserialize_transparent`](crate::transparent::serialization::deserialize_transparent)
+	/// can reconstruct it.
+	fn erased_serialize(&self, write_buf: &mut dyn BufMut) -> Result<(), SerializationError> {
+		let _ = write_buf;
+		Err(SerializationError::SerializationNotImplemented(type_name::<Self>().to_string()))
+	}
 }
 
 /// Identity composition function $g(X) = X$.
@@ -49,6 +62,10 @@ impl<P: PackedField> CompositionPoly<P> for IdentityCompositionPoly {
 	fn binary_tower_level(&self) -> usize {
 		0
 	}
+
+	fn expression(&self) -> Option<ArithExpr<P::Scalar>> {
+		Some(ArithExpr::Var(0))
+	}
 }
 
 /// An adapter that constructs a [`CompositionPoly`] for a field from a [`CompositionPoly`] for a
@@ -97,6 +114,10 @@ where
 	fn binary_tower_level(&self) -> usize {
 		self.composition.binary_tower_level()
 	}
+
+	fn expression(&self) -> Option<ArithExpr<F>> {
+		self.composition.expression()
+	}
 }
 
 /// A polynomial defined as the composition of several multilinear polynomials.
@@ -2,8 +2,12 @@
 
 use super::error::Error;
 use crate::oracle::{BatchId, CommittedBatch, CommittedId, MultilinearPolyOracle};
-use binius_field::Field;
-use binius_utils::bail;
+use binius_field::{deserialize_canonical, serialize_canonical, Field, TowerField};
+use binius_utils::{
+	bail,
+	serialization::{DeserializeBytes, Error as SerializationError, SerializeBytes},
+};
+use bytes::{Buf, BufMut};
 use tracing::instrument;
 
 #[derive(Debug, Clone)]
@@ -29,6 +33,69 @@ pub enum EvalcheckProof<F: Field> {
 	ZeroPadded(F, Box<EvalcheckProof<F>>),
 }
 
+impl<F: TowerField> SerializeBytes for EvalcheckProof<F> {
+	fn serialize(&self, mut write_buf: impl BufMut) -> Result<(), SerializationError> {
+		match self {
+			EvalcheckProof::Transparent => 0u8.serialize(write_buf),
+			EvalcheckProof::Committed => 1u8.serialize(write_buf),
+			EvalcheckProof::Shifted => 2u8.serialize(write_buf),
+			EvalcheckProof::Packed => 3u8.serialize(write_buf),
+			EvalcheckProof::Repeating(inner) => {
+				4u8.serialize(&mut write_buf)?;
+				inner.serialize(write_buf)
+			}
+			EvalcheckProof::Composite { subproofs } => {
+				5u8.serialize(&mut write_buf)?;
+				subproofs.len().serialize(&mut write_buf)?;
+				for (eval, subproof) in subproofs {
+					serialize_canonical(*eval, &mut write_buf)?;
+					subproof.serialize(&mut write_buf)?;
+				}
+				Ok(())
+			}
+			EvalcheckProof::ZeroPadded(eval, inner) => {
+				6u8.serialize(&mut write_buf)?;
+				serialize_canonical(*eval, &mut write_buf)?;
+				inner.serialize(write_buf)
+			}
+		}
+	}
+}
+
+impl<F: TowerField> DeserializeBytes for EvalcheckProof<F> {
+	fn deserialize(mut read_buf: impl Buf) -> Result<Self, SerializationError> {
+		let proof = match u8::deserialize(&mut read_buf)? {
+			0 => EvalcheckProof::Transparent,
+			1 => EvalcheckProof::Committed,
+			2 => EvalcheckProof::Shifted,
+			3 => EvalcheckProof::Packed,
+			4 => EvalcheckProof::Repeating(Box::deserialize(read_buf)?),
+			5 => {
+				let n_subproofs = usize::deserialize(&mut read_buf)?;
+				let subproofs = (0..n_subproofs)
+					.map(|_| {
+						let eval = deserialize_canonical(&mut read_buf)?;
+						let subproof = EvalcheckProof::deserialize(&mut read_buf)?;
+						Ok((eval, subproof))
+					})
+					.collect::<Result<_, SerializationError>>()?;
+				EvalcheckProof::Composite { subproofs }
+			}
+			6 => {
+				let eval = deserialize_canonical(&mut read_buf)?;
+				EvalcheckProof::ZeroPadded(eval, Box::deserialize(read_buf)?)
+			}
+			index => {
+				return Err(SerializationError::UnknownEnumVariant {
+					name: "EvalcheckProof",
+					index,
+				})
+			}
+		};
+		Ok(proof)
+	}
+}
+
 impl<F: Field> EvalcheckProof<F> {
 	pub fn isomorphic<FI: Field + From<F>>(self) -> EvalcheckProof<FI> {
 		match self {
@@ -4,10 +4,17 @@ use crate::{
 	linear_code::LinearCode, merkle_tree_vcs::MerkleTreeScheme, protocols::fri::Error,
 	reed_solomon::reed_solomon::ReedSolomonCode,
 };
-use binius_field::{util::inner_product_unchecked, BinaryField, ExtensionField, PackedField};
+use binius_field::{
+	deserialize_canonical_vec, serialize_canonical_slice, util::inner_product_unchecked,
+	BinaryField, ExtensionField, PackedField, TowerField,
+};
 use binius_math::extrapolate_line_scalar;
 use binius_ntt::AdditiveNTT;
-use binius_utils::bail;
+use binius_utils::{
+	bail,
+	serialization::{DeserializeBytes, Error as SerializationError, SerializeBytes},
+};
+use bytes::{Buf, BufMut};
 use getset::{CopyGetters, Getters};
 use std::marker::PhantomData;
 
@@ -279,6 +286,48 @@ pub struct QueryRoundProof<F, VCSProof> {
 	pub vcs_proof: VCSProof,
 }
 
+impl<F, VCS> SerializeBytes for FRIProof<F, VCS>
+where
+	F: TowerField,
+	VCS: MerkleTreeScheme<F, Digest: SerializeBytes, Proof: SerializeBytes>,
+{
+	fn serialize(&self, mut write_buf: impl BufMut) -> Result<(), SerializationError> {
+		serialize_canonical_slice(&self.terminate_codeword, &mut write_buf)?;
+		self.proofs.serialize(&mut write_buf)?;
+		self.layers.serialize(write_buf)
+	}
+}
+
+impl<F, VCS> DeserializeBytes for FRIProof<F, VCS>
+where
+	F: TowerField,
+	VCS: MerkleTreeScheme<F, Digest: DeserializeBytes, Proof: DeserializeBytes>,
+{
+	fn deserialize(mut read_buf: impl Buf) -> Result<Self, SerializationError> {
+		Ok(Self {
+			terminate_codeword: deserialize_canonical_vec(&mut read_buf)?,
+			proofs: Vec::deserialize(&mut read_buf)?,
+			layers: Vec::deserialize(read_buf)?,
+		})
+	}
+}
+
+impl<F: TowerField, VCSProof: SerializeBytes> SerializeBytes for QueryRoundProof<F, VCSProof> {
+	fn serialize(&self, mut write_buf: impl BufMut) -> Result<(), SerializationError> {
+		serialize_canonical_slice(&self.values, &mut write_buf)?;
+		self.vcs_proof.serialize(write_buf)
+	}
+}
+
+impl<F: TowerField, VCSProof: DeserializeBytes> DeserializeBytes for QueryRoundProof<F, VCSProof> {
+	fn deserialize(mut read_buf: impl Buf) -> Result<Self, SerializationError> {
+		Ok(Self {
+			values: deserialize_canonical_vec(&mut read_buf)?,
+			vcs_proof: VCSProof::deserialize(read_buf)?,
+		})
+	}
+}
+
 /// Calculates the number of test queries required to achieve a target security level.
 ///
 /// Throws [`Error::ParameterError`] if the security level is unattainable given the code
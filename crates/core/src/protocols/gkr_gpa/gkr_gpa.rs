@@ -2,9 +2,13 @@
 
 use super::Error;
 use crate::{protocols::sumcheck::Proof as SumcheckBatchProof, witness::MultilinearWitness};
-use binius_field::{Field, PackedField};
-use binius_utils::bail;
+use binius_field::{Field, PackedField, TowerField};
+use binius_utils::{
+	bail,
+	serialization::{DeserializeBytes, Error as SerializationError, SerializeBytes},
+};
 use bytemuck::zeroed_vec;
+use bytes::{Buf, BufMut};
 use rayon::prelude::*;
 
 type LayerEvals<'a, FW> = &'a [FW];
@@ -116,6 +120,20 @@ pub struct GrandProductBatchProof<F: Field> {
 	pub batch_layer_proofs: Vec<SumcheckBatchProof<F>>,
 }
 
+impl<F: TowerField> SerializeBytes for GrandProductBatchProof<F> {
+	fn serialize(&self, write_buf: impl BufMut) -> Result<(), SerializationError> {
+		self.batch_layer_proofs.serialize(write_buf)
+	}
+}
+
+impl<F: TowerField> DeserializeBytes for GrandProductBatchProof<F> {
+	fn deserialize(read_buf: impl Buf) -> Result<Self, SerializationError> {
+		Ok(Self {
+			batch_layer_proofs: Vec::deserialize(read_buf)?,
+		})
+	}
+}
+
 #[derive(Debug, Default)]
 pub struct GrandProductBatchProveOutput<F: Field> {
 	// Reduced evalcheck claims for all the initial grand product claims
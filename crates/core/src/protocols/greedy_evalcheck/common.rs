@@ -7,7 +7,9 @@ use crate::{
 		sumcheck::Proof,
 	},
 };
-use binius_field::Field;
+use binius_field::{Field, TowerField};
+use binius_utils::serialization::{DeserializeBytes, Error as SerializationError, SerializeBytes};
+use bytes::{Buf, BufMut};
 
 #[derive(Debug, Clone, Default)]
 pub struct GreedyEvalcheckProof<F: Field> {
@@ -16,6 +18,24 @@ pub struct GreedyEvalcheckProof<F: Field> {
 	pub batch_opening_proof: (Proof<F>, Vec<EvalcheckProof<F>>),
 }
 
+impl<F: TowerField> SerializeBytes for GreedyEvalcheckProof<F> {
+	fn serialize(&self, mut write_buf: impl BufMut) -> Result<(), SerializationError> {
+		self.initial_evalcheck_proofs.serialize(&mut write_buf)?;
+		self.virtual_opening_proofs.serialize(&mut write_buf)?;
+		self.batch_opening_proof.serialize(write_buf)
+	}
+}
+
+impl<F: TowerField> DeserializeBytes for GreedyEvalcheckProof<F> {
+	fn deserialize(mut read_buf: impl Buf) -> Result<Self, SerializationError> {
+		Ok(Self {
+			initial_evalcheck_proofs: Vec::deserialize(&mut read_buf)?,
+			virtual_opening_proofs: Vec::deserialize(&mut read_buf)?,
+			batch_opening_proof: DeserializeBytes::deserialize(read_buf)?,
+		})
+	}
+}
+
 impl<F: Field> GreedyEvalcheckProof<F> {
 	fn isomorphic_vec<FI: Field + From<F>>(vec: Vec<EvalcheckProof<F>>) -> Vec<EvalcheckProof<FI>> {
 		vec.into_iter().map(|x| x.isomorphic()).collect()
@@ -2,11 +2,16 @@
 
 use super::error::Error;
 use binius_field::{
+	deserialize_canonical_vec, serialize_canonical_slice,
 	util::{inner_product_unchecked, powers},
-	ExtensionField, Field, PackedField,
+	ExtensionField, Field, PackedField, TowerField,
 };
 use binius_math::{CompositionPoly, MultilinearPoly};
-use binius_utils::bail;
+use binius_utils::{
+	bail,
+	serialization::{DeserializeBytes, Error as SerializationError, SerializeBytes},
+};
+use bytes::{Buf, BufMut};
 use getset::{CopyGetters, Getters};
 use std::ops::{Add, AddAssign, Mul, MulAssign};
 
@@ -224,6 +229,43 @@ impl<F: Field> Proof<F> {
 	}
 }
 
+impl<F: TowerField> SerializeBytes for RoundProof<F> {
+	fn serialize(&self, write_buf: impl BufMut) -> Result<(), SerializationError> {
+		serialize_canonical_slice(&self.0 .0, write_buf)
+	}
+}
+
+impl<F: TowerField> DeserializeBytes for RoundProof<F> {
+	fn deserialize(read_buf: impl Buf) -> Result<Self, SerializationError> {
+		Ok(RoundProof(RoundCoeffs(deserialize_canonical_vec(read_buf)?)))
+	}
+}
+
+impl<F: TowerField> SerializeBytes for Proof<F> {
+	fn serialize(&self, mut write_buf: impl BufMut) -> Result<(), SerializationError> {
+		self.rounds.serialize(&mut write_buf)?;
+		self.multilinear_evals.len().serialize(&mut write_buf)?;
+		for evals in &self.multilinear_evals {
+			serialize_canonical_slice(evals, &mut write_buf)?;
+		}
+		Ok(())
+	}
+}
+
+impl<F: TowerField> DeserializeBytes for Proof<F> {
+	fn deserialize(mut read_buf: impl Buf) -> Result<Self, SerializationError> {
+		let rounds = Vec::deserialize(&mut read_buf)?;
+		let n_claims = usize::deserialize(&mut read_buf)?;
+		let multilinear_evals = (0..n_claims)
+			.map(|_| deserialize_canonical_vec(&mut read_buf))
+			.collect::<Result<_, _>>()?;
+		Ok(Self {
+			rounds,
+			multilinear_evals,
+		})
+	}
+}
+
 #[derive(Debug, PartialEq, Eq)]
 pub struct BatchSumcheckOutput<F: Field> {
 	pub challenges: Vec<F>,
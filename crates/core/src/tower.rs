@@ -12,6 +12,9 @@ use trait_set::trait_set;
 
 /// A trait that groups a family of related [`TowerField`]s as associated types.
 pub trait TowerFamily {
+	/// A stable identifier of the tower, recorded in the configuration of proofs.
+	const ID: &'static str;
+
 	type B1: TowerField;
 	type B8: TowerField;
 	type B16: TowerField;
@@ -30,6 +33,8 @@ pub trait TowerFamily {
 pub struct CanonicalTowerFamily;
 
 impl TowerFamily for CanonicalTowerFamily {
+	const ID: &'static str = "canonical";
+
 	type B1 = BinaryField1b;
 	type B8 = BinaryField8b;
 	type B16 = BinaryField16b;
@@ -43,6 +48,8 @@ impl TowerFamily for CanonicalTowerFamily {
 pub struct AESTowerFamily;
 
 impl TowerFamily for AESTowerFamily {
+	const ID: &'static str = "aes";
+
 	type B1 = BinaryField1b;
 	type B8 = AESTowerField8b;
 	type B16 = AESTowerField16b;
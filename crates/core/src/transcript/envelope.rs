@@ -0,0 +1,243 @@
+// Copyright 2024 Irreducible Inc.
+
+//! The serialized form of a proof's transcript and advice.
+//!
+//! A [`ProofEnvelope`] frames the transcript and advice bytes with a header identifying the
+//! format version and the configuration the proof was produced with, so that a verifier rejects
+//! a proof for another field, hash function or PCS with [`Error::ConfigMismatch`] instead of
+//! failing somewhere in the middle of verification. The encoding is
+//!
+//! ```text
+//! magic        4 bytes, "BNUS"
+//! version      u16, little-endian
+//! field id     u16 length, UTF-8 bytes
+//! hash id      u16 length, UTF-8 bytes
+//! pcs id       u16 length, UTF-8 bytes
+//! transcript   u64 length, bytes
+//! advice       u64 length, bytes
+//! ```
+
+use super::Error;
+use bytes::{Buf, BufMut};
+use std::fmt;
+
+/// The first bytes of every encoded [`ProofEnvelope`].
+pub const PROOF_MAGIC: [u8; 4] = *b"BNUS";
+
+/// The version of the envelope encoding written by [`ProofEnvelope::encode`].
+pub const PROOF_FORMAT_VERSION: u16 = 1;
+
+/// Identifies the configuration a proof is produced and verified with.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ProofConfig {
+	/// The field the proof is over
+	pub field: String,
+	/// The hash function of the commitments
+	pub hash: String,
+	/// The polynomial commitment scheme
+	pub pcs: String,
+}
+
+impl ProofConfig {
+	pub fn new(field: impl Into<String>, hash: impl Into<String>, pcs: impl Into<String>) -> Self {
+		Self {
+			field: field.into(),
+			hash: hash.into(),
+			pcs: pcs.into(),
+		}
+	}
+}
+
+impl fmt::Display for ProofConfig {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "field {}, hash {}, PCS {}", self.field, self.hash, self.pcs)
+	}
+}
+
+/// A proof's transcript and advice together with the configuration they were produced with.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProofEnvelope {
+	pub config: ProofConfig,
+	pub transcript: Vec<u8>,
+	pub advice: Vec<u8>,
+}
+
+impl ProofEnvelope {
+	pub fn new(config: ProofConfig, transcript: Vec<u8>, advice: Vec<u8>) -> Self {
+		Self {
+			config,
+			transcript,
+			advice,
+		}
+	}
+
+	/// Returns an error if the proof was produced with another configuration than `expected`.
+	pub fn check_config(&self, expected: &ProofConfig) -> Result<(), Error> {
+		if &self.config != expected {
+			return Err(Error::ConfigMismatch {
+				expected: expected.clone(),
+				actual: self.config.clone(),
+			});
+		}
+		Ok(())
+	}
+
+	/// Encodes the envelope in the format described in the [module documentation](self).
+	pub fn encode(&self) -> Vec<u8> {
+		let ProofConfig { field, hash, pcs } = &self.config;
+		let mut out = Vec::new();
+		out.put_slice(&PROOF_MAGIC);
+		out.put_u16_le(PROOF_FORMAT_VERSION);
+		for id in [field, hash, pcs] {
+			let len = u16::try_from(id.len()).expect("identifiers are shorter than 64 KiB");
+			out.put_u16_le(len);
+			out.put_slice(id.as_bytes());
+		}
+		for bytes in [&self.transcript, &self.advice] {
+			out.put_u64_le(bytes.len() as u64);
+			out.put_slice(bytes);
+		}
+		out
+	}
+
+	/// Decodes an envelope, rejecting unknown versions and trailing bytes.
+	pub fn decode(mut bytes: &[u8]) -> Result<Self, Error> {
+		let mut magic = [0u8; PROOF_MAGIC.len()];
+		read_slice(&mut bytes, &mut magic)?;
+		if magic != PROOF_MAGIC {
+			return Err(Error::InvalidMagic);
+		}
+
+		if bytes.remaining() < 2 {
+			return Err(Error::NotEnoughBytes);
+		}
+		let version = bytes.get_u16_le();
+		if version != PROOF_FORMAT_VERSION {
+			return Err(Error::UnsupportedVersion {
+				version,
+				supported: PROOF_FORMAT_VERSION,
+			});
+		}
+
+		let field = read_identifier(&mut bytes)?;
+		let hash = read_identifier(&mut bytes)?;
+		let pcs = read_identifier(&mut bytes)?;
+		let transcript = read_framed(&mut bytes)?;
+		let advice = read_framed(&mut bytes)?;
+
+		if bytes.has_remaining() {
+			return Err(Error::TrailingBytes {
+				remaining: bytes.remaining(),
+			});
+		}
+
+		Ok(Self {
+			config: ProofConfig { field, hash, pcs },
+			transcript,
+			advice,
+		})
+	}
+
+	/// Decodes an envelope and checks that it was produced with the `expected` configuration.
+	pub fn decode_for(bytes: &[u8], expected: &ProofConfig) -> Result<Self, Error> {
+		let envelope = Self::decode(bytes)?;
+		envelope.check_config(expected)?;
+		Ok(envelope)
+	}
+}
+
+fn read_slice(bytes: &mut &[u8], out: &mut [u8]) -> Result<(), Error> {
+	if bytes.remaining() < out.len() {
+		return Err(Error::NotEnoughBytes);
+	}
+	bytes.copy_to_slice(out);
+	Ok(())
+}
+
+fn read_identifier(bytes: &mut &[u8]) -> Result<String, Error> {
+	if bytes.remaining() < 2 {
+		return Err(Error::NotEnoughBytes);
+	}
+	let len = bytes.get_u16_le() as usize;
+	let mut id = vec![0u8; len];
+	read_slice(bytes, &mut id)?;
+	String::from_utf8(id).map_err(|_| Error::InvalidIdentifier)
+}
+
+fn read_framed(bytes: &mut &[u8]) -> Result<Vec<u8>, Error> {
+	if bytes.remaining() < 8 {
+		return Err(Error::NotEnoughBytes);
+	}
+	let len = bytes.get_u64_le();
+	if (bytes.remaining() as u64) < len {
+		return Err(Error::NotEnoughBytes);
+	}
+	let mut out = vec![0u8; len as usize];
+	bytes.copy_to_slice(&mut out);
+	Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn envelope() -> ProofEnvelope {
+		ProofEnvelope::new(
+			ProofConfig::new("BinaryField128b", "Groestl256", "FRI"),
+			vec![1, 2, 3, 4],
+			vec![5, 6],
+		)
+	}
+
+	#[test]
+	fn test_encode_decode_roundtrip() {
+		let envelope = envelope();
+		let encoded = envelope.encode();
+		assert_eq!(&encoded[..4], b"BNUS");
+		assert_eq!(ProofEnvelope::decode(&encoded).unwrap(), envelope);
+		assert_eq!(ProofEnvelope::decode_for(&encoded, &envelope.config).unwrap(), envelope);
+	}
+
+	#[test]
+	fn test_decode_errors() {
+		let encoded = envelope().encode();
+
+		let mut bad_magic = encoded.clone();
+		bad_magic[0] ^= 1;
+		assert!(matches!(ProofEnvelope::decode(&bad_magic), Err(Error::InvalidMagic)));
+
+		let mut bad_version = encoded.clone();
+		bad_version[4] = 0xff;
+		assert!(matches!(
+			ProofEnvelope::decode(&bad_version),
+			Err(Error::UnsupportedVersion {
+				version: 0x00ff,
+				..
+			})
+		));
+
+		for len in 0..encoded.len() {
+			assert!(matches!(ProofEnvelope::decode(&encoded[..len]), Err(Error::NotEnoughBytes)));
+		}
+
+		let mut trailing = encoded.clone();
+		trailing.push(0);
+		assert!(matches!(
+			ProofEnvelope::decode(&trailing),
+			Err(Error::TrailingBytes { remaining: 1 })
+		));
+	}
+
+	#[test]
+	fn test_config_mismatch() {
+		let encoded = envelope().encode();
+		let other = ProofConfig::new("BinaryField128b", "Vision32b", "FRI");
+		match ProofEnvelope::decode_for(&encoded, &other) {
+			Err(Error::ConfigMismatch { expected, actual }) => {
+				assert_eq!(expected, other);
+				assert_eq!(actual.hash, "Groestl256");
+			}
+			result => panic!("expected a configuration mismatch, got {result:?}"),
+		}
+	}
+}
@@ -1,5 +1,6 @@
 // Copyright 2024 Irreducible Inc.
 
+use super::ProofConfig;
 use binius_utils::serialization::Error as SerializationError;
 
 #[derive(Debug, thiserror::Error)]
@@ -10,4 +11,17 @@ pub enum Error {
 	NotEnoughBytes,
 	#[error("Serialization error: {0}")]
 	Serialization(#[from] SerializationError),
+	#[error("Proof envelope does not start with the expected magic bytes")]
+	InvalidMagic,
+	#[error("Unsupported proof format version {version}, expected {supported}")]
+	UnsupportedVersion { version: u16, supported: u16 },
+	#[error("Proof envelope identifier is not valid UTF-8")]
+	InvalidIdentifier,
+	#[error("Proof envelope has {remaining} trailing bytes")]
+	TrailingBytes { remaining: usize },
+	#[error("Proof was produced for {actual}, but the verifier expects {expected}")]
+	ConfigMismatch {
+		expected: ProofConfig,
+		actual: ProofConfig,
+	},
 }
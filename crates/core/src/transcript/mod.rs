@@ -1,10 +1,12 @@
 // Copyright 2024 Irreducible Inc.
 
+mod envelope;
 mod error;
 
 use crate::{fiat_shamir::Challenger, merkle_tree::MerkleCap};
 use binius_field::{deserialize_canonical, serialize_canonical, PackedField, TowerField};
 use bytes::{buf::UninitSlice, Buf, BufMut, Bytes, BytesMut};
+pub use envelope::*;
 pub use error::Error;
 use p3_challenger::{CanObserve, CanSample, CanSampleBits};
 use std::slice;
@@ -1,8 +1,13 @@
 // Copyright 2024 Irreducible Inc.
 
+use super::serialization::{serialize_tagged, CONSTANT_TAG};
 use crate::polynomial::{Error, MultivariatePoly};
-use binius_field::TowerField;
-use binius_utils::bail;
+use binius_field::{deserialize_canonical, serialize_canonical, TowerField};
+use binius_utils::{
+	bail,
+	serialization::{DeserializeBytes, Error as SerializationError, SerializeBytes},
+};
+use bytes::{Buf, BufMut};
 
 /// A constant polynomial.
 #[derive(Debug, Copy, Clone)]
@@ -32,4 +37,23 @@ impl<F: TowerField> MultivariatePoly<F> for Constant<F> {
 	fn binary_tower_level(&self) -> usize {
 		F::TOWER_LEVEL
 	}
+
+	fn erased_serialize(&self, write_buf: &mut dyn BufMut) -> Result<(), SerializationError> {
+		serialize_tagged(CONSTANT_TAG, self, write_buf)
+	}
+}
+
+impl<F: TowerField> SerializeBytes for Constant<F> {
+	fn serialize(&self, mut write_buf: impl BufMut) -> Result<(), SerializationError> {
+		self.n_vars.serialize(&mut write_buf)?;
+		serialize_canonical(self.value, write_buf)
+	}
+}
+
+impl<F: TowerField> DeserializeBytes for Constant<F> {
+	fn deserialize(mut read_buf: impl Buf) -> Result<Self, SerializationError> {
+		let n_vars = usize::deserialize(&mut read_buf)?;
+		let value = deserialize_canonical(read_buf)?;
+		Ok(Self { n_vars, value })
+	}
 }
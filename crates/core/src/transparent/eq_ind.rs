@@ -1,10 +1,17 @@
 // Copyright 2024 Irreducible Inc.
 
+use super::serialization::{invalid_value, serialize_tagged, EQ_IND_PARTIAL_EVAL_TAG};
 use crate::polynomial::{Error, MultivariatePoly};
-use binius_field::{Field, PackedField, TowerField};
+use binius_field::{
+	deserialize_canonical_vec, serialize_canonical_slice, Field, PackedField, TowerField,
+};
 use binius_hal::ComputationBackend;
 use binius_math::MultilinearExtension;
-use binius_utils::bail;
+use binius_utils::{
+	bail,
+	serialization::{DeserializeBytes, Error as SerializationError, SerializeBytes},
+};
+use bytes::{Buf, BufMut};
 
 /// Represents the MLE of the eq(X, Y) polynomial on 2*n_vars variables partially evaluated at Y = r
 ///
@@ -63,6 +70,23 @@ impl<F: TowerField, P: PackedField<Scalar = F>> MultivariatePoly<P> for EqIndPar
 	fn binary_tower_level(&self) -> usize {
 		F::TOWER_LEVEL
 	}
+
+	fn erased_serialize(&self, write_buf: &mut dyn BufMut) -> Result<(), SerializationError> {
+		serialize_tagged(EQ_IND_PARTIAL_EVAL_TAG, self, write_buf)
+	}
+}
+
+impl<F: TowerField> SerializeBytes for EqIndPartialEval<F> {
+	fn serialize(&self, write_buf: impl BufMut) -> Result<(), SerializationError> {
+		serialize_canonical_slice(&self.r, write_buf)
+	}
+}
+
+impl<F: TowerField> DeserializeBytes for EqIndPartialEval<F> {
+	fn deserialize(read_buf: impl Buf) -> Result<Self, SerializationError> {
+		let r = deserialize_canonical_vec::<F, _>(read_buf)?;
+		Self::new(r.len(), r).map_err(invalid_value)
+	}
 }
 
 #[cfg(test)]
@@ -1,9 +1,12 @@
 // Copyright 2024 Irreducible Inc.
 
+use super::serialization::{invalid_value, serialize_multilinear_extension};
 use crate::polynomial::{Error, MultivariatePoly};
 use binius_field::{ExtensionField, PackedField, RepackedExtension, TowerField};
 use binius_hal::{make_portable_backend, ComputationBackendExt};
 use binius_math::{MLEEmbeddingAdapter, MultilinearExtension, MultilinearPoly};
+use binius_utils::serialization::Error as SerializationError;
+use bytes::BufMut;
 use std::{fmt::Debug, ops::Deref};
 
 /// A transparent multilinear polynomial defined as the multilinear extension over a small
@@ -73,4 +76,13 @@ where
 	fn binary_tower_level(&self) -> usize {
 		F::TOWER_LEVEL - self.data.log_extension_degree()
 	}
+
+	fn erased_serialize(&self, write_buf: &mut dyn BufMut) -> Result<(), SerializationError> {
+		let values = (0..1 << self.data.n_vars())
+			.map(|index| self.data.evaluate_on_hypercube(index))
+			.collect::<Result<Vec<F>, _>>()
+			.map_err(invalid_value)?;
+		let tower_level = MultivariatePoly::<F>::binary_tower_level(self);
+		serialize_multilinear_extension(tower_level, &values, write_buf)
+	}
 }
@@ -1,9 +1,14 @@
 // Copyright 2024 Irreducible Inc.
 
+use super::serialization::{invalid_value, serialize_tagged, SELECT_ROW_TAG};
 use crate::polynomial::{Error, MultivariatePoly};
 use binius_field::{packed::set_packed_slice, BinaryField1b, Field, PackedField};
 use binius_math::MultilinearExtension;
-use binius_utils::bail;
+use binius_utils::{
+	bail,
+	serialization::{DeserializeBytes, Error as SerializationError, SerializeBytes},
+};
+use bytes::{Buf, BufMut};
 
 /// Represents a multilinear F2-polynomial whose evaluations over the hypercube is 1 at
 /// a specific hypercube index, and 0 everywhere else.
@@ -82,6 +87,25 @@ impl<F: Field> MultivariatePoly<F> for SelectRow {
 	fn binary_tower_level(&self) -> usize {
 		0
 	}
+
+	fn erased_serialize(&self, write_buf: &mut dyn BufMut) -> Result<(), SerializationError> {
+		serialize_tagged(SELECT_ROW_TAG, self, write_buf)
+	}
+}
+
+impl SerializeBytes for SelectRow {
+	fn serialize(&self, mut write_buf: impl BufMut) -> Result<(), SerializationError> {
+		self.n_vars.serialize(&mut write_buf)?;
+		self.index.serialize(write_buf)
+	}
+}
+
+impl DeserializeBytes for SelectRow {
+	fn deserialize(mut read_buf: impl Buf) -> Result<Self, SerializationError> {
+		let n_vars = usize::deserialize(&mut read_buf)?;
+		let index = usize::deserialize(read_buf)?;
+		Self::new(n_vars, index).map_err(invalid_value)
+	}
 }
 
 #[cfg(test)]
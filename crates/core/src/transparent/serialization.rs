@@ -0,0 +1,254 @@
+// Copyright 2024 Irreducible Inc.
+
+//! Serialization of type-erased transparent polynomials.
+//!
+//! A transparent polynomial is written by [`MultivariatePoly::erased_serialize`] as a type tag
+//! followed by the type's own encoding. [`deserialize_transparent`] reads the tag back and
+//! reconstructs one of the transparent polynomials defined in this module.
+
+use super::{
+	constant::Constant, eq_ind::EqIndPartialEval, select_row::SelectRow,
+	shift_ind::ShiftIndPartialEval, step_down::StepDown, tower_basis::TowerBasis,
+};
+use crate::polynomial::{Error, MultivariatePoly};
+use binius_field::{
+	arithmetic_traits::Square, deserialize_canonical, serialize_canonical, Field, TowerField,
+};
+use binius_hal::{make_portable_backend, ComputationBackendExt};
+use binius_math::MultilinearExtension;
+use binius_utils::{
+	bail,
+	serialization::{DeserializeBytes, Error as SerializationError, SerializeBytes},
+};
+use bytes::{Buf, BufMut};
+use std::{fmt::Display, sync::Arc};
+
+pub(super) const CONSTANT_TAG: &str = "Constant";
+pub(super) const EQ_IND_PARTIAL_EVAL_TAG: &str = "EqIndPartialEval";
+pub(super) const MULTILINEAR_EXTENSION_TAG: &str = "MultilinearExtension";
+pub(super) const SELECT_ROW_TAG: &str = "SelectRow";
+pub(super) const SHIFT_IND_PARTIAL_EVAL_TAG: &str = "ShiftIndPartialEval";
+pub(super) const STEP_DOWN_TAG: &str = "StepDown";
+pub(super) const TOWER_BASIS_TAG: &str = "TowerBasis";
+
+/// Writes `tag` followed by the encoding of `poly`.
+pub(super) fn serialize_tagged(
+	tag: &str,
+	poly: &impl SerializeBytes,
+	mut write_buf: impl BufMut,
+) -> Result<(), SerializationError> {
+	tag.serialize(&mut write_buf)?;
+	poly.serialize(write_buf)
+}
+
+/// Maps an error from the constructor of a transparent polynomial to a serialization error.
+pub(super) fn invalid_value(err: impl Display) -> SerializationError {
+	SerializationError::InvalidValue(err.to_string())
+}
+
+/// Reads a transparent polynomial written by [`MultivariatePoly::erased_serialize`].
+pub fn deserialize_transparent<F: TowerField>(
+	mut read_buf: impl Buf,
+) -> Result<Arc<dyn MultivariatePoly<F>>, SerializationError> {
+	let tag = String::deserialize(&mut read_buf)?;
+	let poly: Arc<dyn MultivariatePoly<F>> = match tag.as_str() {
+		CONSTANT_TAG => Arc::new(Constant::<F>::deserialize(read_buf)?),
+		EQ_IND_PARTIAL_EVAL_TAG => Arc::new(EqIndPartialEval::<F>::deserialize(read_buf)?),
+		SELECT_ROW_TAG => Arc::new(SelectRow::deserialize(read_buf)?),
+		SHIFT_IND_PARTIAL_EVAL_TAG => Arc::new(ShiftIndPartialEval::<F>::deserialize(read_buf)?),
+		STEP_DOWN_TAG => Arc::new(StepDown::deserialize(read_buf)?),
+		TOWER_BASIS_TAG => Arc::new(TowerBasis::<F>::deserialize(read_buf)?),
+		MULTILINEAR_EXTENSION_TAG => {
+			Arc::new(EmbeddedMultilinearExtension::<F>::deserialize(read_buf)?)
+		}
+		_ => return Err(SerializationError::UnknownTypeTag(tag)),
+	};
+	Ok(poly)
+}
+
+/// A deserialized [`MultilinearExtensionTransparent`].
+///
+/// The packed subfield type of the original polynomial is not recoverable from the encoding, so
+/// the values are stored in `F`. The original tower level is kept, as it is observable through
+/// [`MultivariatePoly::binary_tower_level`].
+///
+/// [`MultilinearExtensionTransparent`]: super::multilinear_extension::MultilinearExtensionTransparent
+#[derive(Debug)]
+struct EmbeddedMultilinearExtension<F: TowerField> {
+	tower_level: usize,
+	mle: MultilinearExtension<F>,
+}
+
+impl<F: TowerField> MultivariatePoly<F> for EmbeddedMultilinearExtension<F> {
+	fn n_vars(&self) -> usize {
+		self.mle.n_vars()
+	}
+
+	fn degree(&self) -> usize {
+		self.mle.n_vars()
+	}
+
+	fn evaluate(&self, query: &[F]) -> Result<F, Error> {
+		if query.len() != self.mle.n_vars() {
+			bail!(Error::IncorrectQuerySize {
+				expected: self.mle.n_vars(),
+			});
+		}
+		let backend = make_portable_backend();
+		let query = backend.multilinear_query::<F>(query)?;
+		Ok(self.mle.evaluate(query.to_ref())?)
+	}
+
+	fn binary_tower_level(&self) -> usize {
+		self.tower_level
+	}
+
+	fn erased_serialize(&self, write_buf: &mut dyn BufMut) -> Result<(), SerializationError> {
+		serialize_multilinear_extension(self.tower_level, self.mle.evals(), write_buf)
+	}
+}
+
+impl<F: TowerField> DeserializeBytes for EmbeddedMultilinearExtension<F> {
+	fn deserialize(mut read_buf: impl Buf) -> Result<Self, SerializationError> {
+		let tower_level = usize::deserialize(&mut read_buf)?;
+		if tower_level > F::TOWER_LEVEL {
+			return Err(invalid_value(format!("tower level {tower_level} exceeds the field")));
+		}
+		let n_values = usize::deserialize(&mut read_buf)?;
+		let values = (0..n_values)
+			.map(|_| deserialize_canonical(&mut read_buf))
+			.collect::<Result<Vec<F>, _>>()?;
+		if let Some(value) = values
+			.iter()
+			.find(|&&value| !is_in_subfield(value, tower_level))
+		{
+			return Err(invalid_value(format!(
+				"value {value} does not lie in the subfield of tower level {tower_level}"
+			)));
+		}
+		let mle = MultilinearExtension::from_values(values).map_err(invalid_value)?;
+		Ok(Self { tower_level, mle })
+	}
+}
+
+/// Whether `value` lies in the subfield of `F` at `tower_level`.
+///
+/// That subfield is exactly the set of elements fixed by the Frobenius map
+/// $x \mapsto x^{2^{2^\iota}}$, which keeps the check independent of the tower basis of `F`.
+fn is_in_subfield<F: Field>(value: F, tower_level: usize) -> bool {
+	(0..1 << tower_level).fold(value, |x, _| x.square()) == value
+}
+
+/// Writes a multilinear extension transparent by its tower level and hypercube evaluations.
+pub(super) fn serialize_multilinear_extension<F: TowerField>(
+	tower_level: usize,
+	values: &[F],
+	mut write_buf: impl BufMut,
+) -> Result<(), SerializationError> {
+	MULTILINEAR_EXTENSION_TAG.serialize(&mut write_buf)?;
+	tower_level.serialize(&mut write_buf)?;
+	values.len().serialize(&mut write_buf)?;
+	for &value in values {
+		serialize_canonical(value, &mut write_buf)?;
+	}
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::transparent::multilinear_extension::MultilinearExtensionTransparent;
+	use binius_field::{
+		BinaryField128b, BinaryField8b, Field, PackedBinaryField16x8b, PackedBinaryField1x128b,
+		PackedField,
+	};
+	use rand::{rngs::StdRng, SeedableRng};
+	use std::iter::repeat_with;
+
+	type F = BinaryField128b;
+
+	fn roundtrip(poly: &dyn MultivariatePoly<F>) -> Arc<dyn MultivariatePoly<F>> {
+		let mut buf = Vec::new();
+		poly.erased_serialize(&mut buf).unwrap();
+		let mut read_buf = buf.as_slice();
+		let deserialized = deserialize_transparent::<F>(&mut read_buf).unwrap();
+		assert!(read_buf.is_empty());
+		deserialized
+	}
+
+	fn assert_same_poly(poly: &dyn MultivariatePoly<F>) {
+		let deserialized = roundtrip(poly);
+		assert_eq!(deserialized.n_vars(), poly.n_vars());
+		assert_eq!(deserialized.degree(), poly.degree());
+		assert_eq!(deserialized.binary_tower_level(), poly.binary_tower_level());
+
+		let mut rng = StdRng::seed_from_u64(0);
+		let query = repeat_with(|| F::random(&mut rng))
+			.take(poly.n_vars())
+			.collect::<Vec<_>>();
+		assert_eq!(deserialized.evaluate(&query).unwrap(), poly.evaluate(&query).unwrap());
+	}
+
+	#[test]
+	fn test_roundtrip_transparents() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let r = repeat_with(|| F::random(&mut rng))
+			.take(4)
+			.collect::<Vec<_>>();
+
+		assert_same_poly(&Constant {
+			n_vars: 3,
+			value: F::new(7),
+		});
+		assert_same_poly(&StepDown::new(4, 5).unwrap());
+		assert_same_poly(&SelectRow::new(4, 5).unwrap());
+		assert_same_poly(&EqIndPartialEval::new(4, r.clone()).unwrap());
+		assert_same_poly(
+			&ShiftIndPartialEval::new(4, 3, crate::oracle::ShiftVariant::LogicalRight, r).unwrap(),
+		);
+		assert_same_poly(&TowerBasis::<F>::new(3, 2).unwrap());
+	}
+
+	#[test]
+	fn test_roundtrip_multilinear_extension() {
+		let values = (0..2u8)
+			.map(|i| {
+				PackedBinaryField16x8b::from_scalars(
+					(0..16).map(|j| BinaryField8b::new(16 * i + j)),
+				)
+			})
+			.collect::<Vec<_>>();
+		let poly =
+			MultilinearExtensionTransparent::<_, PackedBinaryField1x128b, _>::from_values(values)
+				.unwrap();
+		assert_eq!(MultivariatePoly::<F>::binary_tower_level(&poly), 3);
+		assert_same_poly(&poly);
+	}
+
+	#[test]
+	fn test_multilinear_extension_outside_subfield() {
+		// 2^8 is the primitive element of T_4 / T_3, so it is not in the claimed 8-bit subfield
+		let values = [F::ONE, F::new(1 << 8)];
+		let mut buf = Vec::new();
+		serialize_multilinear_extension(3, &values, &mut buf).unwrap();
+		assert!(matches!(
+			deserialize_transparent::<F>(buf.as_slice()),
+			Err(SerializationError::InvalidValue(_))
+		));
+
+		let mut buf = Vec::new();
+		serialize_multilinear_extension(4, &values, &mut buf).unwrap();
+		let poly = deserialize_transparent::<F>(buf.as_slice()).unwrap();
+		assert_eq!(poly.binary_tower_level(), 4);
+	}
+
+	#[test]
+	fn test_unknown_tag() {
+		let mut buf = Vec::new();
+		"NotATransparent".serialize(&mut buf).unwrap();
+		assert!(matches!(
+			deserialize_transparent::<F>(buf.as_slice()),
+			Err(SerializationError::UnknownTypeTag(_))
+		));
+	}
+}
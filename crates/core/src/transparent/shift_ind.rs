@@ -1,12 +1,20 @@
 // Copyright 2024 Irreducible Inc.
 
+use super::serialization::{invalid_value, serialize_tagged, SHIFT_IND_PARTIAL_EVAL_TAG};
 use crate::{
 	oracle::ShiftVariant,
 	polynomial::{Error, MultivariatePoly},
 };
-use binius_field::{util::eq, Field, PackedFieldIndexable, TowerField};
+use binius_field::{
+	deserialize_canonical_vec, serialize_canonical_slice, util::eq, Field, PackedFieldIndexable,
+	TowerField,
+};
 use binius_math::MultilinearExtension;
-use binius_utils::bail;
+use binius_utils::{
+	bail,
+	serialization::{DeserializeBytes, Error as SerializationError, SerializeBytes},
+};
+use bytes::{Buf, BufMut};
 
 /// Represents MLE of shift indicator $f_{b, o}(X, Y)$ on $2*b$ variables
 /// partially evaluated at $Y = r$
@@ -196,6 +204,29 @@ impl<F: TowerField> MultivariatePoly<F> for ShiftIndPartialEval<F> {
 	fn binary_tower_level(&self) -> usize {
 		F::TOWER_LEVEL
 	}
+
+	fn erased_serialize(&self, write_buf: &mut dyn BufMut) -> Result<(), SerializationError> {
+		serialize_tagged(SHIFT_IND_PARTIAL_EVAL_TAG, self, write_buf)
+	}
+}
+
+impl<F: TowerField> SerializeBytes for ShiftIndPartialEval<F> {
+	fn serialize(&self, mut write_buf: impl BufMut) -> Result<(), SerializationError> {
+		self.block_size.serialize(&mut write_buf)?;
+		self.shift_offset.serialize(&mut write_buf)?;
+		self.shift_variant.serialize(&mut write_buf)?;
+		serialize_canonical_slice(&self.r, write_buf)
+	}
+}
+
+impl<F: TowerField> DeserializeBytes for ShiftIndPartialEval<F> {
+	fn deserialize(mut read_buf: impl Buf) -> Result<Self, SerializationError> {
+		let block_size = usize::deserialize(&mut read_buf)?;
+		let shift_offset = usize::deserialize(&mut read_buf)?;
+		let shift_variant = ShiftVariant::deserialize(&mut read_buf)?;
+		let r = deserialize_canonical_vec(read_buf)?;
+		Self::new(block_size, shift_offset, shift_variant, r).map_err(invalid_value)
+	}
 }
 
 /// Gets right shift offset from left shift offset
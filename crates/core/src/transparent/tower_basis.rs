@@ -1,9 +1,14 @@
 // Copyright 2024 Irreducible Inc.
 
+use super::serialization::{invalid_value, serialize_tagged, TOWER_BASIS_TAG};
 use crate::polynomial::{Error, MultivariatePoly};
 use binius_field::{Field, PackedField, TowerField};
 use binius_math::MultilinearExtension;
-use binius_utils::bail;
+use binius_utils::{
+	bail,
+	serialization::{DeserializeBytes, Error as SerializationError, SerializeBytes},
+};
+use bytes::{Buf, BufMut};
 use std::marker::PhantomData;
 
 /// Represents the $\mathcal{T}_{\iota}$-basis of $\mathcal{T}_{\iota+k}$
@@ -89,6 +94,25 @@ where
 	fn binary_tower_level(&self) -> usize {
 		self.iota + self.k
 	}
+
+	fn erased_serialize(&self, write_buf: &mut dyn BufMut) -> Result<(), SerializationError> {
+		serialize_tagged(TOWER_BASIS_TAG, self, write_buf)
+	}
+}
+
+impl<F: TowerField> SerializeBytes for TowerBasis<F> {
+	fn serialize(&self, mut write_buf: impl BufMut) -> Result<(), SerializationError> {
+		self.k.serialize(&mut write_buf)?;
+		self.iota.serialize(write_buf)
+	}
+}
+
+impl<F: TowerField> DeserializeBytes for TowerBasis<F> {
+	fn deserialize(mut read_buf: impl Buf) -> Result<Self, SerializationError> {
+		let k = usize::deserialize(&mut read_buf)?;
+		let iota = usize::deserialize(read_buf)?;
+		Self::new(k, iota).map_err(invalid_value)
+	}
 }
 
 #[cfg(test)]
@@ -10,10 +10,14 @@ use super::packed_arithmetic::UnderlierWithBitConstants;
 use crate::{
 	arithmetic_traits::{Broadcast, InvertOrZero, MulAlpha, Square},
 	underlier::{NumCast, UnderlierType, UnderlierWithBitOps, WithUnderlier, U1, U2, U4},
-	BinaryField, PackedField,
+	BinaryField, PackedField, TowerField,
+};
+use binius_utils::{
+	checked_arithmetics::checked_int_div,
+	serialization::{DeserializeBytes, Error as SerializationError, SerializeBytes},
 };
-use binius_utils::checked_arithmetics::checked_int_div;
 use bytemuck::{Pod, TransparentWrapper, Zeroable};
+use bytes::{Buf, BufMut};
 use rand::RngCore;
 use std::{
 	fmt::Debug,
@@ -57,6 +61,31 @@ impl<U: UnderlierType, Scalar: BinaryField> PackedPrimitiveType<U, Scalar> {
 	}
 }
 
+/// Packed elements are encoded as their scalars in order, each with the canonical encoding.
+impl<U: UnderlierType, Scalar: TowerField> SerializeBytes for PackedPrimitiveType<U, Scalar>
+where
+	Self: PackedField<Scalar = Scalar>,
+{
+	fn serialize(&self, mut write_buf: impl BufMut) -> Result<(), SerializationError> {
+		for scalar in PackedField::iter(self) {
+			crate::serialize_canonical(scalar, &mut write_buf)?;
+		}
+		Ok(())
+	}
+}
+
+impl<U: UnderlierType, Scalar: TowerField> DeserializeBytes for PackedPrimitiveType<U, Scalar>
+where
+	Self: PackedField<Scalar = Scalar>,
+{
+	fn deserialize(mut read_buf: impl Buf) -> Result<Self, SerializationError> {
+		let scalars = (0..<Self as PackedField>::WIDTH)
+			.map(|_| crate::deserialize_canonical(&mut read_buf))
+			.collect::<Result<Vec<Scalar>, _>>()?;
+		Ok(<Self as PackedField>::from_scalars(scalars))
+	}
+}
+
 unsafe impl<U: UnderlierType, Scalar: BinaryField> WithUnderlier
 	for PackedPrimitiveType<U, Scalar>
 {
@@ -10,8 +10,12 @@ use crate::{
 	underlier::{ScaledUnderlier, UnderlierType, WithUnderlier},
 	Field, PackedField,
 };
-use binius_utils::checked_arithmetics::checked_log_2;
+use binius_utils::{
+	checked_arithmetics::checked_log_2,
+	serialization::{DeserializeBytes, Error as SerializationError, SerializeBytes},
+};
 use bytemuck::{Pod, TransparentWrapper, Zeroable};
+use bytes::{Buf, BufMut};
 use rand::RngCore;
 use std::{
 	array,
@@ -180,6 +184,26 @@ where
 	}
 }
 
+impl<PT: PackedField + SerializeBytes, const N: usize> SerializeBytes for ScaledPackedField<PT, N> {
+	fn serialize(&self, mut write_buf: impl BufMut) -> Result<(), SerializationError> {
+		for inner in &self.0 {
+			inner.serialize(&mut write_buf)?;
+		}
+		Ok(())
+	}
+}
+
+impl<PT: PackedField + DeserializeBytes, const N: usize> DeserializeBytes
+	for ScaledPackedField<PT, N>
+{
+	fn deserialize(mut read_buf: impl Buf) -> Result<Self, SerializationError> {
+		let inner = (0..N)
+			.map(|_| PT::deserialize(&mut read_buf))
+			.collect::<Result<Vec<_>, _>>()?;
+		Ok(Self::from_direct_packed_fn(|i| inner[i]))
+	}
+}
+
 impl<PT: PackedField, const N: usize> PackedField for ScaledPackedField<PT, N>
 where
 	[PT; N]: Default,
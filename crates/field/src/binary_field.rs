@@ -743,6 +743,29 @@ pub fn deserialize_canonical<F: TowerField, R: Buf>(
 	Ok(F::from(as_canonical))
 }
 
+/// Serializes a length-prefixed slice of [`TowerField`] elements with a canonical encoding.
+pub fn serialize_canonical_slice<F: TowerField, W: BufMut>(
+	elems: &[F],
+	mut writer: W,
+) -> Result<(), SerializationError> {
+	elems.len().serialize(&mut writer)?;
+	for &elem in elems {
+		serialize_canonical(elem, &mut writer)?;
+	}
+	Ok(())
+}
+
+/// Deserializes a length-prefixed vector of [`TowerField`] elements written by
+/// [`serialize_canonical_slice`].
+pub fn deserialize_canonical_vec<F: TowerField, R: Buf>(
+	mut reader: R,
+) -> Result<Vec<F>, SerializationError> {
+	let len = usize::deserialize(&mut reader)?;
+	(0..len)
+		.map(|_| deserialize_canonical(&mut reader))
+		.collect()
+}
+
 impl From<BinaryField1b> for Choice {
 	fn from(val: BinaryField1b) -> Self {
 		Choice::from(val.val().val())
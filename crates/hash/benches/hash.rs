@@ -4,16 +4,14 @@ use binius_field::{
 	PackedBinaryField32x8b, PackedField,
 };
 use binius_hash::{
-	FixedLenHasherDigest, Groestl256, GroestlDigest, GroestlDigestCompression, HashDigest,
-	HasherDigest, Vision32b, VisionHasher,  GroestlHasher, Hasher
+	BsGroestl256, BsGroestl256Hasher, FixedLenHasherDigest, Groestl256, GroestlDigest,
+	GroestlDigestCompression, GroestlHasher, HashDigest, Hasher, HasherDigest, MultiHasher,
+	MultiVision32b, Vision32b, VisionHasher, BS_GROESTL_LANES,
 };
 use criterion::{criterion_group, criterion_main, Criterion, Throughput};
 use groestl_crypto::{Digest, Groestl256 as GenericGroestl256};
 use p3_symmetric::PseudoCompressionFunction;
-use rand::{thread_rng, RngCore};
-use binius_hash::bs_groestl::*;
-use rand::prelude::*;
-use rayon::prelude::*;
+use rand::{prelude::*, thread_rng, RngCore};
 use rayon::{collections::linked_list, prelude::*};
 use std::{iter::repeat_with, marker::PhantomData, mem};
 
@@ -58,7 +56,7 @@ fn bench_groestl(c: &mut Criterion) {
 	let mut rng = thread_rng();
 
 	const N: usize = 1 << 8;
-    println!("n: {}", N);
+	println!("n: {}", N);
 	let data_aes: [PackedAESBinaryField32x8b; N] =
 		array::from_fn(|_| PackedAESBinaryField32x8b::random(&mut rng));
 	let data_bin: [PackedBinaryField32x8b; N] =
@@ -93,20 +91,6 @@ fn bench_groestl(c: &mut Criterion) {
 // 	group.finish()
 // }
 
-// Function to convert a slice of PackedPrimitiveType to a slice of u8
-// let data_bin: [PackedBinaryField32x8b; N] =
-// array::from_fn(|_| PackedBinaryField32x8b::random(&mut rng));
-
-fn packed_to_bytes(packed: &[PackedPrimitiveType]) -> &[PackedBinaryField32x8b] {
-    unsafe {
-        std::slice::from_raw_parts(
-            packed.as_ptr() as *const PackedBinaryField32x8b,
-            packed.len() * std::mem::size_of::<PackedPrimitiveType>(),
-        )
-    }
-}
-
-
 // fn bench_groestl_long_data(c: &mut Criterion) {
 //     let mut group = c.benchmark_group("groestl");
 //     let n_hashes = 64;
@@ -123,7 +107,7 @@ fn packed_to_bytes(packed: &[PackedPrimitiveType]) -> &[PackedBinaryField32x8b]
 //     group.bench_function("Groestl256-nonbitsliced", |bench| {
 //         bench.iter(|| {
 //             testinput
-//                 .chunks_exact(input_items_length / n_hashes) 
+//                 .chunks_exact(input_items_length / n_hashes)
 //                 .enumerate()
 //                 .map(|(index, chunk)| {
 //                     let chunk_bytes = packed_to_bytes(chunk);
@@ -138,52 +122,63 @@ fn packed_to_bytes(packed: &[PackedPrimitiveType]) -> &[PackedBinaryField32x8b]
 //     group.finish();
 // }
 
-// Function to generate a random PackedPrimitiveType
-fn random_packed_primitive(rng: &mut impl Rng) -> PackedPrimitiveType {
-    PackedPrimitiveType {
-        value: M128 {
-            high: rng.gen(),
-            low: rng.gen(),
-        },
-    }
-}
-
 fn bench_groestl_bitsliced(c: &mut Criterion) {
 	let mut group = c.benchmark_group("groestl");
-	let n_hashes = 64;
-	let input_items_length = 1024;
-	// let size_of_packed_primitive = std::mem::size_of::<PackedPrimitiveType>();
-	let size_of_packed_primitive = 16; //bytes
-
-    let default_input_value = PackedPrimitiveType {
-        value: M128 { high: 0, low: 0 },
-    };
-
-    // Initialize the vector with default values using the vec! macro and an iterator
-    let mut testdigests: Vec<ScaledPackedField<PackedPrimitiveType, 2>> = vec![
-        ScaledPackedField {
-            elements: [default_input_value, default_input_value]
-        };
-        n_hashes
-    ];
+
 	let mut rng = thread_rng();
-	let mut testinput = vec![random_packed_primitive(&mut rng); input_items_length];
 
-	// const N: usize = 8192;
-	// let mut data = [0u8; N];
-	// rng.fill_bytes(&mut testinput);
+	let n_hashes = BS_GROESTL_LANES;
+	let msg_len = 256;
+	let mut data = vec![0u8; n_hashes * msg_len];
+	rng.fill_bytes(&mut data);
+	let messages = data.chunks_exact(msg_len).collect::<Vec<_>>();
 
-    group.throughput(Throughput::Bytes((input_items_length * std::mem::size_of::<PackedPrimitiveType>()) as u64));
+	group.throughput(Throughput::Bytes(data.len() as u64));
 	group.bench_function("Groestl256-bitsliced", |bench| {
-        bench.iter(|| {
-            // let start_time = std::time::Instant::now();
-            unsafe {
-                binius_groestl_bs_hash(testdigests.as_mut_ptr(), testinput.as_mut_ptr(), input_items_length * size_of_packed_primitive, (input_items_length * size_of_packed_primitive)/n_hashes);
-            }
-            // let elapsed_time = start_time.elapsed();
-            // println!("Elapsed time: {:?}", elapsed_time);
-        }
-    )});
+		bench.iter(|| BsGroestl256::<AESTowerField8b>::hash_many(&messages).unwrap());
+	});
+
+	// The same batch hashed one message at a time with the backend of `Groestl256Core`, which is
+	// the AVX-512 one where available, against the batch backend selected at runtime
+	let field_messages = messages
+		.iter()
+		.map(|msg| {
+			msg.iter()
+				.map(|&b| AESTowerField8b::new(b))
+				.collect::<Vec<_>>()
+		})
+		.collect::<Vec<_>>();
+	let field_messages = field_messages.iter().map(Vec::as_slice).collect::<Vec<_>>();
+	group.bench_function("Groestl256-per-message", |bench| {
+		bench.iter(|| {
+			field_messages
+				.iter()
+				.map(|msg| {
+					HasherDigest::<AESTowerField8b, Groestl256<_, AESTowerField8b>>::hash(msg)
+				})
+				.collect::<Vec<_>>()
+		});
+	});
+	group.bench_function("Groestl256-dispatched-batch", |bench| {
+		bench.iter(|| {
+			BsGroestl256Hasher::<AESTowerField8b, AESTowerField8b>::hash_many(&field_messages)
+		});
+	});
+
+	// Lengths are kept aligned so that the C kernel accepts them as well
+	let var_data = (0..4 * n_hashes)
+		.map(|_| {
+			let mut msg = vec![0u8; rng.gen_range(1..=32) * 16];
+			rng.fill_bytes(&mut msg);
+			msg
+		})
+		.collect::<Vec<_>>();
+	let var_messages = var_data.iter().map(Vec::as_slice).collect::<Vec<_>>();
+
+	group.throughput(Throughput::Bytes(var_data.iter().map(Vec::len).sum::<usize>() as u64));
+	group.bench_function("Groestl256-bitsliced-variable-length", |bench| {
+		bench.iter(|| BsGroestl256::<AESTowerField8b>::hash_many(&var_messages).unwrap());
+	});
 
 	group.finish()
 }
@@ -211,19 +206,23 @@ fn bench_vision32(c: &mut Criterion) {
 		})
 	});
 
+	// The same amount of data split into many short messages, as hashed by Merkle tree leaves
+	let messages = data_bin.chunks_exact(64).collect::<Vec<_>>();
+	group.bench_function("Vision multi-lane over BinaryField32b", |bench| {
+		bench.iter(|| MultiVision32b::<BinaryField32b>::hash_many(&messages))
+	});
+
 	group.finish()
 }
 
-
-
 criterion_group!(
 	hash,
 	// bench_groestl_compression,
 	// bench_groestl,
 	// bench_groestl_rustcrypto,
+	bench_groestl_bitsliced,
 	bench_vision32
 );
 //criterion_group!(hash, bench_groestl_bitsliced, bench_groestl_long_data);
 
 criterion_main!(hash);
-
@@ -1,54 +1,34 @@
-use cc;
-fn main() {
-    // Specify the C library name and path
-    let library_name = "testrustinput";
-    let files = ["src/groestl/custom_groestl/groestl256/hash.c", "src/groestl/custom_groestl/groestl256/bs.c"];
-    let includes = "src/groestl/custom_groestl/groestl256";
-
-    if cfg!(target_os = "windows") {
-        cc::Build::new()
-        .files(files) // Include both C files
-        // .flag("/O2")
-        // .flag("/ftree-vectorize")
-        .include(includes) // Include the directory containing the headers
-        .compile(library_name); // Compile into a static library
-    } else {
-        cc::Build::new()
-        .files(files) // Include both C files
-        .flag_if_supported("-O3")
-        .flag_if_supported("-ftree-vectorize")
-        .flag_if_supported("-Wall")
-        .flag("-O2")
-        // .flag("-ftree-vectorize")
-        .include(includes) // Include the directory containing the headers
-        .compile(library_name); // Compile into a static library
-    }
-    
-    let library_path = env!("CARGO_MANIFEST_DIR");
-    // Print the library path
-    println!("XXXXXXXXXXXXXXXXXXXXX Library Path:");
+// Copyright 2024 Irreducible Inc.
 
-    println!("XXXXXXXXXXXXXXXXXXXXX Library Path: {}", library_path);
+fn main() {
+	println!("cargo:rerun-if-changed=build.rs");
 
-    // // Link the C library
-    println!("cargo:rustc-link-lib=static={}", library_name);
-    println!("cargo:rustc-link-search={}", library_path);
-    println!("cargo:rerun-if-changed=build.rs");
+	#[cfg(feature = "bs-groestl-c")]
+	build_bs_groestl_c();
+}
 
+/// Compiles the bitsliced Grøstl kernel from the `custom_groestl` submodule
+#[cfg(feature = "bs-groestl-c")]
+fn build_bs_groestl_c() {
+	let library_name = "testrustinput";
+	let files = [
+		"src/groestl/custom_groestl/groestl256/hash.c",
+		"src/groestl/custom_groestl/groestl256/bs.c",
+	];
+	let includes = "src/groestl/custom_groestl/groestl256";
 
-    // Build the C library using make or cmake
-    // #[cfg(target_os = "macos")]
-    // {
-    //     let _ = std::process::Command::new("make")
-    //         .current_dir(library_path)
-    //         .status();
-    // }
+	for file in files {
+		println!("cargo:rerun-if-changed={file}");
+	}
 
-    // #[cfg(target_os = "linux")]
-    // {
-    //     let _ = std::process::Command::new("cmake")
-    //         .arg("--build")
-    //         .arg(library_path)
-    //         .status();
-    // }
-}
\ No newline at end of file
+	let mut build = cc::Build::new();
+	build.files(files).include(includes);
+	if !cfg!(target_os = "windows") {
+		build
+			.flag_if_supported("-O3")
+			.flag_if_supported("-ftree-vectorize")
+			.flag_if_supported("-Wall");
+	}
+	// Emits the link directives for the static library as well
+	build.compile(library_name);
+}
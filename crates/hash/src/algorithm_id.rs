@@ -0,0 +1,44 @@
+// Copyright 2024 Irreducible Inc.
+
+//! Stable identifiers of hash and compression algorithms, recorded in the configuration of proofs.
+
+use binius_field::{AESTowerField8b, BinaryField32b, BinaryField8b};
+
+/// A stable, versioned identifier of a hash or compression algorithm.
+///
+/// Proofs record these identifiers rather than the Rust types implementing the algorithms, so that
+/// the record does not depend on the compiler, and implementations computing the same digests,
+/// such as the table-based and the bitsliced Grøstl-256, verify each other's proofs.
+pub trait HashAlgorithmId {
+	/// The name of the algorithm, e.g. `"groestl256"`.
+	const NAME: &'static str;
+	/// The version of the algorithm, bumped whenever its output for a given input changes.
+	const VERSION: u16;
+	/// The field tower the digests are expressed in, see [`FieldTowerId`].
+	const TOWER: &'static str;
+	/// The length of a digest in bytes.
+	const DIGEST_BYTES: usize;
+}
+
+/// Formats the identifier of `H`, e.g. `groestl256-v1/canonical/32`.
+pub fn hash_algorithm_id<H: HashAlgorithmId>() -> String {
+	format!("{}-v{}/{}/{}", H::NAME, H::VERSION, H::TOWER, H::DIGEST_BYTES)
+}
+
+/// The tower of a field that digests are expressed in.
+pub trait FieldTowerId {
+	/// `"canonical"` for the Fan-Paar tower, `"aes"` for the tower over the Rijndael field.
+	const TOWER: &'static str;
+}
+
+impl FieldTowerId for BinaryField8b {
+	const TOWER: &'static str = "canonical";
+}
+
+impl FieldTowerId for BinaryField32b {
+	const TOWER: &'static str = "canonical";
+}
+
+impl FieldTowerId for AESTowerField8b {
+	const TOWER: &'static str = "aes";
+}
@@ -0,0 +1,28 @@
+// Copyright 2024 Irreducible Inc.
+
+use p3_symmetric::PseudoCompressionFunction;
+
+/// Extension of [`PseudoCompressionFunction`] for compression functions that can process many
+/// independent inputs at once.
+///
+/// The default implementation compresses the inputs one after another. Implementations that
+/// compress several inputs in parallel, such as bitsliced ones, override
+/// [`MultiPseudoCompressionFunction::compress_many`] and advertise their preferred group size
+/// through [`MultiPseudoCompressionFunction::BATCH_WIDTH`].
+pub trait MultiPseudoCompressionFunction<T, const N: usize>:
+	PseudoCompressionFunction<T, N>
+{
+	/// Number of inputs that are processed together most efficiently.
+	const BATCH_WIDTH: usize = 1;
+
+	/// Compresses every input independently and returns the outputs in the same order.
+	fn compress_many(&self, inputs: &[[T; N]]) -> Vec<T>
+	where
+		T: Clone,
+	{
+		inputs
+			.iter()
+			.map(|input| self.compress(input.clone()))
+			.collect()
+	}
+}
@@ -0,0 +1,643 @@
+// Copyright 2024 Irreducible Inc.
+
+//! Bitsliced versions of the P and Q permutations of Grøstl256 (512-bit state) and Grøstl512
+//! (1024-bit state).
+//!
+//! The states of [`BITSLICED_LANES`] independent permutation instances are stored transposed:
+//! bit `b` of byte `i` of instance `l` is bit `l` of `state[i][b]`. The S-box is evaluated with
+//! the circuit of Boyar and Peralta, so no step of the permutations depends on the state values.
+//!
+//! Everything in this module is constant-time with respect to the state: there are no branches
+//! and no memory accesses at positions derived from state bytes. Branches only depend on public
+//! values such as round numbers, round constants and the columns of [`transform_bytes`]. Keep it
+//! that way, `tests/constant_time.rs` checks the bitsliced hashing statistically.
+
+use std::array;
+
+/// The number of permutation instances evaluated in parallel, one per bit of a `u64`.
+pub const BITSLICED_LANES: usize = 64;
+
+const STATE_BYTES: usize = 64;
+const ROUND_SIZE: usize = 10;
+const STATE_BYTES_1024: usize = 128;
+const ROUND_SIZE_1024: usize = 14;
+
+/// The `ShiftBytes` offsets of each row for the P permutation
+const SHIFT_P: [usize; 8] = [0, 1, 2, 3, 4, 5, 6, 7];
+/// The `ShiftBytes` offsets of each row for the Q permutation
+const SHIFT_Q: [usize; 8] = [1, 3, 5, 7, 0, 2, 4, 6];
+/// The `ShiftBytes` offsets of each row for the P permutation of the 1024-bit state
+const SHIFT_P_1024: [usize; 8] = [0, 1, 2, 3, 4, 5, 6, 11];
+/// The `ShiftBytes` offsets of each row for the Q permutation of the 1024-bit state
+const SHIFT_Q_1024: [usize; 8] = [1, 3, 5, 11, 0, 2, 4, 6];
+
+/// The transposed state of [`BITSLICED_LANES`] Grøstl256 permutation instances.
+pub type BitslicedState = [[u64; 8]; STATE_BYTES];
+
+/// A single 512-bit Grøstl256 state or message block, in the byte order of the specification.
+pub type StateBytes = [u8; STATE_BYTES];
+
+/// The intermediate states of a round of [`BITSLICED_LANES`] permutation instances, as needed to
+/// fill the witness of a permutation circuit.
+#[derive(Debug, Clone)]
+pub struct BitslicedRoundTrace {
+	/// The state after `AddRoundConstant`, which is the input of `SubBytes`
+	pub with_round_consts: BitslicedState,
+	/// The multiplicative inverses of the `SubBytes` inputs, with zero mapped to zero
+	pub sbox_inverse: BitslicedState,
+	/// The state after `SubBytes`
+	pub sub_bytes: BitslicedState,
+	/// The state after `ShiftBytes` and `MixBytes`, which is the output of the round
+	pub output: BitslicedState,
+}
+
+/// The transposed state of [`BITSLICED_LANES`] Grøstl512 permutation instances.
+pub type BitslicedState1024 = [[u64; 8]; STATE_BYTES_1024];
+
+/// A single 1024-bit Grøstl512 state or message block, in the byte order of the specification.
+pub type StateBytes1024 = [u8; STATE_BYTES_1024];
+
+/// Bitsliced version of the Grøstl256 hash function's P and Q permutations, operating on
+/// [`BITSLICED_LANES`] instances at once.
+#[derive(Debug, Clone, Default)]
+pub struct Groestl256BitslicedCore;
+
+impl Groestl256BitslicedCore {
+	/// Evaluates the P and Q permutations on every lane, as needed by the compression function
+	pub fn permutation_pq(&self, p: &mut BitslicedState, q: &mut BitslicedState) {
+		for r in 0..ROUND_SIZE {
+			round_p(p, r, &SHIFT_P);
+			round_q(q, r, &SHIFT_Q);
+		}
+	}
+
+	/// Evaluates the P permutation on every lane, as needed by the output transformation
+	pub fn permutation_p(&self, p: &mut BitslicedState) {
+		for r in 0..ROUND_SIZE {
+			round_p(p, r, &SHIFT_P);
+		}
+	}
+
+	/// Evaluates the Q permutation on every lane
+	pub fn permutation_q(&self, q: &mut BitslicedState) {
+		for r in 0..ROUND_SIZE {
+			round_q(q, r, &SHIFT_Q);
+		}
+	}
+
+	/// Evaluates the P permutation on every lane, returning the intermediate states of every
+	/// round.
+	pub fn permutation_p_trace(&self, p: &mut BitslicedState) -> Vec<BitslicedRoundTrace> {
+		(0..ROUND_SIZE)
+			.map(|r| {
+				add_round_constants_p(p, r);
+				round_trace(p, &SHIFT_P)
+			})
+			.collect()
+	}
+
+	/// Evaluates the Q permutation on every lane, returning the intermediate states of every
+	/// round.
+	pub fn permutation_q_trace(&self, q: &mut BitslicedState) -> Vec<BitslicedRoundTrace> {
+		(0..ROUND_SIZE)
+			.map(|r| {
+				add_round_constants_q(q, r);
+				round_trace(q, &SHIFT_Q)
+			})
+			.collect()
+	}
+}
+
+/// Bitsliced version of the Grøstl512 hash function's P and Q permutations, operating on
+/// [`BITSLICED_LANES`] instances at once.
+#[derive(Debug, Clone, Default)]
+pub struct Groestl512BitslicedCore;
+
+impl Groestl512BitslicedCore {
+	/// Evaluates the P and Q permutations on every lane, as needed by the compression function
+	pub fn permutation_pq(&self, p: &mut BitslicedState1024, q: &mut BitslicedState1024) {
+		for r in 0..ROUND_SIZE_1024 {
+			round_p(p, r, &SHIFT_P_1024);
+			round_q(q, r, &SHIFT_Q_1024);
+		}
+	}
+
+	/// Evaluates the P permutation on every lane, as needed by the output transformation
+	pub fn permutation_p(&self, p: &mut BitslicedState1024) {
+		for r in 0..ROUND_SIZE_1024 {
+			round_p(p, r, &SHIFT_P_1024);
+		}
+	}
+
+	/// Evaluates the Q permutation on every lane
+	pub fn permutation_q(&self, q: &mut BitslicedState1024) {
+		for r in 0..ROUND_SIZE_1024 {
+			round_q(q, r, &SHIFT_Q_1024);
+		}
+	}
+}
+
+/// A round of the P permutation on a state of `N` bytes
+#[inline(always)]
+fn round_p<const N: usize>(state: &mut [[u64; 8]; N], r: usize, shifts: &[usize; 8]) {
+	add_round_constants_p(state, r);
+	sub_bytes(state);
+	*state = shift_mix_bytes(state, shifts);
+}
+
+/// A round of the Q permutation on a state of `N` bytes
+#[inline(always)]
+fn round_q<const N: usize>(state: &mut [[u64; 8]; N], r: usize, shifts: &[usize; 8]) {
+	add_round_constants_q(state, r);
+	sub_bytes(state);
+	*state = shift_mix_bytes(state, shifts);
+}
+
+/// Finishes a round after `AddRoundConstant`, recording the intermediate states
+fn round_trace(state: &mut BitslicedState, shifts: &[usize; 8]) -> BitslicedRoundTrace {
+	let with_round_consts = *state;
+	sub_bytes(state);
+	let sub_bytes = *state;
+	*state = shift_mix_bytes(state, shifts);
+
+	// The S-box is the affine transformation of the inverse, so the inverse is recovered from the
+	// S-box output without another inversion circuit
+	let mut sbox_inverse = sub_bytes;
+	transform_bytes(&mut sbox_inverse, &SBOX_AFFINE_INV_COLUMNS);
+	for byte in sbox_inverse.iter_mut() {
+		xor_const(byte, SBOX_AFFINE_INV_CONST);
+	}
+
+	BitslicedRoundTrace {
+		with_round_consts,
+		sbox_inverse,
+		sub_bytes,
+		output: *state,
+	}
+}
+
+/// XORs `other` into `state` lane-wise
+#[inline]
+pub(crate) fn xor_state<const N: usize>(state: &mut [[u64; 8]; N], other: &[[u64; 8]; N]) {
+	for (byte, other_byte) in state.iter_mut().zip(other) {
+		for (plane, other_plane) in byte.iter_mut().zip(other_byte) {
+			*plane ^= other_plane;
+		}
+	}
+}
+
+/// Returns the state having `value` in every lane
+pub(crate) fn broadcast<const N: usize>(value: &[u8; N]) -> [[u64; 8]; N] {
+	array::from_fn(|i| array::from_fn(|b| 0u64.wrapping_sub(((value[i] >> b) & 1) as u64)))
+}
+
+/// Converts one state per lane into the transposed representation
+pub fn transpose_in<const N: usize>(lanes: &[[u8; N]; BITSLICED_LANES]) -> [[u64; 8]; N] {
+	let mut state = [[0u64; 8]; N];
+	for (i, byte) in state.iter_mut().enumerate() {
+		for group in 0..BITSLICED_LANES / 8 {
+			// Byte k of `x` is byte i of lane 8 * group + k, after the transposition byte b of `x`
+			// holds bit b of the same 8 lanes.
+			let x = transpose_8x8(u64::from_le_bytes(array::from_fn(|k| lanes[8 * group + k][i])));
+			for (b, plane) in byte.iter_mut().enumerate() {
+				*plane |= ((x >> (8 * b)) & 0xff) << (8 * group);
+			}
+		}
+	}
+	state
+}
+
+/// Converts the transposed representation back into one state per lane
+pub fn transpose_out<const N: usize>(state: &[[u64; 8]; N]) -> [[u8; N]; BITSLICED_LANES] {
+	let mut lanes = [[0u8; N]; BITSLICED_LANES];
+	for (i, byte) in state.iter().enumerate() {
+		for group in 0..BITSLICED_LANES / 8 {
+			let x = transpose_8x8(u64::from_le_bytes(array::from_fn(|b| {
+				(byte[b] >> (8 * group)) as u8
+			})));
+			for (k, lane_byte) in x.to_le_bytes().into_iter().enumerate() {
+				lanes[8 * group + k][i] = lane_byte;
+			}
+		}
+	}
+	lanes
+}
+
+/// Applies a GF(2)-linear map to every byte of every lane.
+///
+/// Bit `j` of an input byte contributes `columns[j]` to the output byte. In the transposed
+/// representation this only recombines the bit planes of each byte, so a change of basis of the
+/// state bytes, such as the isomorphism between the binary and AES towers, costs a few XORs.
+pub(crate) fn transform_bytes<const N: usize>(state: &mut [[u64; 8]; N], columns: &[u8; 8]) {
+	for byte in state.iter_mut() {
+		let input = *byte;
+		*byte = array::from_fn(|b| {
+			(0..8)
+				.filter(|&j| (columns[j] >> b) & 1 == 1)
+				.fold(0, |acc, j| acc ^ input[j])
+		});
+	}
+}
+
+/// Matrix columns of the inverse of the AES S-box affine transformation.
+const SBOX_AFFINE_INV_COLUMNS: [u8; 8] = [0x4a, 0x94, 0x29, 0x52, 0xa4, 0x49, 0x92, 0x25];
+/// Constant vector of the inverse of the AES S-box affine transformation.
+const SBOX_AFFINE_INV_CONST: u8 = 0x05;
+
+/// Transposes the 8x8 bit matrix whose rows are the bytes of `x`
+#[inline(always)]
+fn transpose_8x8(mut x: u64) -> u64 {
+	let t = (x ^ (x >> 7)) & 0x00aa00aa00aa00aa;
+	x ^= t ^ (t << 7);
+	let t = (x ^ (x >> 14)) & 0x0000cccc0000cccc;
+	x ^= t ^ (t << 14);
+	let t = (x ^ (x >> 28)) & 0x00000000f0f0f0f0;
+	x ^= t ^ (t << 28);
+	x
+}
+
+/// XORs the constant byte `c` into every lane of a bitsliced byte
+#[inline(always)]
+fn xor_const(byte: &mut [u64; 8], c: u8) {
+	for (b, plane) in byte.iter_mut().enumerate() {
+		*plane ^= 0u64.wrapping_sub(((c >> b) & 1) as u64);
+	}
+}
+
+#[inline(always)]
+fn add_round_constants_p<const N: usize>(state: &mut [[u64; 8]; N], r: usize) {
+	for col in 0..N / 8 {
+		xor_const(&mut state[col * 8], ((col << 4) ^ r) as u8);
+	}
+}
+
+#[inline(always)]
+fn add_round_constants_q<const N: usize>(state: &mut [[u64; 8]; N], r: usize) {
+	for col in 0..N / 8 {
+		for row in 0..7 {
+			xor_const(&mut state[col * 8 + row], 0xff);
+		}
+		xor_const(&mut state[col * 8 + 7], 0xff ^ ((col << 4) ^ r) as u8);
+	}
+}
+
+#[inline(always)]
+fn sub_bytes<const N: usize>(state: &mut [[u64; 8]; N]) {
+	for byte in state.iter_mut() {
+		sbox(byte);
+	}
+}
+
+/// The AES S-box on a bitsliced byte, using the 113 gate circuit from
+/// [Boyar-Peralta](https://eprint.iacr.org/2011/332.pdf)
+#[inline(always)]
+fn sbox(q: &mut [u64; 8]) {
+	let x0 = q[7];
+	let x1 = q[6];
+	let x2 = q[5];
+	let x3 = q[4];
+	let x4 = q[3];
+	let x5 = q[2];
+	let x6 = q[1];
+	let x7 = q[0];
+
+	// Top linear transformation
+	let y14 = x3 ^ x5;
+	let y13 = x0 ^ x6;
+	let y9 = x0 ^ x3;
+	let y8 = x0 ^ x5;
+	let t0 = x1 ^ x2;
+	let y1 = t0 ^ x7;
+	let y4 = y1 ^ x3;
+	let y12 = y13 ^ y14;
+	let y2 = y1 ^ x0;
+	let y5 = y1 ^ x6;
+	let y3 = y5 ^ y8;
+	let t1 = x4 ^ y12;
+	let y15 = t1 ^ x5;
+	let y20 = t1 ^ x1;
+	let y6 = y15 ^ x7;
+	let y10 = y15 ^ t0;
+	let y11 = y20 ^ y9;
+	let y7 = x7 ^ y11;
+	let y17 = y10 ^ y11;
+	let y19 = y10 ^ y8;
+	let y16 = t0 ^ y11;
+	let y21 = y13 ^ y16;
+	let y18 = x0 ^ y16;
+
+	// Non-linear section
+	let t2 = y12 & y15;
+	let t3 = y3 & y6;
+	let t4 = t3 ^ t2;
+	let t5 = y4 & x7;
+	let t6 = t5 ^ t2;
+	let t7 = y13 & y16;
+	let t8 = y5 & y1;
+	let t9 = t8 ^ t7;
+	let t10 = y2 & y7;
+	let t11 = t10 ^ t7;
+	let t12 = y9 & y11;
+	let t13 = y14 & y17;
+	let t14 = t13 ^ t12;
+	let t15 = y8 & y10;
+	let t16 = t15 ^ t12;
+	let t17 = t4 ^ t14;
+	let t18 = t6 ^ t16;
+	let t19 = t9 ^ t14;
+	let t20 = t11 ^ t16;
+	let t21 = t17 ^ y20;
+	let t22 = t18 ^ y19;
+	let t23 = t19 ^ y21;
+	let t24 = t20 ^ y18;
+
+	let t25 = t21 ^ t22;
+	let t26 = t21 & t23;
+	let t27 = t24 ^ t26;
+	let t28 = t25 & t27;
+	let t29 = t28 ^ t22;
+	let t30 = t23 ^ t24;
+	let t31 = t22 ^ t26;
+	let t32 = t31 & t30;
+	let t33 = t32 ^ t24;
+	let t34 = t23 ^ t33;
+	let t35 = t27 ^ t33;
+	let t36 = t24 & t35;
+	let t37 = t36 ^ t34;
+	let t38 = t27 ^ t36;
+	let t39 = t29 & t38;
+	let t40 = t25 ^ t39;
+
+	let t41 = t40 ^ t37;
+	let t42 = t29 ^ t33;
+	let t43 = t29 ^ t40;
+	let t44 = t33 ^ t37;
+	let t45 = t42 ^ t41;
+	let z0 = t44 & y15;
+	let z1 = t37 & y6;
+	let z2 = t33 & x7;
+	let z3 = t43 & y16;
+	let z4 = t40 & y1;
+	let z5 = t29 & y7;
+	let z6 = t42 & y11;
+	let z7 = t45 & y17;
+	let z8 = t41 & y10;
+	let z9 = t44 & y12;
+	let z10 = t37 & y3;
+	let z11 = t33 & y4;
+	let z12 = t43 & y13;
+	let z13 = t40 & y5;
+	let z14 = t29 & y2;
+	let z15 = t42 & y9;
+	let z16 = t45 & y14;
+	let z17 = t41 & y8;
+
+	// Bottom linear transformation
+	let t46 = z15 ^ z16;
+	let t47 = z10 ^ z11;
+	let t48 = z5 ^ z13;
+	let t49 = z9 ^ z10;
+	let t50 = z2 ^ z12;
+	let t51 = z2 ^ z5;
+	let t52 = z7 ^ z8;
+	let t53 = z0 ^ z3;
+	let t54 = z6 ^ z7;
+	let t55 = z16 ^ z17;
+	let t56 = z12 ^ t48;
+	let t57 = t50 ^ t53;
+	let t58 = z4 ^ t46;
+	let t59 = z3 ^ t54;
+	let t60 = t46 ^ t57;
+	let t61 = z14 ^ t57;
+	let t62 = t52 ^ t58;
+	let t63 = t49 ^ t58;
+	let t64 = z4 ^ t59;
+	let t65 = t61 ^ t62;
+	let t66 = z1 ^ t63;
+	let s0 = t59 ^ t63;
+	let s6 = t56 ^ !t62;
+	let s7 = t48 ^ !t60;
+	let t67 = t64 ^ t65;
+	let s3 = t53 ^ t66;
+	let s4 = t51 ^ t66;
+	let s5 = t47 ^ t65;
+	let s1 = t64 ^ !s3;
+	let s2 = t55 ^ !t67;
+
+	q[7] = s0;
+	q[6] = s1;
+	q[5] = s2;
+	q[4] = s3;
+	q[3] = s4;
+	q[2] = s5;
+	q[1] = s6;
+	q[0] = s7;
+}
+
+/// Multiplication by `x` modulo the AES polynomial on a bitsliced byte
+#[inline(always)]
+fn mul2(x: [u64; 8]) -> [u64; 8] {
+	[
+		x[7],
+		x[0] ^ x[7],
+		x[1],
+		x[2] ^ x[7],
+		x[3] ^ x[7],
+		x[4],
+		x[5],
+		x[6],
+	]
+}
+
+#[inline(always)]
+fn xor_bytes(a: [u64; 8], b: [u64; 8]) -> [u64; 8] {
+	array::from_fn(|i| a[i] ^ b[i])
+}
+
+/// The `ShiftBytes` step followed by the `MixBytes` step on a state of `N / 8` columns.
+///
+/// `MixBytes` multiplies every column by the circulant matrix `circ(2, 2, 3, 4, 5, 3, 5, 7)`. The
+/// coefficient of the byte `k` rows below the output row is split into its bits, so every output
+/// byte is `s0 + 2 * (s1 + 2 * s2)` with `s0`, `s1`, `s2` sums of input bytes.
+#[inline(always)]
+fn shift_mix_bytes<const N: usize>(state: &[[u64; 8]; N], shifts: &[usize; 8]) -> [[u64; 8]; N] {
+	let cols = N / 8;
+	let mut out = [[0u64; 8]; N];
+	for col in 0..cols {
+		let a: [[u64; 8]; 8] = array::from_fn(|row| state[((col + shifts[row]) % cols) * 8 + row]);
+		for row in 0..8 {
+			let a_k = |k: usize| a[(row + k) % 8];
+			let s0 =
+				xor_bytes(xor_bytes(xor_bytes(a_k(2), a_k(4)), xor_bytes(a_k(5), a_k(6))), a_k(7));
+			let s1 =
+				xor_bytes(xor_bytes(xor_bytes(a_k(0), a_k(1)), xor_bytes(a_k(2), a_k(5))), a_k(7));
+			let s2 = xor_bytes(xor_bytes(a_k(3), a_k(4)), xor_bytes(a_k(6), a_k(7)));
+			out[col * 8 + row] = xor_bytes(s0, mul2(xor_bytes(s1, mul2(s2))));
+		}
+	}
+	out
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{
+		super::{Groestl256Core, Groestl512TableCore},
+		*,
+	};
+	use binius_field::{AESTowerField8b, Field, PackedAESBinaryField64x8b, PackedField};
+	use rand::{thread_rng, RngCore};
+
+	fn to_packed(bytes: &StateBytes) -> PackedAESBinaryField64x8b {
+		PackedAESBinaryField64x8b::from_fn(|i| AESTowerField8b::new(bytes[i]))
+	}
+
+	fn random_lanes() -> [StateBytes; BITSLICED_LANES] {
+		let mut rng = thread_rng();
+		array::from_fn(|_| {
+			let mut lane = [0u8; STATE_BYTES];
+			rng.fill_bytes(&mut lane);
+			lane
+		})
+	}
+
+	#[test]
+	fn test_transpose_roundtrip() {
+		let lanes = random_lanes();
+		let state = transpose_in(&lanes);
+		for (lane, bytes) in lanes.iter().enumerate() {
+			for (i, &byte) in bytes.iter().enumerate() {
+				for b in 0..8 {
+					assert_eq!((state[i][b] >> lane) & 1, ((byte >> b) & 1) as u64);
+				}
+			}
+		}
+		assert_eq!(transpose_out(&state), lanes);
+	}
+
+	#[test]
+	fn test_transform_bytes() {
+		let lanes = random_lanes();
+		let columns = [0x01, 0xbc, 0xb0, 0xec, 0xd3, 0x8d, 0x2e, 0x58];
+		let mut state = transpose_in(&lanes);
+		transform_bytes(&mut state, &columns);
+
+		let expected = lanes.map(|lane| {
+			lane.map(|byte| {
+				(0..8)
+					.filter(|&j| (byte >> j) & 1 == 1)
+					.fold(0, |acc, j| acc ^ columns[j])
+			})
+		});
+		assert_eq!(transpose_out(&state), expected);
+	}
+
+	#[test]
+	fn test_permutations_match_portable() {
+		let p_lanes = random_lanes();
+		let q_lanes = random_lanes();
+
+		let mut p = transpose_in(&p_lanes);
+		let mut q = transpose_in(&q_lanes);
+		Groestl256BitslicedCore.permutation_pq(&mut p, &mut q);
+		let p_out = transpose_out(&p);
+		let q_out = transpose_out(&q);
+
+		let mut p_only = transpose_in(&p_lanes);
+		Groestl256BitslicedCore.permutation_p(&mut p_only);
+		assert_eq!(p_only, p);
+
+		let mut q_only = transpose_in(&q_lanes);
+		Groestl256BitslicedCore.permutation_q(&mut q_only);
+		assert_eq!(q_only, q);
+
+		for lane in 0..BITSLICED_LANES {
+			let (expected_p, expected_q) =
+				Groestl256Core.permutation_pq(to_packed(&p_lanes[lane]), to_packed(&q_lanes[lane]));
+			assert_eq!(to_packed(&p_out[lane]), expected_p);
+			assert_eq!(to_packed(&q_out[lane]), expected_q);
+		}
+	}
+
+	#[test]
+	fn test_permutation_p_trace() {
+		check_permutation_trace(
+			Groestl256BitslicedCore::permutation_p_trace,
+			Groestl256BitslicedCore::permutation_p,
+		);
+	}
+
+	#[test]
+	fn test_permutation_q_trace() {
+		check_permutation_trace(
+			Groestl256BitslicedCore::permutation_q_trace,
+			Groestl256BitslicedCore::permutation_q,
+		);
+	}
+
+	fn check_permutation_trace(
+		trace_fn: fn(&Groestl256BitslicedCore, &mut BitslicedState) -> Vec<BitslicedRoundTrace>,
+		permutation_fn: fn(&Groestl256BitslicedCore, &mut BitslicedState),
+	) {
+		let lanes = random_lanes();
+		let mut state = transpose_in(&lanes);
+		let trace = trace_fn(&Groestl256BitslicedCore, &mut state);
+		assert_eq!(trace.len(), ROUND_SIZE);
+
+		let mut expected = transpose_in(&lanes);
+		permutation_fn(&Groestl256BitslicedCore, &mut expected);
+		assert_eq!(state, expected);
+		assert_eq!(trace[ROUND_SIZE - 1].output, state);
+
+		for round in &trace {
+			let inputs = transpose_out(&round.with_round_consts);
+			let inverses = transpose_out(&round.sbox_inverse);
+			for (input, inverse) in inputs.iter().flatten().zip(inverses.iter().flatten()) {
+				assert_eq!(
+					AESTowerField8b::new(*inverse),
+					AESTowerField8b::new(*input).invert_or_zero()
+				);
+			}
+		}
+	}
+
+	#[test]
+	fn test_permutations_1024_match_portable() {
+		let mut rng = thread_rng();
+		let mut random_lanes_1024 = || -> [StateBytes1024; BITSLICED_LANES] {
+			array::from_fn(|_| {
+				let mut lane = [0u8; STATE_BYTES_1024];
+				rng.fill_bytes(&mut lane);
+				lane
+			})
+		};
+		let p_lanes = random_lanes_1024();
+		let q_lanes = random_lanes_1024();
+		let to_halves = |bytes: &StateBytes1024| -> [PackedAESBinaryField64x8b; 2] {
+			array::from_fn(|half| {
+				PackedAESBinaryField64x8b::from_fn(|i| AESTowerField8b::new(bytes[64 * half + i]))
+			})
+		};
+
+		let mut p = transpose_in(&p_lanes);
+		let mut q = transpose_in(&q_lanes);
+		Groestl512BitslicedCore.permutation_pq(&mut p, &mut q);
+		let p_out = transpose_out(&p);
+		let q_out = transpose_out(&q);
+
+		let mut p_only = transpose_in(&p_lanes);
+		Groestl512BitslicedCore.permutation_p(&mut p_only);
+		assert_eq!(p_only, p);
+
+		let mut q_only = transpose_in(&q_lanes);
+		Groestl512BitslicedCore.permutation_q(&mut q_only);
+		assert_eq!(q_only, q);
+
+		for lane in 0..BITSLICED_LANES {
+			let (expected_p, expected_q) = Groestl512TableCore
+				.permutation_pq(to_halves(&p_lanes[lane]), to_halves(&q_lanes[lane]));
+			assert_eq!(to_halves(&p_out[lane]), expected_p);
+			assert_eq!(to_halves(&q_out[lane]), expected_q);
+			assert_eq!(Groestl512TableCore.permutation_p(to_halves(&p_lanes[lane])), expected_p);
+		}
+	}
+}
@@ -0,0 +1,271 @@
+// Copyright 2024 Irreducible Inc.
+
+//! Runtime selection of the Grøstl256 implementation.
+//!
+//! The CPU features are detected once per process. [`Groestl256Core`] then evaluates single
+//! permutations with the fastest available backend, and [`groestl_dispatch`] tells the batched
+//! hashers whether to use the bitsliced backend or to hash messages one by one.
+
+use super::portable::Groestl256TableCore;
+use binius_field::PackedAESBinaryField64x8b;
+use lazy_static::lazy_static;
+
+/// An implementation of the Grøstl256 permutations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GroestlBackend {
+	/// The portable implementation based on lookup tables.
+	Table,
+	/// The implementation using the AVX-512 and GFNI extensions.
+	Avx512,
+	/// The bitsliced implementation, evaluating 64 instances at once.
+	Bitsliced,
+}
+
+/// The CPU features relevant to the choice of a [`GroestlBackend`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CpuFeatures {
+	/// Whether AVX512F, AVX512BW and AVX512VBMI are all available
+	pub avx512: bool,
+	pub gfni: bool,
+	pub avx2: bool,
+}
+
+impl CpuFeatures {
+	/// Detects the features of the CPU the process is running on.
+	pub fn detect() -> Self {
+		#[cfg(target_arch = "x86_64")]
+		{
+			Self {
+				avx512: is_x86_feature_detected!("avx512f")
+					&& is_x86_feature_detected!("avx512bw")
+					&& is_x86_feature_detected!("avx512vbmi"),
+				gfni: is_x86_feature_detected!("gfni"),
+				avx2: is_x86_feature_detected!("avx2"),
+			}
+		}
+		#[cfg(not(target_arch = "x86_64"))]
+		{
+			Self::default()
+		}
+	}
+}
+
+/// The backends chosen for hashing single messages and batches of messages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GroestlDispatch {
+	/// The backend of [`Groestl256Core`], used to hash one message at a time
+	pub single: GroestlBackend,
+	/// The backend used by the batched hashers and compression functions
+	pub batch: GroestlBackend,
+}
+
+impl GroestlDispatch {
+	/// Chooses the backends for a CPU with the given features.
+	///
+	/// Batches always use the bitsliced backend. Its pure Rust kernel only needs 64-bit integer
+	/// operations, and hashing a batch message by message pays for the padding and the output
+	/// transformation of every message separately, even with AVX-512. A platform should only be
+	/// downgraded here once the `groestl` benchmarks show the bitsliced backend losing on it.
+	pub fn for_features(features: CpuFeatures) -> Self {
+		let single = if features.avx512 && features.gfni {
+			GroestlBackend::Avx512
+		} else {
+			GroestlBackend::Table
+		};
+		Self {
+			single,
+			batch: GroestlBackend::Bitsliced,
+		}
+	}
+}
+
+lazy_static! {
+	static ref DISPATCH: GroestlDispatch = GroestlDispatch::for_features(CpuFeatures::detect());
+	static ref PERMUTATIONS: Groestl256Permutations =
+		Groestl256Permutations::for_backend(DISPATCH.single);
+}
+
+/// Returns the backends selected for the current CPU.
+pub fn groestl_dispatch() -> GroestlDispatch {
+	*DISPATCH
+}
+
+/// The Grøstl256 P and Q permutations, evaluated with the backend selected at runtime.
+///
+/// This is [`GroestlDispatch::single`] of [`groestl_dispatch`], either the AVX-512
+/// implementation or [`Groestl256TableCore`]. Every call looks the selection up, code permuting
+/// many blocks should [`resolve`](Self::resolve) it once instead.
+#[derive(Debug, Clone, Default)]
+pub struct Groestl256Core;
+
+impl Groestl256Core {
+	/// Returns the permutations of the backend selected at runtime
+	#[inline]
+	pub fn resolve(&self) -> Groestl256Permutations {
+		*PERMUTATIONS
+	}
+
+	/// This function can be used to create the compression function of Grøstl256 hash efficiently
+	/// from the P and Q permutations
+	#[inline]
+	pub fn permutation_pq(
+		&self,
+		p: PackedAESBinaryField64x8b,
+		q: PackedAESBinaryField64x8b,
+	) -> (PackedAESBinaryField64x8b, PackedAESBinaryField64x8b) {
+		self.resolve().permutation_pq(p, q)
+	}
+
+	/// This function is simply the P permutation from Grøstl256 that is intended to be used in the
+	/// output transformation stage of hash function at finalization
+	#[inline]
+	pub fn permutation_p(&self, p: PackedAESBinaryField64x8b) -> PackedAESBinaryField64x8b {
+		self.resolve().permutation_p(p)
+	}
+
+	/// The Q permutation from Grøstl256
+	#[inline]
+	pub fn permutation_q(&self, q: PackedAESBinaryField64x8b) -> PackedAESBinaryField64x8b {
+		self.resolve().permutation_q(q)
+	}
+}
+
+/// The Grøstl256 permutations of one backend, as function pointers.
+///
+/// Obtained from [`Groestl256Core::resolve`], it evaluates the permutations without checking the
+/// runtime selection again, and is cheap to copy into a hasher.
+#[derive(Debug, Clone, Copy)]
+pub struct Groestl256Permutations {
+	pq: fn(
+		PackedAESBinaryField64x8b,
+		PackedAESBinaryField64x8b,
+	) -> (PackedAESBinaryField64x8b, PackedAESBinaryField64x8b),
+	p: fn(PackedAESBinaryField64x8b) -> PackedAESBinaryField64x8b,
+	q: fn(PackedAESBinaryField64x8b) -> PackedAESBinaryField64x8b,
+}
+
+impl Groestl256Permutations {
+	/// The permutations of `backend`, which must be supported by the CPU
+	fn for_backend(backend: GroestlBackend) -> Self {
+		match backend {
+			// SAFETY: the AVX-512 backend is only selected if the CPU supports it
+			#[cfg(target_arch = "x86_64")]
+			GroestlBackend::Avx512 => Self {
+				pq: |p, q| unsafe { avx512::permutation_pq(p, q) },
+				p: |p| unsafe { avx512::permutation_p(p) },
+				q: |q| unsafe { avx512::permutation_q(q) },
+			},
+			_ => Self {
+				pq: |p, q| Groestl256TableCore.permutation_pq(p, q),
+				p: |p| Groestl256TableCore.permutation_p(p),
+				q: |q| Groestl256TableCore.permutation_q(q),
+			},
+		}
+	}
+
+	#[inline]
+	pub fn permutation_pq(
+		&self,
+		p: PackedAESBinaryField64x8b,
+		q: PackedAESBinaryField64x8b,
+	) -> (PackedAESBinaryField64x8b, PackedAESBinaryField64x8b) {
+		(self.pq)(p, q)
+	}
+
+	#[inline]
+	pub fn permutation_p(&self, p: PackedAESBinaryField64x8b) -> PackedAESBinaryField64x8b {
+		(self.p)(p)
+	}
+
+	#[inline]
+	pub fn permutation_q(&self, q: PackedAESBinaryField64x8b) -> PackedAESBinaryField64x8b {
+		(self.q)(q)
+	}
+}
+
+#[cfg(target_arch = "x86_64")]
+mod avx512 {
+	use super::super::groestl_avx512::Groestl256Avx512Core;
+	use binius_field::PackedAESBinaryField64x8b;
+
+	// The permutations are compiled with the extensions enabled here, so that the intrinsics are
+	// inlined even when the crate is built for a baseline CPU.
+	#[target_feature(enable = "avx512f,avx512bw,avx512vbmi,gfni")]
+	pub(super) unsafe fn permutation_pq(
+		p: PackedAESBinaryField64x8b,
+		q: PackedAESBinaryField64x8b,
+	) -> (PackedAESBinaryField64x8b, PackedAESBinaryField64x8b) {
+		Groestl256Avx512Core.permutation_pq(p, q)
+	}
+
+	#[target_feature(enable = "avx512f,avx512bw,avx512vbmi,gfni")]
+	pub(super) unsafe fn permutation_p(p: PackedAESBinaryField64x8b) -> PackedAESBinaryField64x8b {
+		Groestl256Avx512Core.permutation_p(p)
+	}
+
+	#[target_feature(enable = "avx512f,avx512bw,avx512vbmi,gfni")]
+	pub(super) unsafe fn permutation_q(q: PackedAESBinaryField64x8b) -> PackedAESBinaryField64x8b {
+		Groestl256Avx512Core.permutation_q(q)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use binius_field::{AESTowerField8b, PackedField};
+	use rand::thread_rng;
+
+	#[test]
+	fn test_backend_selection() {
+		let avx512 = CpuFeatures {
+			avx512: true,
+			gfni: true,
+			avx2: true,
+		};
+		assert_eq!(
+			GroestlDispatch::for_features(avx512),
+			GroestlDispatch {
+				single: GroestlBackend::Avx512,
+				batch: GroestlBackend::Bitsliced,
+			}
+		);
+
+		let avx2 = CpuFeatures {
+			avx512: true,
+			gfni: false,
+			avx2: true,
+		};
+		assert_eq!(
+			GroestlDispatch::for_features(avx2),
+			GroestlDispatch {
+				single: GroestlBackend::Table,
+				batch: GroestlBackend::Bitsliced,
+			}
+		);
+
+		// As on non-x86 targets, which detect no features
+		assert_eq!(
+			GroestlDispatch::for_features(CpuFeatures::default()),
+			GroestlDispatch {
+				single: GroestlBackend::Table,
+				batch: GroestlBackend::Bitsliced,
+			}
+		);
+	}
+
+	#[test]
+	fn test_selected_backend_matches_table() {
+		let mut rng = thread_rng();
+		let p = PackedAESBinaryField64x8b::random(&mut rng);
+		let q = PackedAESBinaryField64x8b::from_fn(|i| AESTowerField8b::new(i as u8));
+
+		assert_eq!(Groestl256Core.permutation_pq(p, q), Groestl256TableCore.permutation_pq(p, q));
+		assert_eq!(Groestl256Core.permutation_p(p), Groestl256TableCore.permutation_p(p));
+		assert_eq!(Groestl256Core.permutation_q(q), Groestl256TableCore.permutation_q(q));
+
+		let resolved = Groestl256Core.resolve();
+		assert_eq!(resolved.permutation_pq(p, q), Groestl256TableCore.permutation_pq(p, q));
+		assert_eq!(resolved.permutation_p(p), Groestl256TableCore.permutation_p(p));
+		assert_eq!(resolved.permutation_q(q), Groestl256TableCore.permutation_q(q));
+	}
+}
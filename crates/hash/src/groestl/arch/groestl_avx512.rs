@@ -88,10 +88,13 @@ const INDEX: AlignedArray = AlignedArray([
 /// An implementation of Grøstl256 that uses AVX512 vector extensions to perform P and Q
 /// permutation functions. Some of the steps in a round of the permutation gets simplified to a
 /// single instruction.
+///
+/// The required extensions are detected at runtime, so this is only reachable through the
+/// dispatching [`Groestl256Core`](super::Groestl256Core).
 #[derive(Clone, Default)]
-pub struct Groestl256Core;
+pub(super) struct Groestl256Avx512Core;
 
-impl Groestl256Core {
+impl Groestl256Avx512Core {
 	#[inline]
 	fn mix_bytes(&self, block: __m512i) -> __m512i {
 		let b_adj_1: __m512i = unsafe { _mm512_ror_epi64(block, 8) };
@@ -174,6 +177,17 @@ impl Groestl256Core {
 		block
 	}
 
+	fn perm_q_m512i(&self, block: __m512i) -> __m512i {
+		let mut block = block;
+		for r in 0..ROUND_SIZE {
+			block = self.add_round_constants_q(block, r as u8);
+			block = self.sub_bytes(block);
+			block = self.shift_bytes(block, &SHIFT_ARRAY_Q);
+			block = self.mix_bytes(block);
+		}
+		block
+	}
+
 	fn combined_perm_m512i(&self, p_block: __m512i, q_block: __m512i) -> (__m512i, __m512i) {
 		let mut p_block = p_block;
 		let mut q_block = q_block;
@@ -188,7 +202,7 @@ impl Groestl256Core {
 			q_block = self.mix_bytes(q_block);
 		}
 
-		(q_block, p_block)
+		(p_block, q_block)
 	}
 
 	/// This function is simply the P permutation from Grøstl256 that is intended to be used in the
@@ -203,6 +217,17 @@ impl Groestl256Core {
 		from_u8_slice(&out.0)
 	}
 
+	/// The Q permutation from Grøstl256
+	#[inline]
+	pub fn permutation_q(&self, q: PackedAESBinaryField64x8b) -> PackedAESBinaryField64x8b {
+		let q = [q];
+		let q_slice = to_u8_slice(&q);
+		let input = AlignedArray(q_slice.try_into().unwrap());
+		let out: AlignedArray = self.perm_q_m512i(input.into()).into();
+
+		from_u8_slice(&out.0)
+	}
+
 	/// This function can be used to create the compression function of Grøstl256 hash efficiently
 	/// from the P and Q permutations.
 	#[inline]
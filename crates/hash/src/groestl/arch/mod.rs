@@ -1,17 +1,19 @@
 // Copyright 2024 Irreducible Inc.
 
-use cfg_if::cfg_if;
+pub(crate) mod bitsliced;
+mod dispatch;
+mod groestl_table;
+mod portable;
 
-// We will choose the AVX512 Implementation of Grøstl if our machine supports the various AVX512
-// extensions, otherwise defaults to the portable implementation which was found to be fast in most
-// machines
-cfg_if! {
-	if #[cfg(all(target_arch = "x86_64",target_feature = "avx512bw",target_feature = "avx512vbmi",target_feature = "avx512f",target_feature = "gfni",))] {
-		mod groestl_avx512;
-		pub use groestl_avx512::Groestl256Core;
-	} else {
-		mod groestl_table;
-		mod portable;
-		pub use portable::Groestl256Core;
-	}
-}
+// The AVX512 implementation of Grøstl is compiled on every x86_64 target and only used if the
+// machine supports the various AVX512 extensions, which is checked at runtime. Otherwise we
+// default to the portable implementation which was found to be fast in most machines.
+#[cfg(target_arch = "x86_64")]
+mod groestl_avx512;
+
+pub use bitsliced::{
+	transpose_in, transpose_out, BitslicedRoundTrace, BitslicedState, BitslicedState1024,
+	Groestl256BitslicedCore, Groestl512BitslicedCore, StateBytes, StateBytes1024, BITSLICED_LANES,
+};
+pub use dispatch::*;
+pub use portable::{Groestl256TableCore, Groestl512TableCore};
@@ -11,6 +11,7 @@ use lazy_static::lazy_static;
 use std::array;
 
 const ROUND_SIZE: usize = 10;
+const ROUND_SIZE_1024: usize = 14;
 
 /// The shift of a given index of the state of P permutation as per the `ShiftBytes` step
 #[inline(always)]
@@ -28,8 +29,24 @@ fn shift_q_func(row: usize, col: usize) -> usize {
 	new_col * 8 + new_row
 }
 
+/// The shift of a given index of the 1024-bit state of P permutation as per the `ShiftBytes` step
+#[inline(always)]
+fn shift_p_1024_func(row: usize, col: usize) -> usize {
+	const SHIFTS: [usize; 8] = [0, 1, 2, 3, 4, 5, 6, 11];
+	let new_col = (col + SHIFTS[row]) % 16;
+	new_col * 8 + row
+}
+
+/// The shift of a given index of the 1024-bit state of Q permutation as per the `ShiftBytes` step
+#[inline(always)]
+fn shift_q_1024_func(row: usize, col: usize) -> usize {
+	const SHIFTS: [usize; 8] = [1, 3, 5, 11, 0, 2, 4, 6];
+	let new_col = (col + SHIFTS[row]) % 16;
+	new_col * 8 + row
+}
+
 lazy_static! {
-	static ref ROW_0_SELECT: [PackedAESBinaryField64x8b; 10] = array::from_fn(|r| {
+	static ref ROW_0_SELECT: [PackedAESBinaryField64x8b; ROUND_SIZE_1024] = array::from_fn(|r| {
 		PackedAESBinaryField64x8b::from_fn(|i| {
 			let selector = i % 8;
 			if selector == 0 {
@@ -39,7 +56,7 @@ lazy_static! {
 			}
 		})
 	});
-	static ref ROW_7_SELECT: [PackedAESBinaryField64x8b; 10] = array::from_fn(|r| {
+	static ref ROW_7_SELECT: [PackedAESBinaryField64x8b; ROUND_SIZE_1024] = array::from_fn(|r| {
 		PackedAESBinaryField64x8b::from_fn(|i| {
 			let selector = i % 8;
 			if selector == 7 {
@@ -67,14 +84,35 @@ lazy_static! {
 				AESTowerField8b::new(0xff)
 			}
 		});
+	// The column constants of the 1024-bit state, split in two halves of 8 columns each
+	static ref ROUND_CONSTANT_P_1024: [PackedAESBinaryField64x8b; 2] = array::from_fn(|half| {
+		PackedAESBinaryField64x8b::from_fn(|i| {
+			let selector = i % 8;
+			if selector == 0 {
+				AESTowerField8b::new(0x10 * (8 * half + i / 8) as u8)
+			} else {
+				AESTowerField8b::ZERO
+			}
+		})
+	});
+	static ref ROUND_CONSTANT_Q_1024: [PackedAESBinaryField64x8b; 2] = array::from_fn(|half| {
+		PackedAESBinaryField64x8b::from_fn(|i| {
+			let selector = i % 8;
+			if selector == 7 {
+				AESTowerField8b::new(0xff ^ (0x10 * (8 * half + i / 8) as u8))
+			} else {
+				AESTowerField8b::new(0xff)
+			}
+		})
+	});
 }
 
 /// Portable version of the Grøstl256 hash function's P and Q permutations that uses the
 /// implementation of section `8.1.2` from [Grøstl](https://www.groestl.info/Groestl.pdf)
 #[derive(Debug, Clone, Default)]
-pub struct Groestl256Core;
+pub struct Groestl256TableCore;
 
-impl Groestl256Core {
+impl Groestl256TableCore {
 	#[inline(always)]
 	fn add_round_constants_q(
 		&self,
@@ -100,24 +138,12 @@ impl Groestl256Core {
 		shift_func: fn(usize, usize) -> usize,
 	) -> PackedAESBinaryField64x8b {
 		let x = [x];
-		let input: &[AESTowerField8b] = PackedAESBinaryField64x8b::unpack_base_scalars(&x);
 		let mut state_arr = [PackedAESBinaryField64x8b::zero()];
-		let state: &mut [AESTowerField8b] =
-			PackedAESBinaryField64x8b::unpack_base_scalars_mut(&mut state_arr);
-
-		for col in 0..8 {
-			let mut final_col: PackedAESBinaryField8x8b = PackedAESBinaryField8x8b::zero();
-			for row in 0..8 {
-				let shifted = shift_func(row, col);
-				final_col += PackedAESBinaryField8x8b::from_underlier(
-					TABLE[row][input[shifted].val() as usize],
-				);
-			}
-			let final_col = [final_col];
-			state[col * 8..col * 8 + 8]
-				.copy_from_slice(PackedAESBinaryField8x8b::unpack_base_scalars(&final_col));
-		}
-
+		sub_mix_shift_columns(
+			PackedAESBinaryField64x8b::unpack_base_scalars(&x),
+			PackedAESBinaryField64x8b::unpack_base_scalars_mut(&mut state_arr),
+			shift_func,
+		);
 		state_arr[0]
 	}
 
@@ -150,6 +176,111 @@ impl Groestl256Core {
 		}
 		p
 	}
+
+	/// The Q permutation from Grøstl256
+	pub fn permutation_q(&self, q: PackedAESBinaryField64x8b) -> PackedAESBinaryField64x8b {
+		let mut q = q;
+		for r in 0..ROUND_SIZE {
+			q = self.add_round_constants_q(q, r);
+			q = self.sub_mix_shift(q, shift_q_func);
+		}
+		q
+	}
+}
+
+/// The `SubBytes`, `ShiftBytes` and `MixBytes` steps on a state of `input.len() / 8` columns,
+/// using the combined table lookups
+#[inline(always)]
+fn sub_mix_shift_columns(
+	input: &[AESTowerField8b],
+	state: &mut [AESTowerField8b],
+	shift_func: fn(usize, usize) -> usize,
+) {
+	for col in 0..input.len() / 8 {
+		let mut final_col: PackedAESBinaryField8x8b = PackedAESBinaryField8x8b::zero();
+		for row in 0..8 {
+			let shifted = shift_func(row, col);
+			final_col +=
+				PackedAESBinaryField8x8b::from_underlier(TABLE[row][input[shifted].val() as usize]);
+		}
+		let final_col = [final_col];
+		state[col * 8..col * 8 + 8]
+			.copy_from_slice(PackedAESBinaryField8x8b::unpack_base_scalars(&final_col));
+	}
+}
+
+/// Portable version of the Grøstl512 hash function's P and Q permutations on the 1024-bit state,
+/// using the same tables as [`Groestl256TableCore`].
+///
+/// The state is split in two halves holding the columns `0..8` and `8..16`.
+#[derive(Debug, Clone, Default)]
+pub struct Groestl512TableCore;
+
+impl Groestl512TableCore {
+	#[inline(always)]
+	fn add_round_constants_q(
+		&self,
+		x: [PackedAESBinaryField64x8b; 2],
+		r: usize,
+	) -> [PackedAESBinaryField64x8b; 2] {
+		array::from_fn(|half| x[half] + ROW_7_SELECT[r] + ROUND_CONSTANT_Q_1024[half])
+	}
+
+	#[inline(always)]
+	fn add_round_constants_p(
+		&self,
+		x: [PackedAESBinaryField64x8b; 2],
+		r: usize,
+	) -> [PackedAESBinaryField64x8b; 2] {
+		array::from_fn(|half| x[half] + ROW_0_SELECT[r] + ROUND_CONSTANT_P_1024[half])
+	}
+
+	#[inline(always)]
+	fn sub_mix_shift(
+		&self,
+		x: [PackedAESBinaryField64x8b; 2],
+		shift_func: fn(usize, usize) -> usize,
+	) -> [PackedAESBinaryField64x8b; 2] {
+		let mut state_arr = [PackedAESBinaryField64x8b::zero(); 2];
+		sub_mix_shift_columns(
+			PackedAESBinaryField64x8b::unpack_base_scalars(&x),
+			PackedAESBinaryField64x8b::unpack_base_scalars_mut(&mut state_arr),
+			shift_func,
+		);
+		state_arr
+	}
+
+	/// This function can be used to create the compression function of Grøstl512 hash efficiently
+	/// from the P and Q permutations
+	pub fn permutation_pq(
+		&self,
+		p: [PackedAESBinaryField64x8b; 2],
+		q: [PackedAESBinaryField64x8b; 2],
+	) -> ([PackedAESBinaryField64x8b; 2], [PackedAESBinaryField64x8b; 2]) {
+		let mut p = p;
+		let mut q = q;
+		for r in 0..ROUND_SIZE_1024 {
+			p = self.add_round_constants_p(p, r);
+			q = self.add_round_constants_q(q, r);
+			p = self.sub_mix_shift(p, shift_p_1024_func);
+			q = self.sub_mix_shift(q, shift_q_1024_func);
+		}
+
+		(p, q)
+	}
+
+	/// The P permutation from Grøstl512, used in the output transformation
+	pub fn permutation_p(
+		&self,
+		p: [PackedAESBinaryField64x8b; 2],
+	) -> [PackedAESBinaryField64x8b; 2] {
+		let mut p = p;
+		for r in 0..ROUND_SIZE_1024 {
+			p = self.add_round_constants_p(p, r);
+			p = self.sub_mix_shift(p, shift_p_1024_func);
+		}
+		p
+	}
 }
 
 #[cfg(test)]
@@ -184,8 +315,10 @@ mod tests {
 			PackedAESBinaryField64x8b::from_fn(|i| AESTowerField8b::new((64 * off + i) as u8))
 		});
 
-		let instance = Groestl256Core;
+		let instance = Groestl256TableCore;
 		let (pout, qout) = instance.permutation_pq(input[0], input[1]);
+		assert_eq!(instance.permutation_p(input[0]), pout);
+		assert_eq!(instance.permutation_q(input[1]), qout);
 
 		let pout = (0..8)
 			.map(|i| {
@@ -0,0 +1,277 @@
+// Copyright 2024 Irreducible Inc.
+
+//! Streaming Grøstl-256 hashing of a batch of messages in bitsliced form.
+
+use super::{
+	native::{compress_with, iv, num_padded_blocks_of, output_transform_with, BLOCK_LEN_U8},
+	DIGEST_BYTES,
+};
+use crate::groestl::{
+	arch::{
+		bitsliced::{broadcast, transpose_in},
+		BitslicedState, Groestl256BitslicedCore, Groestl256Core, Groestl256Permutations,
+		StateBytes, BITSLICED_LANES,
+	},
+	hasher::{compression_func, GroestlDigest},
+};
+use binius_field::{
+	arch::OptimalUnderlier256b, as_packed_field::PackScalar, underlier::Divisible, AESTowerField8b,
+	BinaryField, PackedAESBinaryField64x8b, PackedField,
+};
+use std::{array, collections::VecDeque, marker::PhantomData};
+
+/// The number of full blocks a lane may buffer while waiting for the other lanes to fill an
+/// absorption round, before they are absorbed on their own
+const MAX_PENDING_BLOCKS: usize = 16;
+
+/// IV for Grøstl256
+const IV: StateBytes = iv(DIGEST_BYTES);
+
+/// Streaming Grøstl-256 hasher for [`BITSLICED_LANES`] independent messages.
+///
+/// Each lane is fed incrementally through [`BatchHasher::update`]. The chaining values of all
+/// lanes are kept in bitsliced form and full message blocks are buffered per lane, so the
+/// messages never need to be materialized as a whole. An absorption round takes the oldest
+/// pending block of every lane and runs as soon as every lane has one.
+///
+/// A bitsliced round costs about as much whether it absorbs one block or [`BITSLICED_LANES`], so
+/// a lane that runs ahead of the others does not force rounds. Once it has buffered more than
+/// `MAX_PENDING_BLOCKS` blocks, they are absorbed with the single-message [`Groestl256Core`]
+/// instead, at the cost of scalar Grøstl. Feeding the lanes in an interleaved fashion keeps the
+/// rounds full and gets the bitsliced throughput.
+///
+/// [`BatchHasher::finalize_all`] pads every lane and returns the digests, which are identical to
+/// those of [`Groestl256`](super::Groestl256) over the same bytes.
+#[derive(Debug, Clone)]
+pub struct BatchHasher<F = AESTowerField8b> {
+	state: BitslicedState,
+	core: Groestl256Permutations,
+	lanes: [LaneBuffer; BITSLICED_LANES],
+	_f_marker: PhantomData<F>,
+}
+
+#[derive(Debug, Clone, Default)]
+struct LaneBuffer {
+	/// Full blocks waiting to be absorbed
+	pending: VecDeque<StateBytes>,
+	/// The trailing bytes that do not fill a block yet
+	partial: Vec<u8>,
+	/// Number of bytes fed into the lane
+	len: usize,
+}
+
+impl<F> Default for BatchHasher<F> {
+	fn default() -> Self {
+		Self {
+			state: broadcast(&IV),
+			core: Groestl256Core.resolve(),
+			lanes: array::from_fn(|_| LaneBuffer::default()),
+			_f_marker: PhantomData,
+		}
+	}
+}
+
+impl<F> BatchHasher<F>
+where
+	F: BinaryField + From<AESTowerField8b>,
+	OptimalUnderlier256b: PackScalar<F> + Divisible<F::Underlier>,
+{
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Appends `data` to the message of lane `lane`.
+	///
+	/// ## Panics
+	///
+	/// Panics if `lane` is not less than [`BITSLICED_LANES`].
+	pub fn update(&mut self, lane: usize, data: &[u8]) {
+		assert!(lane < BITSLICED_LANES, "lane index {lane} out of range");
+
+		let buffer = &mut self.lanes[lane];
+		buffer.len += data.len();
+		buffer.partial.extend_from_slice(data);
+		if buffer.partial.len() >= BLOCK_LEN_U8 {
+			let mut blocks = buffer.partial.chunks_exact(BLOCK_LEN_U8);
+			buffer.pending.extend(
+				blocks
+					.by_ref()
+					.map(|block| StateBytes::try_from(block).expect("chunk has block length")),
+			);
+			buffer.partial = blocks.remainder().to_vec();
+		}
+
+		while self.lanes.iter().all(|buffer| !buffer.pending.is_empty()) {
+			self.absorb_round();
+		}
+		if self.lanes[lane].pending.len() > MAX_PENDING_BLOCKS {
+			self.absorb_lane(lane);
+		}
+	}
+
+	/// Pads the messages of all lanes and returns their digests, in lane order.
+	///
+	/// Lanes that were never updated hold the digest of the empty message.
+	pub fn finalize_all(mut self) -> Vec<GroestlDigest<F>> {
+		for buffer in self.lanes.iter_mut() {
+			let num_blocks = num_padded_blocks_of(buffer.len, BLOCK_LEN_U8);
+
+			// A single 0x80 byte, zeros and the 8-byte big-endian block count
+			let mut tail = std::mem::take(&mut buffer.partial);
+			tail.push(0x80);
+			tail.resize((tail.len() + 8).next_multiple_of(BLOCK_LEN_U8) - 8, 0);
+			tail.extend_from_slice(&(num_blocks as u64).to_be_bytes());
+			buffer.pending.extend(
+				tail.chunks_exact(BLOCK_LEN_U8)
+					.map(|block| StateBytes::try_from(block).expect("chunk has block length")),
+			);
+		}
+
+		while self.lanes.iter().any(|buffer| !buffer.pending.is_empty()) {
+			self.absorb_round();
+		}
+
+		output_transform_with(&Groestl256BitslicedCore, &self.state)
+			.iter()
+			.map(|lane| {
+				GroestlDigest::<F>::from_fn(|i| {
+					F::from(AESTowerField8b::new(lane[BLOCK_LEN_U8 - DIGEST_BYTES + i]))
+				})
+			})
+			.collect()
+	}
+
+	/// Absorbs the oldest pending block of every lane that has one
+	fn absorb_round(&mut self) {
+		let mut blocks = [[0u8; BLOCK_LEN_U8]; BITSLICED_LANES];
+		let mut mask = 0u64;
+		for (i, (block, buffer)) in blocks.iter_mut().zip(self.lanes.iter_mut()).enumerate() {
+			if let Some(pending) = buffer.pending.pop_front() {
+				*block = pending;
+				mask |= 1 << i;
+			}
+		}
+
+		let mut state = self.state;
+		compress_with(&Groestl256BitslicedCore, &mut state, &transpose_in(&blocks));
+		for (byte, new_byte) in self.state.iter_mut().zip(&state) {
+			for (plane, new_plane) in byte.iter_mut().zip(new_byte) {
+				*plane ^= (*plane ^ new_plane) & mask;
+			}
+		}
+	}
+
+	/// Absorbs all pending blocks of lane `lane` with the single-message compression function
+	fn absorb_lane(&mut self, lane: usize) {
+		let mut h = PackedAESBinaryField64x8b::from_fn(|i| {
+			let byte = self.state[i]
+				.iter()
+				.enumerate()
+				.fold(0u8, |byte, (b, plane)| byte | (((plane >> lane) & 1) as u8) << b);
+			AESTowerField8b::new(byte)
+		});
+		for block in self.lanes[lane].pending.drain(..) {
+			let m = PackedAESBinaryField64x8b::from_fn(|i| AESTowerField8b::new(block[i]));
+			h = compression_func(&self.core, h, m);
+		}
+
+		for (i, byte) in self.state.iter_mut().enumerate() {
+			let value = h.get(i).val();
+			for (b, plane) in byte.iter_mut().enumerate() {
+				*plane = (*plane & !(1 << lane)) | ((((value >> b) & 1) as u64) << lane);
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use binius_field::BinaryField8b;
+	use groestl_crypto::Digest;
+	use rand::{thread_rng, Rng, RngCore};
+
+	fn check_digests(hasher: BatchHasher, messages: &[Vec<u8>]) {
+		for (digest, msg) in hasher.finalize_all().iter().zip(messages) {
+			let expected = groestl_crypto::Groestl256::digest(msg);
+			assert!(digest.iter().zip(expected).all(|(a, b)| a.val() == b));
+		}
+	}
+
+	#[test]
+	fn test_interleaved_updates() {
+		let mut rng = thread_rng();
+		let mut hasher = BatchHasher::<AESTowerField8b>::new();
+		let mut messages = vec![Vec::new(); BITSLICED_LANES];
+
+		for _ in 0..200 {
+			let lane = rng.gen_range(0..BITSLICED_LANES);
+			let mut chunk = vec![0u8; rng.gen_range(0..100)];
+			rng.fill_bytes(&mut chunk);
+			hasher.update(lane, &chunk);
+			messages[lane].extend_from_slice(&chunk);
+		}
+
+		check_digests(hasher, &messages);
+	}
+
+	#[test]
+	fn test_single_long_lane() {
+		let mut rng = thread_rng();
+		let mut hasher = BatchHasher::<AESTowerField8b>::new();
+		let mut messages = vec![Vec::new(); BITSLICED_LANES];
+
+		// Enough data to trigger several absorption rounds while the other lanes stay idle
+		messages[5] = vec![0u8; 3 * MAX_PENDING_BLOCKS * BLOCK_LEN_U8 + 55];
+		rng.fill_bytes(&mut messages[5]);
+		for chunk in messages[5].chunks(1000) {
+			hasher.update(5, chunk);
+			assert!(hasher.lanes[5].pending.len() <= MAX_PENDING_BLOCKS);
+		}
+		// The lone lane was absorbed on its own, leaving the state of the other lanes untouched
+		let idle = broadcast(&IV);
+		for (byte, idle_byte) in hasher.state.iter().zip(&idle) {
+			for (plane, idle_plane) in byte.iter().zip(idle_byte) {
+				assert_eq!(plane & !(1 << 5), idle_plane & !(1 << 5));
+			}
+		}
+		hasher.update(7, b"abc");
+		messages[7] = b"abc".to_vec();
+
+		check_digests(hasher, &messages);
+	}
+
+	#[test]
+	fn test_full_rounds_absorbed_eagerly() {
+		let mut rng = thread_rng();
+		let mut hasher = BatchHasher::<AESTowerField8b>::new();
+		let mut messages = vec![vec![0u8; BLOCK_LEN_U8]; BITSLICED_LANES];
+
+		// The last lane to get a block completes a round, which empties every buffer
+		for (lane, msg) in messages.iter_mut().enumerate() {
+			rng.fill_bytes(msg);
+			hasher.update(lane, msg);
+		}
+		assert!(hasher.lanes.iter().all(|buffer| buffer.pending.is_empty()));
+
+		check_digests(hasher, &messages);
+	}
+
+	#[test]
+	fn test_binary_field_digests() {
+		let mut hasher = BatchHasher::<AESTowerField8b>::new();
+		let mut hasher_bin = BatchHasher::<BinaryField8b>::new();
+		for lane in 0..BITSLICED_LANES {
+			let msg = vec![lane as u8; 3 * lane];
+			hasher.update(lane, &msg);
+			hasher_bin.update(lane, &msg);
+		}
+
+		for (digest_bin, digest) in hasher_bin.finalize_all().iter().zip(hasher.finalize_all()) {
+			assert!(digest_bin
+				.iter()
+				.zip(digest.iter())
+				.all(|(a, b)| a == BinaryField8b::from(b)));
+		}
+	}
+}
@@ -0,0 +1,89 @@
+// Copyright 2024 Irreducible Inc.
+
+//! Bindings to the bitsliced Grøstl-256 kernel written in C.
+
+use super::{ByteRepr, DIGEST_BYTES};
+use bytemuck::{Pod, Zeroable};
+
+/// The number of messages hashed by a single kernel call
+pub(super) const LANES: usize = 64;
+/// Message lengths must be a multiple of the size of the kernel's input elements
+pub(super) const MSG_ALIGNMENT: usize = std::mem::size_of::<PackedPrimitiveType>();
+pub(super) const ACCEPTS_EMPTY_MESSAGES: bool = false;
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Pod, Zeroable)]
+pub struct M128 {
+	pub high: u64,
+	pub low: u64,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Pod, Zeroable)]
+pub struct PackedPrimitiveType {
+	pub value: M128,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Pod, Zeroable)]
+pub struct Digest {
+	pub elements: [PackedPrimitiveType; 2],
+}
+
+extern "C" {
+	// total_length is the length of the input in bytes
+	// chunk_size is the chunk size in bytes
+	fn binius_groestl_bs_hash(
+		digests: *mut Digest,
+		input: *mut PackedPrimitiveType,
+		total_length: usize,
+		chunk_size: usize,
+	);
+}
+
+/// The kernel takes a single chunk size, so only messages of the same length are hashed together
+pub(super) fn group_key(msg_len: usize) -> usize {
+	msg_len
+}
+
+/// Hashes up to `LANES` messages sharing the same [`group_key`] in a single kernel call and
+/// returns the concatenated digests of all lanes, including the padding ones.
+///
+/// The caller must have checked that the message length is a non-zero multiple of
+/// `MSG_ALIGNMENT`. The kernel only accepts the AES representation, so messages in another
+/// representation are converted byte by byte.
+pub(super) fn hash_group(group: &[&[u8]], repr: ByteRepr) -> Vec<u8> {
+	assert!(group.len() <= LANES);
+	let Some(msg_len) = group.first().map(|msg| msg.len()) else {
+		return Vec::new();
+	};
+	debug_assert!(msg_len != 0 && msg_len % MSG_ALIGNMENT == 0);
+	debug_assert!(group.iter().all(|msg| msg.len() == msg_len));
+
+	// Copying into an owned buffer of `PackedPrimitiveType` guarantees the alignment the
+	// kernel expects, independently of the alignment of the caller's slices.
+	let total_length = msg_len * LANES;
+	let mut input = vec![PackedPrimitiveType::default(); total_length / MSG_ALIGNMENT];
+	let input_bytes: &mut [u8] = bytemuck::cast_slice_mut(&mut input);
+	for (chunk, msg) in input_bytes.chunks_exact_mut(msg_len).zip(group) {
+		match repr {
+			ByteRepr::Aes => chunk.copy_from_slice(msg),
+			ByteRepr::Binary => {
+				for (dst, &src) in chunk.iter_mut().zip(msg.iter()) {
+					*dst = repr.decode(src);
+				}
+			}
+		}
+	}
+
+	let mut digests = vec![Digest::default(); LANES];
+	// SAFETY: `input` holds exactly `total_length` bytes, `total_length` is divisible by
+	// `msg_len`, and `digests` has room for the `total_length / msg_len` outputs.
+	unsafe {
+		binius_groestl_bs_hash(digests.as_mut_ptr(), input.as_mut_ptr(), total_length, msg_len);
+	}
+
+	let digest_bytes: &[u8] = bytemuck::cast_slice(&digests);
+	debug_assert_eq!(digest_bytes.len(), LANES * DIGEST_BYTES);
+	digest_bytes.to_vec()
+}
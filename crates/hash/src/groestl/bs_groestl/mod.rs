@@ -0,0 +1,775 @@
+// Copyright 2024 Irreducible Inc.
+
+//! Safe interface to the bitsliced implementation of Grøstl-256.
+//!
+//! The bitsliced kernel hashes [`BS_GROESTL_LANES`] independent messages at once. By default it
+//! is the pure Rust implementation built on [`Groestl256BitslicedCore`]. With the `bs-groestl-c`
+//! feature the kernel written in C is linked instead, through a small FFI layer which is kept
+//! private to this module.
+//!
+//! The pure Rust kernel does not branch on, nor index memory with, the message contents. Only the
+//! number of messages and their lengths affect its control flow, so it may hash secret data. The
+//! batched hashers and compression functions however pick a backend at runtime, which may be the
+//! lookup tables or the C kernel, so secret data should go through
+//! [`BsGroestl256::hash_many_constant_time`].
+//!
+//! [`Groestl256BitslicedCore`]: super::arch::Groestl256BitslicedCore
+
+use super::{
+	arch::{
+		bitsliced::{transpose_in, transpose_out, xor_state},
+		groestl_dispatch, Groestl256BitslicedCore, GroestlBackend, BITSLICED_LANES,
+	},
+	hasher::{Groestl256, GroestlDigest, GroestlDigestCompression},
+};
+use crate::{
+	algorithm_id::{FieldTowerId, HashAlgorithmId},
+	compression::MultiPseudoCompressionFunction,
+	hasher::{concat_parts, Hasher, MultiHasher},
+};
+use binius_field::{
+	arch::OptimalUnderlier256b, as_packed_field::PackScalar, underlier::Divisible, AESTowerField8b,
+	BinaryField, BinaryField8b, ExtensionField, PackedExtension, PackedExtensionIndexable,
+	PackedField, PackedFieldIndexable,
+};
+use cfg_if::cfg_if;
+use p3_symmetric::{CompressionFunction, PseudoCompressionFunction};
+use std::{
+	any::TypeId, array, borrow::Cow, collections::BTreeMap, marker::PhantomData, mem::MaybeUninit,
+};
+
+// The other Grøstl variants, the streaming hasher and the constant-time hashing build on the pure
+// Rust kernel, so it is compiled in any case
+mod batch;
+mod native;
+mod variants;
+
+cfg_if! {
+	if #[cfg(feature = "bs-groestl-c")] {
+		mod ffi;
+		use ffi as kernel;
+	} else {
+		use native as kernel;
+	}
+}
+
+pub use batch::BatchHasher;
+pub use variants::{BsGroestl224, BsGroestl512};
+
+/// The number of messages the bitsliced kernel hashes in parallel.
+pub const BS_GROESTL_LANES: usize = kernel::LANES;
+
+/// Message lengths accepted by the bitsliced kernel must be a multiple of this many bytes.
+pub const BS_GROESTL_MSG_ALIGNMENT: usize = kernel::MSG_ALIGNMENT;
+
+const DIGEST_BYTES: usize = 32;
+
+/// The field whose canonical byte encoding the message bytes handed to the kernel are in.
+///
+/// Grøstl is defined over the AES field. Messages over `BinaryField8b` are passed to the kernel
+/// as they are, and the pure Rust kernel applies the change of basis to whole bitsliced blocks
+/// at once instead of converting every byte.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ByteRepr {
+	Aes,
+	Binary,
+}
+
+impl ByteRepr {
+	/// The representation used for the scalars of `F`, if the kernel supports it
+	fn of<F: 'static>() -> Option<Self> {
+		if TypeId::of::<F>() == TypeId::of::<AESTowerField8b>() {
+			Some(Self::Aes)
+		} else if TypeId::of::<F>() == TypeId::of::<BinaryField8b>() {
+			Some(Self::Binary)
+		} else {
+			None
+		}
+	}
+
+	/// Encodes a byte of the AES representation, e.g. a padding byte, in this representation
+	fn encode(self, byte: u8) -> u8 {
+		match self {
+			Self::Aes => byte,
+			Self::Binary => BinaryField8b::from(AESTowerField8b::new(byte)).val(),
+		}
+	}
+
+	/// Decodes a byte of this representation into the AES representation
+	fn decode(self, byte: u8) -> u8 {
+		match self {
+			Self::Aes => byte,
+			Self::Binary => AESTowerField8b::from(BinaryField8b::new(byte)).val(),
+		}
+	}
+
+	/// The columns of the linear map from this representation to the AES one
+	fn to_aes_columns(self) -> [u8; 8] {
+		array::from_fn(|j| self.decode(1 << j))
+	}
+}
+
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum BsGroestlError {
+	#[error("message at index {index} is empty")]
+	EmptyMessage { index: usize },
+	#[error("message length {len} at index {index} is not a multiple of {alignment} bytes")]
+	MisalignedLength {
+		index: usize,
+		len: usize,
+		alignment: usize,
+	},
+}
+
+/// Bitsliced Grøstl-256 hasher for batches of independent messages.
+///
+/// The digests are byte-identical to those of [`Groestl256`](super::Groestl256) over
+/// `AESTowerField8b`. When `F` is `BinaryField8b`, the digest bytes are mapped through the
+/// AES-to-binary isomorphism, exactly as [`Groestl256`](super::Groestl256) does.
+#[derive(Debug, Default, Clone)]
+pub struct BsGroestl256<F = AESTowerField8b> {
+	_f_marker: PhantomData<F>,
+}
+
+impl<F> BsGroestl256<F>
+where
+	F: BinaryField + From<AESTowerField8b>,
+	OptimalUnderlier256b: PackScalar<F> + Divisible<F::Underlier>,
+{
+	/// Hashes every message in `messages` and returns the digests in the same order.
+	///
+	/// Messages may have different lengths. They are grouped internally so that every group only
+	/// contains messages the kernel can process together, e.g. messages spanning the same number
+	/// of blocks after padding. Every length must be a multiple of [`BS_GROESTL_MSG_ALIGNMENT`]
+	/// bytes and the C kernel additionally rejects empty messages.
+	pub fn hash_many(messages: &[&[u8]]) -> Result<Vec<GroestlDigest<F>>, BsGroestlError> {
+		for (index, msg) in messages.iter().enumerate() {
+			check_length(index, msg.len())?;
+		}
+		Ok(hash_grouped(messages, ByteRepr::Aes))
+	}
+
+	/// Hashes every message in `messages` with the pure Rust bitsliced kernel, independently of
+	/// the runtime dispatch and of the `bs-groestl-c` feature.
+	///
+	/// The running time only depends on the number of messages and their lengths, which are
+	/// assumed to be public, and not on the message contents. Messages may have any length.
+	pub fn hash_many_constant_time(messages: &[&[u8]]) -> Vec<GroestlDigest<F>> {
+		let mut digests = vec![GroestlDigest::<F>::default(); messages.len()];
+		native::hash_grouped_with(&Groestl256BitslicedCore, messages, DIGEST_BYTES, |i, digest| {
+			digests[i] = GroestlDigest::<F>::from_fn(|j| F::from(AESTowerField8b::new(digest[j])));
+		});
+		digests
+	}
+}
+
+/// Hashes messages which all passed [`check_length`], grouping them by the kernel's group key.
+fn hash_grouped<F>(messages: &[&[u8]], repr: ByteRepr) -> Vec<GroestlDigest<F>>
+where
+	F: BinaryField + From<AESTowerField8b>,
+	OptimalUnderlier256b: PackScalar<F> + Divisible<F::Underlier>,
+{
+	let mut indices_by_key = BTreeMap::<usize, Vec<usize>>::new();
+	for (i, msg) in messages.iter().enumerate() {
+		indices_by_key
+			.entry(kernel::group_key(msg.len()))
+			.or_default()
+			.push(i);
+	}
+
+	let mut digests = vec![GroestlDigest::<F>::default(); messages.len()];
+	for indices in indices_by_key.into_values() {
+		for chunk in indices.chunks(BS_GROESTL_LANES) {
+			let group = chunk.iter().map(|&i| messages[i]).collect::<Vec<_>>();
+			let digest_bytes = kernel::hash_group(&group, repr);
+			for (&i, digest) in chunk.iter().zip(digest_bytes.chunks_exact(DIGEST_BYTES)) {
+				digests[i] =
+					GroestlDigest::<F>::from_fn(|j| F::from(AESTowerField8b::new(digest[j])));
+			}
+		}
+	}
+	digests
+}
+
+/// Hashes byte messages of arbitrary, possibly different, lengths, encoded in `repr`.
+///
+/// Messages the kernel accepts are hashed in bitsliced form, the remaining ones fall back to
+/// [`Groestl256`]. If the runtime dispatch prefers hashing messages one by one on this CPU, all of
+/// them are hashed with [`Groestl256`].
+fn hash_bytes_many<F>(messages: &[&[u8]], repr: ByteRepr) -> Vec<GroestlDigest<F>>
+where
+	F: BinaryField + From<AESTowerField8b>,
+	OptimalUnderlier256b: PackScalar<F> + Divisible<F::Underlier>,
+{
+	if groestl_dispatch().batch != GroestlBackend::Bitsliced {
+		return messages
+			.iter()
+			.map(|msg| single_digest(msg, repr))
+			.collect();
+	}
+
+	let (supported, unsupported): (Vec<_>, Vec<_>) =
+		(0..messages.len()).partition(|&i| check_length(i, messages[i].len()).is_ok());
+
+	let mut digests = vec![GroestlDigest::<F>::default(); messages.len()];
+	let supported_messages = supported.iter().map(|&i| messages[i]).collect::<Vec<_>>();
+	for (i, digest) in supported
+		.into_iter()
+		.zip(hash_grouped(&supported_messages, repr))
+	{
+		digests[i] = digest;
+	}
+	for i in unsupported {
+		digests[i] = single_digest(messages[i], repr);
+	}
+	digests
+}
+
+fn single_digest<F>(msg: &[u8], repr: ByteRepr) -> GroestlDigest<F>
+where
+	F: BinaryField + From<AESTowerField8b>,
+	OptimalUnderlier256b: PackScalar<F> + Divisible<F::Underlier>,
+{
+	// The message is decoded through a buffer on the stack rather than collected into a Vec
+	const CHUNK_LEN: usize = 64;
+
+	let mut hasher = Groestl256::<AESTowerField8b, AESTowerField8b>::new();
+	let mut chunk = [AESTowerField8b::default(); CHUNK_LEN];
+	for bytes in msg.chunks(CHUNK_LEN) {
+		for (x, &b) in chunk.iter_mut().zip(bytes) {
+			*x = AESTowerField8b::new(repr.decode(b));
+		}
+		hasher.update(&chunk[..bytes.len()]);
+	}
+	let digest = hasher.finalize();
+	GroestlDigest::<F>::from_fn(|i| F::from(digest.get(i)))
+}
+
+/// Checks that the kernel accepts messages of length `len`.
+fn check_length(index: usize, len: usize) -> Result<(), BsGroestlError> {
+	if len == 0 && !kernel::ACCEPTS_EMPTY_MESSAGES {
+		return Err(BsGroestlError::EmptyMessage { index });
+	}
+	if len % BS_GROESTL_MSG_ALIGNMENT != 0 {
+		return Err(BsGroestlError::MisalignedLength {
+			index,
+			len,
+			alignment: BS_GROESTL_MSG_ALIGNMENT,
+		});
+	}
+	Ok(())
+}
+
+/// The Grøstl-256 hash function backed by the bitsliced kernel.
+///
+/// This is a drop-in replacement for [`Groestl256`] with the same type parameters and identical
+/// digests. A single message hashed through [`Hasher`] occupies a whole bitsliced batch, so the
+/// speedup only materializes through [`MultiHasher::hash_many`].
+#[derive(Debug, Clone)]
+pub struct BsGroestl256Hasher<P, F> {
+	/// The message bytes, in the representation returned by [`to_kernel_bytes`]
+	buffer: Vec<u8>,
+	_p_marker: PhantomData<P>,
+	_f_marker: PhantomData<F>,
+}
+
+impl<P, F: 'static> BsGroestl256Hasher<P, F> {
+	/// The representation of the buffered bytes, see [`to_kernel_bytes`]
+	fn repr() -> ByteRepr {
+		ByteRepr::of::<F>().unwrap_or(ByteRepr::Aes)
+	}
+}
+
+impl<P, F> Default for BsGroestl256Hasher<P, F> {
+	fn default() -> Self {
+		Self {
+			buffer: Vec::new(),
+			_p_marker: PhantomData,
+			_f_marker: PhantomData,
+		}
+	}
+}
+
+/// Returns the bytes of the message in the order [`Groestl256`] absorbs them, together with
+/// their representation.
+///
+/// Messages over `AESTowerField8b` and `BinaryField8b` are reinterpreted as bytes without any
+/// conversion, other fields are converted into the AES representation.
+fn to_kernel_bytes<P, F>(msg: &[P]) -> (Cow<'_, [u8]>, ByteRepr)
+where
+	F: BinaryField + Into<AESTowerField8b>,
+	P: PackedExtension<F, PackedSubfield: PackedFieldIndexable>,
+	P::Scalar: ExtensionField<F>,
+{
+	let scalars = P::unpack_base_scalars(msg);
+	match ByteRepr::of::<F>() {
+		Some(repr) => (Cow::Borrowed(bytemuck::cast_slice(F::to_underliers_ref(scalars))), repr),
+		None => {
+			let bytes = scalars
+				.iter()
+				.map(|&x| Into::<AESTowerField8b>::into(x).val())
+				.collect();
+			(Cow::Owned(bytes), ByteRepr::Aes)
+		}
+	}
+}
+
+impl<P, F> Hasher<P> for BsGroestl256Hasher<P, F>
+where
+	F: BinaryField + From<AESTowerField8b> + Into<AESTowerField8b>,
+	P: PackedExtension<F, PackedSubfield: PackedFieldIndexable>,
+	P::Scalar: ExtensionField<F>,
+	OptimalUnderlier256b: PackScalar<F> + Divisible<F::Underlier>,
+{
+	type Digest = GroestlDigest<F>;
+
+	fn new() -> Self {
+		Self::default()
+	}
+
+	fn update(&mut self, data: impl AsRef<[P]>) {
+		let (bytes, _) = to_kernel_bytes::<P, F>(data.as_ref());
+		self.buffer.extend_from_slice(&bytes);
+	}
+
+	fn chain_update(mut self, data: impl AsRef<[P]>) -> Self {
+		self.update(data);
+		self
+	}
+
+	fn finalize(mut self) -> Self::Digest {
+		self.finalize_reset()
+	}
+
+	fn finalize_into(self, out: &mut MaybeUninit<Self::Digest>) {
+		out.write(self.finalize());
+	}
+
+	fn finalize_reset(&mut self) -> Self::Digest {
+		let digest = hash_bytes_many::<F>(&[&self.buffer], Self::repr())
+			.pop()
+			.expect("one digest is returned per message");
+		self.reset();
+		digest
+	}
+
+	fn finalize_into_reset(&mut self, out: &mut MaybeUninit<Self::Digest>) {
+		out.write(self.finalize_reset());
+	}
+
+	fn reset(&mut self) {
+		self.buffer.clear();
+	}
+}
+
+impl<P, F> MultiHasher<P> for BsGroestl256Hasher<P, F>
+where
+	F: BinaryField + From<AESTowerField8b> + Into<AESTowerField8b>,
+	P: PackedExtension<F, PackedSubfield: PackedFieldIndexable>,
+	P::Scalar: ExtensionField<F>,
+	OptimalUnderlier256b: PackScalar<F> + Divisible<F::Underlier>,
+{
+	const BATCH_WIDTH: usize = BS_GROESTL_LANES;
+
+	fn hash_many_parts<'a, M>(messages: impl IntoIterator<Item = M>) -> Vec<Self::Digest>
+	where
+		P: 'a,
+		M: IntoIterator<Item = &'a [P]>,
+	{
+		let messages = messages
+			.into_iter()
+			.map(|parts| {
+				concat_parts(
+					parts
+						.into_iter()
+						.map(|part| to_kernel_bytes::<P, F>(part).0),
+				)
+			})
+			.collect::<Vec<_>>();
+		let messages = messages.iter().map(AsRef::as_ref).collect::<Vec<_>>();
+		hash_bytes_many::<F>(&messages, Self::repr())
+	}
+}
+
+/// Shares the identifier of [`Groestl256`], whose digests it reproduces.
+impl<P, F: FieldTowerId> HashAlgorithmId for BsGroestl256Hasher<P, F> {
+	const NAME: &'static str = <Groestl256<P, F> as HashAlgorithmId>::NAME;
+	const VERSION: u16 = <Groestl256<P, F> as HashAlgorithmId>::VERSION;
+	const TOWER: &'static str = <Groestl256<P, F> as HashAlgorithmId>::TOWER;
+	const DIGEST_BYTES: usize = <Groestl256<P, F> as HashAlgorithmId>::DIGEST_BYTES;
+}
+
+/// The bitsliced counterpart of [`GroestlDigestCompression`].
+///
+/// Pairs of digests are compressed with the Grøstl output transformation, [`BITSLICED_LANES`]
+/// pairs at a time, on top of [`Groestl256BitslicedCore`]. The outputs are identical to those of
+/// [`GroestlDigestCompression`]. A single pair would cost as much as a whole batch, so
+/// [`PseudoCompressionFunction::compress`] delegates to [`GroestlDigestCompression`] instead,
+/// which keeps Merkle path verification cheap. Batches are delegated as well when the runtime
+/// dispatch does not select the bitsliced backend on this CPU.
+#[derive(Debug, Default, Clone)]
+pub struct BsGroestlDigestCompression<F> {
+	_f_marker: PhantomData<F>,
+}
+
+impl<F> BsGroestlDigestCompression<F>
+where
+	F: BinaryField + From<AESTowerField8b> + Into<AESTowerField8b>,
+	OptimalUnderlier256b: PackScalar<F> + Divisible<F::Underlier>,
+{
+	/// Compresses up to [`BITSLICED_LANES`] pairs of digests.
+	fn compress_group(inputs: &[[GroestlDigest<F>; 2]]) -> Vec<GroestlDigest<F>> {
+		debug_assert!(inputs.len() <= BITSLICED_LANES);
+
+		let mut lanes = [[0u8; 2 * DIGEST_BYTES]; BITSLICED_LANES];
+		for (lane, input) in lanes.iter_mut().zip(inputs) {
+			for (i, byte) in lane.iter_mut().enumerate() {
+				let x: AESTowerField8b = input[i / DIGEST_BYTES].get(i % DIGEST_BYTES).into();
+				*byte = x.val();
+			}
+		}
+
+		let input_state = transpose_in(&lanes);
+		let mut state = input_state;
+		Groestl256BitslicedCore.permutation_p(&mut state);
+		xor_state(&mut state, &input_state);
+
+		transpose_out(&state)
+			.iter()
+			.take(inputs.len())
+			.map(|lane| {
+				GroestlDigest::<F>::from_fn(|i| {
+					F::from(AESTowerField8b::new(lane[DIGEST_BYTES + i]))
+				})
+			})
+			.collect()
+	}
+}
+
+impl<F> PseudoCompressionFunction<GroestlDigest<F>, 2> for BsGroestlDigestCompression<F>
+where
+	F: BinaryField + From<AESTowerField8b> + Into<AESTowerField8b>,
+	OptimalUnderlier256b: PackScalar<F> + Divisible<F::Underlier>,
+{
+	fn compress(&self, input: [GroestlDigest<F>; 2]) -> GroestlDigest<F> {
+		GroestlDigestCompression::<F>::default().compress(input)
+	}
+}
+
+impl<F> CompressionFunction<GroestlDigest<F>, 2> for BsGroestlDigestCompression<F>
+where
+	F: BinaryField + From<AESTowerField8b> + Into<AESTowerField8b>,
+	OptimalUnderlier256b: PackScalar<F> + Divisible<F::Underlier>,
+{
+}
+
+impl<F> MultiPseudoCompressionFunction<GroestlDigest<F>, 2> for BsGroestlDigestCompression<F>
+where
+	F: BinaryField + From<AESTowerField8b> + Into<AESTowerField8b>,
+	OptimalUnderlier256b: PackScalar<F> + Divisible<F::Underlier>,
+{
+	const BATCH_WIDTH: usize = BITSLICED_LANES;
+
+	fn compress_many(&self, inputs: &[[GroestlDigest<F>; 2]]) -> Vec<GroestlDigest<F>> {
+		if groestl_dispatch().batch != GroestlBackend::Bitsliced {
+			return inputs.iter().map(|&input| self.compress(input)).collect();
+		}
+
+		inputs
+			.chunks(BITSLICED_LANES)
+			.flat_map(Self::compress_group)
+			.collect()
+	}
+}
+
+/// Shares the identifier of [`GroestlDigestCompression`], whose outputs it reproduces.
+impl<F> HashAlgorithmId for BsGroestlDigestCompression<F>
+where
+	F: BinaryField + From<AESTowerField8b> + Into<AESTowerField8b> + FieldTowerId,
+{
+	const NAME: &'static str = <GroestlDigestCompression<F> as HashAlgorithmId>::NAME;
+	const VERSION: u16 = <GroestlDigestCompression<F> as HashAlgorithmId>::VERSION;
+	const TOWER: &'static str = <GroestlDigestCompression<F> as HashAlgorithmId>::TOWER;
+	const DIGEST_BYTES: usize = <GroestlDigestCompression<F> as HashAlgorithmId>::DIGEST_BYTES;
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{HashDigest, HasherDigest};
+	use binius_field::{BinaryField8b, Field, PackedAESBinaryField32x8b, PackedBinaryField32x8b};
+	use groestl_crypto::Digest;
+	use rand::{thread_rng, Rng, RngCore};
+	use std::{array, iter::repeat_with};
+
+	fn reference_digest(msg: &[u8]) -> GroestlDigest<AESTowerField8b> {
+		let msg_aes = msg
+			.iter()
+			.map(|&b| AESTowerField8b::new(b))
+			.collect::<Vec<_>>();
+		HasherDigest::<_, Groestl256<_, AESTowerField8b>>::hash(msg_aes)
+	}
+
+	#[test]
+	fn test_hash_many_matches_groestl256() {
+		let mut rng = thread_rng();
+		// Not a multiple of the lane count, to exercise the padded final group
+		let messages = (0..BS_GROESTL_LANES + 3)
+			.map(|_| {
+				let mut msg = vec![0u8; 256];
+				rng.fill_bytes(&mut msg);
+				msg
+			})
+			.collect::<Vec<_>>();
+		let messages = messages.iter().map(Vec::as_slice).collect::<Vec<_>>();
+
+		let digests = BsGroestl256::<AESTowerField8b>::hash_many(&messages).unwrap();
+		assert_eq!(digests.len(), messages.len());
+		for (digest, msg) in digests.iter().zip(&messages) {
+			assert_eq!(*digest, reference_digest(msg));
+		}
+
+		let digests_bin = BsGroestl256::<BinaryField8b>::hash_many(&messages).unwrap();
+		for (digest_bin, digest) in digests_bin.iter().zip(&digests) {
+			assert!(digest_bin
+				.iter()
+				.zip(digest.iter())
+				.all(|(a, b)| a == BinaryField8b::from(b)));
+		}
+	}
+
+	#[test]
+	fn test_hash_many_constant_time_matches_hash_many() {
+		let mut rng = thread_rng();
+		let messages = (0..BS_GROESTL_LANES + 9)
+			.map(|i| {
+				let mut msg = vec![0u8; 16 * (i % 7)];
+				rng.fill_bytes(&mut msg);
+				msg
+			})
+			.collect::<Vec<_>>();
+		let messages = messages.iter().map(Vec::as_slice).collect::<Vec<_>>();
+
+		let digests = BsGroestl256::<BinaryField8b>::hash_many_constant_time(&messages);
+		let expected = messages
+			.iter()
+			.map(|msg| single_digest::<BinaryField8b>(msg, ByteRepr::Aes))
+			.collect::<Vec<_>>();
+		assert_eq!(digests, expected);
+
+		// On the lengths the kernel accepts, `hash_many` hashes the same messages
+		let (accepted, accepted_digests): (Vec<_>, Vec<_>) = messages
+			.iter()
+			.zip(digests)
+			.enumerate()
+			.filter(|(i, (msg, _))| check_length(*i, msg.len()).is_ok())
+			.map(|(_, pair)| pair)
+			.unzip();
+		assert!(!accepted.is_empty());
+		assert_eq!(BsGroestl256::<BinaryField8b>::hash_many(&accepted).unwrap(), accepted_digests);
+	}
+
+	#[test]
+	fn test_hash_many_mixed_lengths() {
+		let mut rng = thread_rng();
+		// Lengths spanning one, two and three padded blocks, interleaved
+		let messages = (0..3 * BS_GROESTL_LANES)
+			.map(|i| {
+				let mut msg = vec![0u8; [48, 112, 64, 160][i % 4]];
+				rng.fill_bytes(&mut msg);
+				msg
+			})
+			.collect::<Vec<_>>();
+		let messages = messages.iter().map(Vec::as_slice).collect::<Vec<_>>();
+
+		assert_eq!(BsGroestl256::<AESTowerField8b>::hash_many(&[]).unwrap(), Vec::new());
+		let digests = BsGroestl256::<AESTowerField8b>::hash_many(&messages).unwrap();
+		for (digest, msg) in digests.iter().zip(&messages) {
+			assert_eq!(*digest, reference_digest(msg));
+		}
+	}
+
+	#[test]
+	fn test_hash_many_matches_groestl_crypto() {
+		let mut rng = thread_rng();
+		let messages = (0..=1024)
+			.map(|len| {
+				let mut msg = vec![0u8; len];
+				rng.fill_bytes(&mut msg);
+				msg
+			})
+			.collect::<Vec<_>>();
+		let expected = messages
+			.iter()
+			.map(|msg| groestl_crypto::Groestl256::digest(msg))
+			.collect::<Vec<_>>();
+
+		// The C kernel only hashes part of the lengths, the others are left to the hasher below
+		let supported = messages
+			.iter()
+			.enumerate()
+			.filter(|(i, msg)| check_length(*i, msg.len()).is_ok())
+			.map(|(_, msg)| msg.as_slice())
+			.collect::<Vec<_>>();
+		let digests = BsGroestl256::<AESTowerField8b>::hash_many(&supported).unwrap();
+		for (digest, msg) in digests.iter().zip(&supported) {
+			let expected = &expected[msg.len()];
+			assert!(digest.iter().zip(expected).all(|(a, &b)| a.val() == b));
+		}
+
+		let messages_aes = messages
+			.iter()
+			.map(|msg| {
+				msg.iter()
+					.map(|&b| AESTowerField8b::new(b))
+					.collect::<Vec<_>>()
+			})
+			.collect::<Vec<_>>();
+		let messages_aes = messages_aes.iter().map(Vec::as_slice).collect::<Vec<_>>();
+		let digests =
+			<BsGroestl256Hasher<_, AESTowerField8b> as MultiHasher<_>>::hash_many(&messages_aes);
+		for (digest, expected) in digests.iter().zip(&expected) {
+			assert!(digest.iter().zip(expected).all(|(a, &b)| a.val() == b));
+		}
+	}
+
+	#[cfg(feature = "bs-groestl-c")]
+	#[test]
+	fn test_hash_many_rejects_unsupported_lengths() {
+		assert_eq!(
+			BsGroestl256::<AESTowerField8b>::hash_many(&[&[0u8; 16], &[]]).unwrap_err(),
+			BsGroestlError::EmptyMessage { index: 1 }
+		);
+		assert_eq!(
+			BsGroestl256::<AESTowerField8b>::hash_many(&[&[0u8; 17]]).unwrap_err(),
+			BsGroestlError::MisalignedLength {
+				index: 0,
+				len: 17,
+				alignment: 16
+			}
+		);
+	}
+
+	#[test]
+	fn test_hasher_matches_groestl256() {
+		let mut rng = thread_rng();
+
+		let data_aes = (0..90)
+			.map(|_| PackedAESBinaryField32x8b::random(&mut rng))
+			.collect::<Vec<_>>();
+		assert_eq!(
+			HasherDigest::<_, BsGroestl256Hasher<_, AESTowerField8b>>::hash(&data_aes),
+			HasherDigest::<_, Groestl256<_, AESTowerField8b>>::hash(&data_aes)
+		);
+
+		let data_bin = (0..90)
+			.map(|_| PackedBinaryField32x8b::random(&mut rng))
+			.collect::<Vec<_>>();
+		assert_eq!(
+			HasherDigest::<_, BsGroestl256Hasher<_, BinaryField8b>>::hash(&data_bin),
+			HasherDigest::<_, Groestl256<_, BinaryField8b>>::hash(&data_bin)
+		);
+	}
+
+	#[test]
+	fn test_multi_hasher_mixed_lengths() {
+		let mut rng = thread_rng();
+		// Mix of lengths, including some that the C kernel does not accept
+		let messages = (0..200)
+			.map(|i| {
+				let len = if i % 3 == 0 {
+					rng.gen_range(0..100)
+				} else {
+					128
+				};
+				(0..len)
+					.map(|_| <AESTowerField8b as Field>::random(&mut rng))
+					.collect::<Vec<_>>()
+			})
+			.collect::<Vec<_>>();
+		let messages = messages.iter().map(Vec::as_slice).collect::<Vec<_>>();
+
+		let expected = <Groestl256<_, AESTowerField8b> as MultiHasher<_>>::hash_many(&messages);
+		let digests =
+			<BsGroestl256Hasher<_, AESTowerField8b> as MultiHasher<_>>::hash_many(&messages);
+		assert_eq!(digests, expected);
+	}
+
+	#[test]
+	fn test_multi_hasher_binary_field_mixed_lengths() {
+		let mut rng = thread_rng();
+		// Lengths around the padding boundaries, where padding bytes share a block with the message
+		let messages = [0, 1, 54, 55, 56, 63, 64, 119, 120, 128, 200]
+			.into_iter()
+			.cycle()
+			.take(3 * BS_GROESTL_LANES)
+			.map(|len| {
+				(0..len)
+					.map(|_| <BinaryField8b as Field>::random(&mut rng))
+					.collect::<Vec<_>>()
+			})
+			.collect::<Vec<_>>();
+		let messages = messages.iter().map(Vec::as_slice).collect::<Vec<_>>();
+
+		let expected = <Groestl256<_, BinaryField8b> as MultiHasher<_>>::hash_many(&messages);
+		let digests =
+			<BsGroestl256Hasher<_, BinaryField8b> as MultiHasher<_>>::hash_many(&messages);
+		assert_eq!(digests, expected);
+	}
+
+	#[test]
+	fn test_hash_many_parts() {
+		let mut rng = thread_rng();
+		let messages = (0..2 * BS_GROESTL_LANES + 3)
+			.map(|i| {
+				(0..i % 5)
+					.map(|_| {
+						let len = rng.gen_range(0..40);
+						repeat_with(|| <BinaryField8b as Field>::random(&mut rng))
+							.take(len)
+							.collect::<Vec<_>>()
+					})
+					.collect::<Vec<_>>()
+			})
+			.collect::<Vec<_>>();
+		let concatenated = messages
+			.iter()
+			.map(|parts| parts.concat())
+			.collect::<Vec<_>>();
+		let concatenated = concatenated.iter().map(Vec::as_slice).collect::<Vec<_>>();
+
+		let expected = <Groestl256<_, BinaryField8b> as MultiHasher<_>>::hash_many(&concatenated);
+		let digests = <BsGroestl256Hasher<_, BinaryField8b> as MultiHasher<_>>::hash_many_parts(
+			messages.iter().map(|parts| parts.iter().map(Vec::as_slice)),
+		);
+		assert_eq!(digests, expected);
+	}
+
+	#[test]
+	fn test_compress_many_matches_groestl_digest_compression() {
+		let mut rng = thread_rng();
+
+		for n_inputs in [0, 1, BITSLICED_LANES, 2 * BITSLICED_LANES + 5] {
+			let inputs = (0..n_inputs)
+				.map(|_| array::from_fn(|_| GroestlDigest::<BinaryField8b>::random(&mut rng)))
+				.collect::<Vec<_>>();
+
+			let expected = inputs
+				.iter()
+				.map(|&input| GroestlDigestCompression::<BinaryField8b>::default().compress(input))
+				.collect::<Vec<_>>();
+			let outputs =
+				BsGroestlDigestCompression::<BinaryField8b>::default().compress_many(&inputs);
+			assert_eq!(outputs, expected);
+		}
+
+		let input = [
+			GroestlDigest::<AESTowerField8b>::random(&mut rng),
+			GroestlDigest::<AESTowerField8b>::random(&mut rng),
+		];
+		assert_eq!(
+			BsGroestlDigestCompression::<AESTowerField8b>::default().compress_many(&[input]),
+			vec![GroestlDigestCompression::<AESTowerField8b>::default().compress(input)]
+		);
+	}
+}
@@ -0,0 +1,202 @@
+// Copyright 2024 Irreducible Inc.
+
+//! Bitsliced Grøstl hashing on top of the pure Rust bitsliced permutations.
+
+use super::ByteRepr;
+use crate::groestl::arch::{
+	bitsliced::{broadcast, transform_bytes, transpose_in, transpose_out, xor_state},
+	BitslicedState, BitslicedState1024, Groestl256BitslicedCore, Groestl512BitslicedCore,
+	BITSLICED_LANES,
+};
+use std::{cmp, collections::BTreeMap};
+
+/// The number of messages hashed in parallel
+pub(super) const LANES: usize = BITSLICED_LANES;
+/// Messages of any length are padded by the hash itself
+#[cfg(not(feature = "bs-groestl-c"))]
+pub(super) const MSG_ALIGNMENT: usize = 1;
+#[cfg(not(feature = "bs-groestl-c"))]
+pub(super) const ACCEPTS_EMPTY_MESSAGES: bool = true;
+
+pub(super) const BLOCK_LEN_U8: usize = 64;
+
+/// The bitsliced P and Q permutations of the Grøstl variants with an `N`-byte state
+pub(super) trait BitslicedCore<const N: usize> {
+	fn permutation_pq(&self, p: &mut [[u64; 8]; N], q: &mut [[u64; 8]; N]);
+	fn permutation_p(&self, p: &mut [[u64; 8]; N]);
+}
+
+impl BitslicedCore<BLOCK_LEN_U8> for Groestl256BitslicedCore {
+	fn permutation_pq(&self, p: &mut BitslicedState, q: &mut BitslicedState) {
+		self.permutation_pq(p, q)
+	}
+
+	fn permutation_p(&self, p: &mut BitslicedState) {
+		self.permutation_p(p)
+	}
+}
+
+impl BitslicedCore<{ 2 * BLOCK_LEN_U8 }> for Groestl512BitslicedCore {
+	fn permutation_pq(&self, p: &mut BitslicedState1024, q: &mut BitslicedState1024) {
+		self.permutation_pq(p, q)
+	}
+
+	fn permutation_p(&self, p: &mut BitslicedState1024) {
+		self.permutation_p(p)
+	}
+}
+
+/// The IV of the Grøstl variant with `N`-byte state and `digest_bytes`-byte output, which encodes
+/// the output length in bits in its last two bytes
+pub(super) const fn iv<const N: usize>(digest_bytes: usize) -> [u8; N] {
+	let mut iv = [0u8; N];
+	let [hi, lo] = ((8 * digest_bytes) as u16).to_be_bytes();
+	iv[N - 2] = hi;
+	iv[N - 1] = lo;
+	iv
+}
+
+/// Messages are hashed together when their padded forms have the same number of blocks
+#[cfg(not(feature = "bs-groestl-c"))]
+pub(super) fn group_key(msg_len: usize) -> usize {
+	num_padded_blocks_of(msg_len, BLOCK_LEN_U8)
+}
+
+/// Hashes up to `LANES` messages sharing the same [`group_key`] and returns the concatenated
+/// digests of all lanes, including the padding ones.
+///
+/// Every lane is padded according to the length of its own message, so the messages may have
+/// different lengths as long as they span the same number of blocks. Message bytes encoded in
+/// another representation than AES are converted after transposition, eight bits of all lanes
+/// at a time.
+#[cfg(not(feature = "bs-groestl-c"))]
+pub(super) fn hash_group(group: &[&[u8]], repr: ByteRepr) -> Vec<u8> {
+	hash_group_with(&Groestl256BitslicedCore, group, repr, super::DIGEST_BYTES)
+}
+
+/// Hashes up to `LANES` messages spanning the same number of `N`-byte blocks with the Grøstl
+/// variant of `N`-byte state and `digest_bytes`-byte output, see [`hash_group`].
+pub(super) fn hash_group_with<const N: usize>(
+	core: &impl BitslicedCore<N>,
+	group: &[&[u8]],
+	repr: ByteRepr,
+	digest_bytes: usize,
+) -> Vec<u8> {
+	assert!(group.len() <= LANES);
+	let Some(first) = group.first() else {
+		return Vec::new();
+	};
+	let num_blocks = num_padded_blocks_of(first.len(), N);
+	debug_assert!(group
+		.iter()
+		.all(|msg| num_padded_blocks_of(msg.len(), N) == num_blocks));
+
+	let to_aes_columns = repr.to_aes_columns();
+	let mut state = broadcast(&iv::<N>(digest_bytes));
+	let mut blocks = [[0u8; N]; LANES];
+	for block_idx in 0..num_blocks {
+		for (block, msg) in blocks.iter_mut().zip(group) {
+			*block = padded_block(msg, block_idx, num_blocks, repr);
+		}
+		let mut m = transpose_in(&blocks);
+		if repr != ByteRepr::Aes {
+			transform_bytes(&mut m, &to_aes_columns);
+		}
+		compress_with(core, &mut state, &m);
+	}
+
+	output_transform_with(core, &state)
+		.iter()
+		.flat_map(|lane| lane[N - digest_bytes..].iter().copied())
+		.collect()
+}
+
+/// Hashes messages of any length with the Grøstl variant of `N`-byte state and `digest_bytes`-byte
+/// output and passes the digest of every message to `write` along with its index.
+pub(super) fn hash_grouped_with<const N: usize>(
+	core: &impl BitslicedCore<N>,
+	messages: &[&[u8]],
+	digest_bytes: usize,
+	mut write: impl FnMut(usize, &[u8]),
+) {
+	let mut indices_by_blocks = BTreeMap::<usize, Vec<usize>>::new();
+	for (i, msg) in messages.iter().enumerate() {
+		indices_by_blocks
+			.entry(num_padded_blocks_of(msg.len(), N))
+			.or_default()
+			.push(i);
+	}
+
+	for indices in indices_by_blocks.into_values() {
+		for chunk in indices.chunks(LANES) {
+			let group = chunk.iter().map(|&i| messages[i]).collect::<Vec<_>>();
+			let digest_bytes_all = hash_group_with(core, &group, ByteRepr::Aes, digest_bytes);
+			for (&i, digest) in chunk
+				.iter()
+				.zip(digest_bytes_all.chunks_exact(digest_bytes))
+			{
+				write(i, digest);
+			}
+		}
+	}
+}
+
+/// The number of `block_len`-byte blocks of the padded message
+pub(super) fn num_padded_blocks_of(msg_len: usize, block_len: usize) -> usize {
+	// At least one byte of 0x80 and 8 bytes of block count are appended
+	(msg_len + 9).div_ceil(block_len)
+}
+
+/// Returns the block `block_idx` of the message padded as per the Grøstl specification, with the
+/// padding bytes encoded in `repr` like the message bytes
+fn padded_block<const N: usize>(
+	msg: &[u8],
+	block_idx: usize,
+	num_blocks: usize,
+	repr: ByteRepr,
+) -> [u8; N] {
+	let mut block = [0u8; N];
+	let start = block_idx * N;
+	let end = cmp::min(start + N, msg.len());
+	if start < end {
+		block[..end - start].copy_from_slice(&msg[start..end]);
+	}
+	if (start..start + N).contains(&msg.len()) {
+		block[msg.len() - start] = repr.encode(0x80);
+	}
+	if block_idx == num_blocks - 1 {
+		for (dst, src) in block[N - 8..]
+			.iter_mut()
+			.zip((num_blocks as u64).to_be_bytes())
+		{
+			*dst = repr.encode(src);
+		}
+	}
+	block
+}
+
+/// Compression function `P(h + m) + Q(m) + h` of the Grøstl variant of `core`, applied to every
+/// lane
+pub(super) fn compress_with<const N: usize>(
+	core: &impl BitslicedCore<N>,
+	h: &mut [[u64; 8]; N],
+	m: &[[u64; 8]; N],
+) {
+	let mut p = *h;
+	xor_state(&mut p, m);
+	let mut q = *m;
+	core.permutation_pq(&mut p, &mut q);
+	xor_state(h, &p);
+	xor_state(h, &q);
+}
+
+/// The output transformation of the Grøstl variant of `core`, before truncation, of every lane
+pub(super) fn output_transform_with<const N: usize>(
+	core: &impl BitslicedCore<N>,
+	h: &[[u64; 8]; N],
+) -> [[u8; N]; LANES] {
+	let mut out = *h;
+	core.permutation_p(&mut out);
+	xor_state(&mut out, h);
+	transpose_out(&out)
+}
@@ -0,0 +1,138 @@
+// Copyright 2024 Irreducible Inc.
+
+//! Bitsliced Grøstl-224 and Grøstl-512 for batches of independent messages.
+//!
+//! Both are hashed by the pure Rust kernel, also when the `bs-groestl-c` feature is enabled, since
+//! the C kernel only implements Grøstl-256.
+
+use super::native;
+use crate::groestl::{
+	arch::{Groestl256BitslicedCore, Groestl512BitslicedCore},
+	Groestl224Digest, Groestl512Digest,
+};
+use binius_field::{
+	arch::OptimalUnderlier512b, as_packed_field::PackScalar, underlier::Divisible, AESTowerField8b,
+	BinaryField, PackedField,
+};
+use std::{array, marker::PhantomData};
+
+const GROESTL224_DIGEST_BYTES: usize = 28;
+const GROESTL512_DIGEST_BYTES: usize = 64;
+
+/// Bitsliced Grøstl-224 hasher for batches of independent messages.
+///
+/// The digests are byte-identical to those of [`Groestl224`](crate::Groestl224) over
+/// `AESTowerField8b`. When `F` is `BinaryField8b`, the digest bytes are mapped through the
+/// AES-to-binary isomorphism.
+#[derive(Debug, Default, Clone)]
+pub struct BsGroestl224<F = AESTowerField8b> {
+	_f_marker: PhantomData<F>,
+}
+
+impl<F> BsGroestl224<F>
+where
+	F: BinaryField + From<AESTowerField8b>,
+{
+	/// Hashes every message in `messages` and returns the digests in the same order.
+	///
+	/// Messages may have any length, they are grouped internally by their number of blocks.
+	pub fn hash_many(messages: &[&[u8]]) -> Vec<Groestl224Digest<F>> {
+		let mut digests = vec![[F::ZERO; GROESTL224_DIGEST_BYTES]; messages.len()];
+		native::hash_grouped_with(
+			&Groestl256BitslicedCore,
+			messages,
+			GROESTL224_DIGEST_BYTES,
+			|i, digest| {
+				digests[i] = array::from_fn(|j| F::from(AESTowerField8b::new(digest[j])));
+			},
+		);
+		digests
+	}
+}
+
+/// Bitsliced Grøstl-512 hasher for batches of independent messages.
+///
+/// The digests are byte-identical to those of [`Groestl512`](crate::Groestl512) over
+/// `AESTowerField8b`. When `F` is `BinaryField8b`, the digest bytes are mapped through the
+/// AES-to-binary isomorphism.
+#[derive(Debug, Default, Clone)]
+pub struct BsGroestl512<F = AESTowerField8b> {
+	_f_marker: PhantomData<F>,
+}
+
+impl<F> BsGroestl512<F>
+where
+	F: BinaryField + From<AESTowerField8b>,
+	OptimalUnderlier512b: PackScalar<F> + Divisible<F::Underlier>,
+{
+	/// Hashes every message in `messages` and returns the digests in the same order.
+	///
+	/// Messages may have any length, they are grouped internally by their number of blocks.
+	pub fn hash_many(messages: &[&[u8]]) -> Vec<Groestl512Digest<F>> {
+		let mut digests = vec![Groestl512Digest::<F>::default(); messages.len()];
+		native::hash_grouped_with(
+			&Groestl512BitslicedCore,
+			messages,
+			GROESTL512_DIGEST_BYTES,
+			|i, digest| {
+				digests[i] =
+					Groestl512Digest::<F>::from_fn(|j| F::from(AESTowerField8b::new(digest[j])));
+			},
+		);
+		digests
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::groestl::arch::BITSLICED_LANES;
+	use binius_field::BinaryField8b;
+	use groestl_crypto::Digest;
+	use rand::{thread_rng, RngCore};
+
+	fn random_messages() -> Vec<Vec<u8>> {
+		let mut rng = thread_rng();
+		// Lengths around the padding boundaries of both block sizes, more than one group each
+		[0, 1, 54, 55, 56, 64, 118, 119, 120, 128, 247, 300]
+			.into_iter()
+			.cycle()
+			.take(2 * BITSLICED_LANES + 5)
+			.map(|len| {
+				let mut msg = vec![0u8; len];
+				rng.fill_bytes(&mut msg);
+				msg
+			})
+			.collect()
+	}
+
+	#[test]
+	fn test_bs_groestl224_matches_groestl_crypto() {
+		let messages = random_messages();
+		let messages = messages.iter().map(Vec::as_slice).collect::<Vec<_>>();
+
+		let digests = BsGroestl224::<AESTowerField8b>::hash_many(&messages);
+		let digests_bin = BsGroestl224::<BinaryField8b>::hash_many(&messages);
+		for ((digest, digest_bin), msg) in digests.iter().zip(&digests_bin).zip(&messages) {
+			let expected = groestl_crypto::Groestl224::digest(msg);
+			assert!(digest.iter().zip(expected).all(|(a, b)| a.val() == b));
+			assert!(digest_bin
+				.iter()
+				.zip(digest)
+				.all(|(&a, &b)| a == BinaryField8b::from(b)));
+		}
+	}
+
+	#[test]
+	fn test_bs_groestl512_matches_groestl_crypto() {
+		let messages = random_messages();
+		let messages = messages.iter().map(Vec::as_slice).collect::<Vec<_>>();
+
+		assert!(BsGroestl512::<AESTowerField8b>::hash_many(&[]).is_empty());
+		let digests = BsGroestl512::<AESTowerField8b>::hash_many(&messages);
+		for (digest, msg) in digests.iter().zip(&messages) {
+			let expected = groestl_crypto::Groestl512::digest(msg);
+			assert!(digest.iter().zip(expected).all(|(a, b)| a.val() == b));
+		}
+	}
+}
@@ -1,8 +1,16 @@
 // Copyright 2024 Irreducible Inc.
 
 //! This module implements the 256-bit variant of [Grøstl](https://www.groestl.info/Groestl.pdf)
-
-use super::{super::hasher::Hasher, arch::Groestl256Core};
+//! and its truncation to 224 bits
+
+use super::{
+	super::{
+		algorithm_id::{FieldTowerId, HashAlgorithmId},
+		compression::MultiPseudoCompressionFunction,
+		hasher::{Hasher, MultiHasher},
+	},
+	arch::{Groestl256Core, Groestl256Permutations},
+};
 use binius_field::{
 	arch::OptimalUnderlier256b,
 	as_packed_field::{PackScalar, PackedType},
@@ -12,15 +20,20 @@ use binius_field::{
 	PackedFieldIndexable, TowerField,
 };
 use p3_symmetric::{CompressionFunction, PseudoCompressionFunction};
-use std::{cmp, marker::PhantomData, mem::MaybeUninit, slice};
+use std::{array, cmp, marker::PhantomData, mem::MaybeUninit, slice};
 
 /// The type of output digest for `Grøstl256` over `F` which should be isomorphic to `AESTowerField8b`
 pub type GroestlDigest<F> = PackedType<OptimalUnderlier256b, F>;
 
+/// The type of output digest for `Grøstl224` over `F`
+pub type Groestl224Digest<F> = [F; GROESTL224_DIGEST_LEN];
+
 /// An alias for `Grøstl256` defined over `BinaryField8b`
 pub type GroestlHasher<P> = Groestl256<P, BinaryField8b>;
 
 const BLOCK_LEN_U8: usize = 64;
+const GROESTL256_DIGEST_LEN: usize = 32;
+const GROESTL224_DIGEST_LEN: usize = 28;
 
 /// The Grøstl-256 hash function.
 ///
@@ -29,6 +42,8 @@ const BLOCK_LEN_U8: usize = 64;
 /// function which has to be over a packed extension field of `BinaryField8b` or `AESTowerField8b`.
 #[derive(Debug, Clone)]
 pub struct Groestl256<P, F> {
+	// The permutations of the backend selected at runtime, resolved once per hasher
+	core: Groestl256Permutations,
 	state: PackedAESBinaryField64x8b,
 	current_block: PackedAESBinaryField64x8b,
 	current_len: u64,
@@ -53,7 +68,7 @@ impl<P> UpdateOverSlice for Groestl256<P, BinaryField8b> {
 				let block_idx = (cur_block + i) % BLOCK_LEN_U8;
 				self.current_block.set(block_idx, x);
 				if block_idx == BLOCK_LEN_U8 - 1 {
-					self.state = compression_func(self.state, self.current_block);
+					self.state = compression_func(&self.core, self.state, self.current_block);
 				}
 			});
 	}
@@ -80,7 +95,7 @@ impl<P, F> Groestl256<P, F> {
 
 			// absorb if ready
 			if cur_block + to_process == BLOCK_LEN_U8 {
-				self.state = compression_func(self.state, self.current_block);
+				self.state = compression_func(&self.core, self.state, self.current_block);
 				cur_block = 0;
 			}
 
@@ -89,12 +104,16 @@ impl<P, F> Groestl256<P, F> {
 	}
 }
 
-impl<P, F> Default for Groestl256<P, F> {
-	fn default() -> Self {
+impl<P, F> Groestl256<P, F> {
+	/// The initial state of the Grøstl variants on the 512-bit state, which encodes the output
+	/// length in bits in the last two bytes
+	fn with_digest_len(digest_len: usize) -> Self {
 		let mut iv = PackedAESBinaryField64x8b::default();
-		// IV for Grøstl256
-		iv.set(62, AESTowerField8b::new(0x01));
+		let [hi, lo] = ((8 * digest_len) as u16).to_be_bytes();
+		iv.set(BLOCK_LEN_U8 - 2, AESTowerField8b::new(hi));
+		iv.set(BLOCK_LEN_U8 - 1, AESTowerField8b::new(lo));
 		Self {
+			core: Groestl256Core.resolve(),
 			state: iv,
 			current_block: PackedAESBinaryField64x8b::default(),
 			current_len: 0,
@@ -104,12 +123,19 @@ impl<P, F> Default for Groestl256<P, F> {
 	}
 }
 
+impl<P, F> Default for Groestl256<P, F> {
+	fn default() -> Self {
+		Self::with_digest_len(GROESTL256_DIGEST_LEN)
+	}
+}
+
 /// Compression function as defined for Grøstl256
-fn compression_func(
+pub(super) fn compression_func(
+	core: &Groestl256Permutations,
 	h: PackedAESBinaryField64x8b,
 	m: PackedAESBinaryField64x8b,
 ) -> PackedAESBinaryField64x8b {
-	let (a, b) = Groestl256Core.permutation_pq(h + m, m);
+	let (a, b) = core.permutation_pq(h + m, m);
 	a + b + h
 }
 
@@ -119,7 +145,8 @@ where
 	P: PackedExtension<F, PackedSubfield: PackedFieldIndexable>,
 	P::Scalar: ExtensionField<F>,
 {
-	fn finalize_packed(&mut self) -> PackedAESBinaryField32x8b {
+	/// Pads the message and returns the output transformation `P(h) + h` before truncation
+	fn finalize_state(&mut self) -> PackedAESBinaryField64x8b {
 		let bits_per_elem = P::WIDTH * P::Scalar::DEGREE * (1 << BinaryField8b::TOWER_LEVEL);
 		let n = self
 			.current_len
@@ -138,7 +165,11 @@ where
 		let cur_block = (self.current_len as usize * P::WIDTH * P::Scalar::DEGREE) % BLOCK_LEN_U8;
 		self.update_native(&padding[..zero_pads + 9], cur_block);
 
-		let out_full = Groestl256Core.permutation_p(self.state) + self.state;
+		self.core.permutation_p(self.state) + self.state
+	}
+
+	fn finalize_packed(&mut self) -> PackedAESBinaryField32x8b {
+		let out_full = self.finalize_state();
 		let mut out = [PackedAESBinaryField32x8b::default()];
 		let out_as_slice = PackedFieldIndexable::unpack_scalars_mut(&mut out);
 		out_as_slice.copy_from_slice(&PackedFieldIndexable::unpack_scalars(&[out_full])[32..]);
@@ -211,6 +242,111 @@ where
 	}
 }
 
+impl<P, F> MultiHasher<P> for Groestl256<P, F>
+where
+	F: BinaryField + From<AESTowerField8b> + Into<AESTowerField8b>,
+	P: PackedExtension<F, PackedSubfield: PackedFieldIndexable>,
+	P::Scalar: ExtensionField<F>,
+	OptimalUnderlier256b: PackScalar<F> + Divisible<F::Underlier>,
+	Self: UpdateOverSlice<Elem = F>,
+{
+}
+
+impl<P, F: FieldTowerId> HashAlgorithmId for Groestl256<P, F> {
+	const NAME: &'static str = "groestl256";
+	const VERSION: u16 = 1;
+	const TOWER: &'static str = F::TOWER;
+	const DIGEST_BYTES: usize = GROESTL256_DIGEST_LEN;
+}
+
+/// The Grøstl-224 hash function.
+///
+/// Grøstl-224 shares the permutations, compression function and padding of [`Groestl256`] and
+/// only differs in its IV and in the truncation of the output to the last 28 bytes.
+#[derive(Debug, Clone)]
+pub struct Groestl224<P, F>(Groestl256<P, F>);
+
+impl<P, F> Default for Groestl224<P, F> {
+	fn default() -> Self {
+		Self(Groestl256::with_digest_len(GROESTL224_DIGEST_LEN))
+	}
+}
+
+impl<P, F> Groestl224<P, F>
+where
+	F: BinaryField + From<AESTowerField8b>,
+	P: PackedExtension<F, PackedSubfield: PackedFieldIndexable>,
+	P::Scalar: ExtensionField<F>,
+{
+	fn finalize_digest(&mut self) -> Groestl224Digest<F> {
+		let out_full = self.0.finalize_state();
+		array::from_fn(|i| F::from(out_full.get(BLOCK_LEN_U8 - GROESTL224_DIGEST_LEN + i)))
+	}
+}
+
+impl<P, F> Hasher<P> for Groestl224<P, F>
+where
+	F: BinaryField + From<AESTowerField8b>,
+	P: PackedExtension<F, PackedSubfield: PackedFieldIndexable>,
+	P::Scalar: ExtensionField<F>,
+	Groestl256<P, F>: Hasher<P>,
+{
+	type Digest = Groestl224Digest<F>;
+
+	fn new() -> Self {
+		Self::default()
+	}
+
+	fn update(&mut self, data: impl AsRef<[P]>) {
+		self.0.update(data);
+	}
+
+	fn chain_update(mut self, data: impl AsRef<[P]>) -> Self {
+		self.update(data);
+		self
+	}
+
+	fn finalize(mut self) -> Self::Digest {
+		self.finalize_digest()
+	}
+
+	fn finalize_into(self, out: &mut MaybeUninit<Self::Digest>) {
+		let finalized = self.finalize();
+		out.write(finalized);
+	}
+
+	fn finalize_reset(&mut self) -> Self::Digest {
+		let out = self.finalize_digest();
+		self.reset();
+		out
+	}
+
+	fn finalize_into_reset(&mut self, out: &mut MaybeUninit<Self::Digest>) {
+		out.write(self.finalize_digest());
+		self.reset();
+	}
+
+	fn reset(&mut self) {
+		*self = Self::new();
+	}
+}
+
+impl<P, F> MultiHasher<P> for Groestl224<P, F>
+where
+	F: BinaryField + From<AESTowerField8b>,
+	P: PackedExtension<F, PackedSubfield: PackedFieldIndexable>,
+	P::Scalar: ExtensionField<F>,
+	Groestl256<P, F>: Hasher<P>,
+{
+}
+
+impl<P, F: FieldTowerId> HashAlgorithmId for Groestl224<P, F> {
+	const NAME: &'static str = "groestl224";
+	const VERSION: u16 = 1;
+	const TOWER: &'static str = F::TOWER;
+	const DIGEST_BYTES: usize = GROESTL224_DIGEST_LEN;
+}
+
 /// A compression function for Grøstl hash digests based on the Grøstl output transformation.
 ///
 /// This is a 512-bit to 256-bit compression function. This does _not_ apply the full Grøstl hash
@@ -267,6 +403,23 @@ where
 {
 }
 
+impl<F> MultiPseudoCompressionFunction<GroestlDigest<F>, 2> for GroestlDigestCompression<F>
+where
+	OptimalUnderlier256b: PackScalar<F> + Divisible<F::Underlier>,
+	F: BinaryField + From<AESTowerField8b> + Into<AESTowerField8b>,
+{
+}
+
+impl<F> HashAlgorithmId for GroestlDigestCompression<F>
+where
+	F: BinaryField + From<AESTowerField8b> + Into<AESTowerField8b> + FieldTowerId,
+{
+	const NAME: &'static str = "groestl256-output-transform";
+	const VERSION: u16 = 1;
+	const TOWER: &'static str = F::TOWER;
+	const DIGEST_BYTES: usize = GROESTL256_DIGEST_LEN;
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -275,8 +428,9 @@ mod tests {
 		linear_transformation::Transformation, make_aes_to_binary_packed_transformer,
 		PackedBinaryField32x8b, PackedBinaryField64x8b,
 	};
-	use rand::thread_rng;
-	use std::array;
+	use groestl_crypto::Digest;
+	use hex_literal::hex;
+	use rand::{thread_rng, RngCore};
 
 	#[test]
 	fn test_groestl_digest_compression() {
@@ -295,6 +449,40 @@ mod tests {
 		}
 	}
 
+	#[test]
+	fn test_groestl224_matches_groestl_crypto() {
+		let mut rng = thread_rng();
+		for len in [0, 1, 27, 55, 56, 64, 119, 120, 200] {
+			let mut msg = vec![0u8; len];
+			rng.fill_bytes(&mut msg);
+			let msg_aes = msg
+				.iter()
+				.map(|&b| AESTowerField8b::new(b))
+				.collect::<Vec<_>>();
+
+			let digest = HasherDigest::<_, Groestl224<_, AESTowerField8b>>::hash(&msg_aes);
+			let expected = groestl_crypto::Groestl224::digest(&msg);
+			assert_eq!(digest.map(|x| x.val()).as_slice(), expected.as_slice());
+		}
+	}
+
+	#[test]
+	fn test_groestl224_known_answers() {
+		let hash = |msg: &[u8]| {
+			let msg_aes = msg
+				.iter()
+				.map(|&b| AESTowerField8b::new(b))
+				.collect::<Vec<_>>();
+			HasherDigest::<_, Groestl224<_, AESTowerField8b>>::hash(&msg_aes).map(|x| x.val())
+		};
+
+		// The empty message, from the Grøstl submission package
+		assert_eq!(hash(b""), hex!("f2e180fb5947be964cd584e22e496242c6a329c577fc4ce8c36d34c3"));
+		// Four blocks, from the reference implementation in `testgen/hash/groestl.py`
+		let msg = (0..200).collect::<Vec<u8>>();
+		assert_eq!(hash(&msg), hex!("6d702bcd0f0edcc2ee9494688bc63310ddd95ffaf77d86512f7b874e"));
+	}
+
 	#[test]
 	fn test_aes_binary_convertion() {
 		let mut rng = thread_rng();
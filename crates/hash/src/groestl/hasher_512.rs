@@ -0,0 +1,356 @@
+// Copyright 2024 Irreducible Inc.
+
+//! This module implements the 512-bit variant of [Grøstl](https://www.groestl.info/Groestl.pdf),
+//! which uses the permutations on the 1024-bit state
+
+use super::{
+	super::{
+		algorithm_id::{FieldTowerId, HashAlgorithmId},
+		compression::MultiPseudoCompressionFunction,
+		hasher::{Hasher, MultiHasher},
+	},
+	arch::Groestl512TableCore,
+};
+use binius_field::{
+	arch::OptimalUnderlier512b,
+	as_packed_field::{PackScalar, PackedType},
+	underlier::Divisible,
+	AESTowerField8b, BinaryField, ExtensionField, PackedAESBinaryField64x8b, PackedExtension,
+	PackedField, PackedFieldIndexable,
+};
+use p3_symmetric::{CompressionFunction, PseudoCompressionFunction};
+use std::{array, cmp, marker::PhantomData, mem::MaybeUninit};
+
+/// The type of output digest for `Grøstl512` over `F` which should be isomorphic to `AESTowerField8b`
+pub type Groestl512Digest<F> = PackedType<OptimalUnderlier512b, F>;
+
+/// The 1024-bit state of Grøstl512, split in two halves holding the columns `0..8` and `8..16`
+type State = [PackedAESBinaryField64x8b; 2];
+
+const BLOCK_LEN_U8: usize = 128;
+const DIGEST_LEN_U8: usize = 64;
+
+/// The Grøstl-512 hash function.
+///
+/// Like [`Groestl256`](super::Groestl256), the hash function is natively defined over
+/// `AESTowerField8b` and isomorphically maps to `BinaryField8b`. The type `P` is the input to the
+/// update function which has to be over a packed extension field of `BinaryField8b` or
+/// `AESTowerField8b`.
+#[derive(Debug, Clone)]
+pub struct Groestl512<P, F> {
+	state: State,
+	current_block: State,
+	current_len: u64,
+	_p_marker: PhantomData<P>,
+	_f_marker: PhantomData<F>,
+}
+
+impl<P, F> Groestl512<P, F> {
+	fn update_native(&mut self, mut msg: &[AESTowerField8b], mut cur_block: usize) {
+		while !msg.is_empty() {
+			let to_process = cmp::min(BLOCK_LEN_U8 - cur_block, msg.len());
+
+			// Firstly copy data into next block
+			let next_block = PackedAESBinaryField64x8b::unpack_scalars_mut(&mut self.current_block);
+			next_block[cur_block..cur_block + to_process].copy_from_slice(&msg[..to_process]);
+
+			// absorb if ready
+			if cur_block + to_process == BLOCK_LEN_U8 {
+				self.state = compression_func(self.state, self.current_block);
+				cur_block = 0;
+			}
+
+			msg = &msg[to_process..];
+		}
+	}
+}
+
+impl<P, F> Default for Groestl512<P, F> {
+	fn default() -> Self {
+		let mut iv = State::default();
+		// IV for Grøstl512
+		iv[1].set(62, AESTowerField8b::new(0x02));
+		Self {
+			state: iv,
+			current_block: State::default(),
+			current_len: 0,
+			_p_marker: PhantomData,
+			_f_marker: PhantomData,
+		}
+	}
+}
+
+/// Compression function as defined for Grøstl512
+fn compression_func(h: State, m: State) -> State {
+	let (a, b) = Groestl512TableCore.permutation_pq(array::from_fn(|i| h[i] + m[i]), m);
+	array::from_fn(|i| a[i] + b[i] + h[i])
+}
+
+/// The output transformation `P(x) + x` of Grøstl512 truncated to the last 64 bytes, which is
+/// exactly the second half of the state
+fn output_transform(x: State) -> PackedAESBinaryField64x8b {
+	Groestl512TableCore.permutation_p(x)[1] + x[1]
+}
+
+impl<P, F> Groestl512<P, F>
+where
+	F: BinaryField + Into<AESTowerField8b>,
+	P: PackedExtension<F, PackedSubfield: PackedFieldIndexable>,
+	P::Scalar: ExtensionField<F>,
+{
+	fn cur_block(&self) -> usize {
+		(self.current_len as usize * P::WIDTH * P::Scalar::DEGREE) % BLOCK_LEN_U8
+	}
+
+	fn finalize_packed(&mut self) -> PackedAESBinaryField64x8b {
+		let len = self
+			.current_len
+			.checked_mul((P::WIDTH * P::Scalar::DEGREE) as u64)
+			.expect("Overflow on message length");
+		// At least one byte of 0x80 and 8 bytes of block count are appended
+		let num_blocks = (len + 9).div_ceil(BLOCK_LEN_U8 as u64);
+		let padding_len = (num_blocks * BLOCK_LEN_U8 as u64 - len) as usize;
+		// Enough for 2 blocks
+		let mut padding = [AESTowerField8b::default(); 2 * BLOCK_LEN_U8];
+		padding[0] = AESTowerField8b::new(0x80);
+		padding[padding_len - 8..padding_len]
+			.copy_from_slice(&num_blocks.to_be_bytes().map(AESTowerField8b::new));
+
+		let cur_block = self.cur_block();
+		self.update_native(&padding[..padding_len], cur_block);
+
+		output_transform(self.state)
+	}
+}
+
+impl<P, F> Hasher<P> for Groestl512<P, F>
+where
+	F: BinaryField + From<AESTowerField8b> + Into<AESTowerField8b>,
+	P: PackedExtension<F, PackedSubfield: PackedFieldIndexable>,
+	P::Scalar: ExtensionField<F>,
+	OptimalUnderlier512b: PackScalar<F> + Divisible<F::Underlier>,
+{
+	type Digest = Groestl512Digest<F>;
+
+	fn new() -> Self {
+		Self::default()
+	}
+
+	fn update(&mut self, data: impl AsRef<[P]>) {
+		let msg = data.as_ref();
+		if msg.is_empty() {
+			return;
+		}
+
+		let mut cur_block = self.cur_block();
+		// Convert into the AES representation a block at a time to avoid allocating
+		let mut block = [AESTowerField8b::default(); BLOCK_LEN_U8];
+		for chunk in P::unpack_base_scalars(msg).chunks(BLOCK_LEN_U8) {
+			for (dst, &src) in block.iter_mut().zip(chunk) {
+				*dst = src.into();
+			}
+			self.update_native(&block[..chunk.len()], cur_block);
+			cur_block = (cur_block + chunk.len()) % BLOCK_LEN_U8;
+		}
+
+		self.current_len = self
+			.current_len
+			.checked_add(msg.len() as u64)
+			.expect("Overflow on message length");
+	}
+
+	fn chain_update(mut self, data: impl AsRef<[P]>) -> Self {
+		self.update(data);
+		self
+	}
+
+	fn finalize(mut self) -> Self::Digest {
+		let out = self.finalize_packed();
+		Self::Digest::from_fn(|i| F::from(out.get(i)))
+	}
+
+	fn finalize_into(self, out: &mut MaybeUninit<Self::Digest>) {
+		let finalized = self.finalize();
+		out.write(finalized);
+	}
+
+	fn finalize_reset(&mut self) -> Self::Digest {
+		let out_native = self.finalize_packed();
+		let out = Self::Digest::from_fn(|i| F::from(out_native.get(i)));
+		self.reset();
+		out
+	}
+
+	fn finalize_into_reset(&mut self, out: &mut MaybeUninit<Self::Digest>) {
+		let finalized = self.finalize_packed();
+		out.write(Self::Digest::from_fn(|i| F::from(finalized.get(i))));
+		self.reset();
+	}
+
+	fn reset(&mut self) {
+		*self = Self::new();
+	}
+}
+
+impl<P, F> MultiHasher<P> for Groestl512<P, F>
+where
+	F: BinaryField + From<AESTowerField8b> + Into<AESTowerField8b>,
+	P: PackedExtension<F, PackedSubfield: PackedFieldIndexable>,
+	P::Scalar: ExtensionField<F>,
+	OptimalUnderlier512b: PackScalar<F> + Divisible<F::Underlier>,
+{
+}
+
+impl<P, F: FieldTowerId> HashAlgorithmId for Groestl512<P, F> {
+	const NAME: &'static str = "groestl512";
+	const VERSION: u16 = 1;
+	const TOWER: &'static str = F::TOWER;
+	const DIGEST_BYTES: usize = DIGEST_LEN_U8;
+}
+
+/// A 1024-bit to 512-bit compression function for Grøstl512 digests based on the Grøstl512
+/// output transformation.
+///
+/// This is the counterpart of [`GroestlDigestCompression`](super::GroestlDigestCompression) on
+/// the 1024-bit state, see there for the security justification.
+#[derive(Debug, Default, Clone)]
+pub struct Groestl512DigestCompression<
+	F: BinaryField + From<AESTowerField8b> + Into<AESTowerField8b>,
+> {
+	_f_marker: PhantomData<F>,
+}
+
+impl<F> PseudoCompressionFunction<Groestl512Digest<F>, 2> for Groestl512DigestCompression<F>
+where
+	OptimalUnderlier512b: PackScalar<F> + Divisible<F::Underlier>,
+	F: BinaryField + From<AESTowerField8b> + Into<AESTowerField8b>,
+{
+	fn compress(&self, input: [Groestl512Digest<F>; 2]) -> Groestl512Digest<F> {
+		let input_as_slice = PackedFieldIndexable::unpack_scalars(&input);
+		let state: State = array::from_fn(|half| {
+			PackedAESBinaryField64x8b::from_fn(|i| input_as_slice[half * DIGEST_LEN_U8 + i].into())
+		});
+		let out = output_transform(state);
+		Groestl512Digest::<F>::from_fn(|i| F::from(out.get(i)))
+	}
+}
+
+impl<F> CompressionFunction<Groestl512Digest<F>, 2> for Groestl512DigestCompression<F>
+where
+	OptimalUnderlier512b: PackScalar<F> + Divisible<F::Underlier>,
+	F: BinaryField + From<AESTowerField8b> + Into<AESTowerField8b>,
+{
+}
+
+impl<F> MultiPseudoCompressionFunction<Groestl512Digest<F>, 2> for Groestl512DigestCompression<F>
+where
+	OptimalUnderlier512b: PackScalar<F> + Divisible<F::Underlier>,
+	F: BinaryField + From<AESTowerField8b> + Into<AESTowerField8b>,
+{
+}
+
+impl<F> HashAlgorithmId for Groestl512DigestCompression<F>
+where
+	F: BinaryField + From<AESTowerField8b> + Into<AESTowerField8b> + FieldTowerId,
+{
+	const NAME: &'static str = "groestl512-output-transform";
+	const VERSION: u16 = 1;
+	const TOWER: &'static str = F::TOWER;
+	const DIGEST_BYTES: usize = DIGEST_LEN_U8;
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{HashDigest, HasherDigest};
+	use binius_field::{BinaryField8b, PackedAESBinaryField32x8b, PackedBinaryField32x8b};
+	use groestl_crypto::Digest;
+	use hex_literal::hex;
+	use rand::{thread_rng, RngCore};
+
+	#[test]
+	fn test_groestl512_matches_groestl_crypto() {
+		let mut rng = thread_rng();
+		for len in [0, 1, 64, 118, 119, 120, 128, 247, 248, 300] {
+			let mut msg = vec![0u8; len];
+			rng.fill_bytes(&mut msg);
+			let msg_aes = msg
+				.iter()
+				.map(|&b| AESTowerField8b::new(b))
+				.collect::<Vec<_>>();
+
+			let digest = HasherDigest::<_, Groestl512<_, AESTowerField8b>>::hash(&msg_aes);
+			let expected = groestl_crypto::Groestl512::digest(&msg);
+			assert!(digest.iter().zip(expected).all(|(a, b)| a.val() == b));
+		}
+	}
+
+	#[test]
+	fn test_groestl512_known_answers() {
+		let hash = |msg: &[u8]| {
+			let msg_aes = msg
+				.iter()
+				.map(|&b| AESTowerField8b::new(b))
+				.collect::<Vec<_>>();
+			let digest = HasherDigest::<_, Groestl512<_, AESTowerField8b>>::hash(&msg_aes);
+			digest.iter().map(|x| x.val()).collect::<Vec<_>>()
+		};
+
+		// The empty message, from the Grøstl submission package
+		assert_eq!(
+			hash(b""),
+			hex!(
+				"6d3ad29d279110eef3adbd66de2a0345a77baede1557f5d099fce0c03d6dc2ba"
+				"8e6d4a6633dfbd66053c20faa87d1a11f39a7fbe4a6c2f009801370308fc4ad8"
+			)
+		);
+		// Two blocks, from the reference implementation in `testgen/hash/groestl.py`
+		let msg = (0..200).collect::<Vec<u8>>();
+		assert_eq!(
+			hash(&msg),
+			hex!(
+				"ff6dabc4aacd1f3955daba7ee2f36b2e24cca8aef87bdf286ea77b2d86dc4052"
+				"6ca5290c0558e95b4f620d78241a2665ab300216016b66ae87c6dc2e216348bb"
+			)
+		);
+	}
+
+	#[test]
+	fn test_groestl512_packed_and_binary_inputs() {
+		let mut rng = thread_rng();
+		let data_aes = (0..9)
+			.map(|_| PackedAESBinaryField32x8b::random(&mut rng))
+			.collect::<Vec<_>>();
+		let data_bin = data_aes
+			.iter()
+			.map(|x| PackedBinaryField32x8b::from_fn(|i| BinaryField8b::from(x.get(i))))
+			.collect::<Vec<_>>();
+
+		// Updates not aligned to the block size
+		let mut hasher = Groestl512::<_, AESTowerField8b>::new();
+		for chunk in data_aes.chunks(5) {
+			hasher.update(chunk);
+		}
+		let digest_aes = hasher.finalize();
+		let bytes = data_aes
+			.iter()
+			.flat_map(|x| x.iter().map(|y| y.val()))
+			.collect::<Vec<_>>();
+		let expected = groestl_crypto::Groestl512::digest(&bytes);
+		assert!(digest_aes.iter().zip(expected).all(|(a, b)| a.val() == b));
+
+		let digest_bin = HasherDigest::<_, Groestl512<_, BinaryField8b>>::hash(&data_bin);
+		assert!(digest_bin
+			.iter()
+			.zip(digest_aes.iter())
+			.all(|(a, b)| a == BinaryField8b::from(b)));
+	}
+
+	#[test]
+	fn test_groestl512_digest_compression() {
+		let digest = Groestl512DigestCompression::<AESTowerField8b>::default()
+			.compress([Groestl512Digest::default(), Groestl512Digest::default()]);
+		let zero_perm = Groestl512TableCore.permutation_p(State::default());
+		assert_eq!(digest.iter().collect::<Vec<_>>(), zero_perm[1].iter().collect::<Vec<_>>());
+	}
+}
@@ -1,11 +1,14 @@
 // Copyright 2024 Irreducible Inc.
 
+mod bs_groestl;
 mod hasher;
+mod hasher_512;
+mod permutation;
 
 pub mod arch;
 
-pub mod bs_groestl;
-
 pub use arch::Groestl256Core;
-pub use hasher::*;
 pub use bs_groestl::*;
+pub use hasher::*;
+pub use hasher_512::*;
+pub use permutation::*;
@@ -0,0 +1,178 @@
+// Copyright 2024 Irreducible Inc.
+
+//! The P and Q permutations of Grøstl256 as standalone [`Permutation`]s.
+//!
+//! [`GroestlP`] and [`GroestlQ`] permute a single 512-bit state with the backend selected by
+//! [`Groestl256Core`], [`BsGroestlP`] and [`BsGroestlQ`] permute [`BITSLICED_LANES`] states at
+//! once with the bitsliced implementation.
+
+use super::arch::{
+	bitsliced::{transpose_in, transpose_out},
+	BitslicedState, Groestl256BitslicedCore, Groestl256Core, StateBytes, BITSLICED_LANES,
+};
+use binius_field::{
+	AESTowerField8b, BinaryField8b, PackedAESBinaryField64x8b, PackedFieldIndexable,
+};
+use p3_symmetric::{CryptographicPermutation, Permutation};
+use std::{array, slice};
+
+const STATE_LEN: usize = 64;
+
+/// The Grøstl256 P permutation.
+///
+/// It implements [`Permutation`] over `PackedAESBinaryField64x8b`, over `[AESTowerField8b; 64]`
+/// and, through the isomorphism of the towers, over `[BinaryField8b; 64]`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GroestlP;
+
+/// The Grøstl256 Q permutation.
+///
+/// It implements [`Permutation`] over `PackedAESBinaryField64x8b`, over `[AESTowerField8b; 64]`
+/// and, through the isomorphism of the towers, over `[BinaryField8b; 64]`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GroestlQ;
+
+/// The Grøstl256 P permutation on [`BITSLICED_LANES`] states at once.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BsGroestlP;
+
+/// The Grøstl256 Q permutation on [`BITSLICED_LANES`] states at once.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BsGroestlQ;
+
+impl Permutation<PackedAESBinaryField64x8b> for GroestlP {
+	fn permute_mut(&self, input: &mut PackedAESBinaryField64x8b) {
+		*input = Groestl256Core.permutation_p(*input);
+	}
+}
+
+impl Permutation<PackedAESBinaryField64x8b> for GroestlQ {
+	fn permute_mut(&self, input: &mut PackedAESBinaryField64x8b) {
+		*input = Groestl256Core.permutation_q(*input);
+	}
+}
+
+impl Permutation<[[AESTowerField8b; STATE_LEN]; BITSLICED_LANES]> for BsGroestlP {
+	fn permute_mut(&self, input: &mut [[AESTowerField8b; STATE_LEN]; BITSLICED_LANES]) {
+		permute_lanes(input, |state| Groestl256BitslicedCore.permutation_p(state));
+	}
+}
+
+impl Permutation<[[AESTowerField8b; STATE_LEN]; BITSLICED_LANES]> for BsGroestlQ {
+	fn permute_mut(&self, input: &mut [[AESTowerField8b; STATE_LEN]; BITSLICED_LANES]) {
+		permute_lanes(input, |state| Groestl256BitslicedCore.permutation_q(state));
+	}
+}
+
+macro_rules! impl_single_state_permutation {
+	($name:ident) => {
+		impl Permutation<[AESTowerField8b; STATE_LEN]> for $name {
+			fn permute_mut(&self, input: &mut [AESTowerField8b; STATE_LEN]) {
+				let mut packed = PackedAESBinaryField64x8b::default();
+				PackedFieldIndexable::unpack_scalars_mut(slice::from_mut(&mut packed))
+					.copy_from_slice(input);
+				self.permute_mut(&mut packed);
+				input.copy_from_slice(PackedFieldIndexable::unpack_scalars(slice::from_ref(
+					&packed,
+				)));
+			}
+		}
+
+		impl Permutation<[BinaryField8b; STATE_LEN]> for $name {
+			fn permute_mut(&self, input: &mut [BinaryField8b; STATE_LEN]) {
+				let mut input_aes = input.map(AESTowerField8b::from);
+				self.permute_mut(&mut input_aes);
+				*input = input_aes.map(BinaryField8b::from);
+			}
+		}
+
+		impl CryptographicPermutation<PackedAESBinaryField64x8b> for $name {}
+		impl CryptographicPermutation<[AESTowerField8b; STATE_LEN]> for $name {}
+		impl CryptographicPermutation<[BinaryField8b; STATE_LEN]> for $name {}
+	};
+}
+
+impl_single_state_permutation!(GroestlP);
+impl_single_state_permutation!(GroestlQ);
+
+macro_rules! impl_bitsliced_permutation {
+	($name:ident) => {
+		impl $name {
+			/// Permutes any number of states, [`BITSLICED_LANES`] at a time
+			pub fn permute_many(&self, states: &mut [[AESTowerField8b; STATE_LEN]]) {
+				for chunk in states.chunks_mut(BITSLICED_LANES) {
+					let mut lanes = [[AESTowerField8b::default(); STATE_LEN]; BITSLICED_LANES];
+					lanes[..chunk.len()].copy_from_slice(chunk);
+					self.permute_mut(&mut lanes);
+					chunk.copy_from_slice(&lanes[..chunk.len()]);
+				}
+			}
+		}
+
+		impl CryptographicPermutation<[[AESTowerField8b; STATE_LEN]; BITSLICED_LANES]> for $name {}
+	};
+}
+
+impl_bitsliced_permutation!(BsGroestlP);
+impl_bitsliced_permutation!(BsGroestlQ);
+
+/// Applies a bitsliced permutation to one state per lane
+fn permute_lanes(
+	lanes: &mut [[AESTowerField8b; STATE_LEN]; BITSLICED_LANES],
+	permutation: impl FnOnce(&mut BitslicedState),
+) {
+	let bytes: [StateBytes; BITSLICED_LANES] = array::from_fn(|i| lanes[i].map(|x| x.val()));
+	let mut state = transpose_in(&bytes);
+	permutation(&mut state);
+	for (lane, out) in lanes.iter_mut().zip(transpose_out(&state)) {
+		*lane = out.map(AESTowerField8b::new);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{super::arch::Groestl256TableCore, *};
+	use binius_field::{Field, PackedField};
+	use rand::thread_rng;
+
+	#[test]
+	fn test_permutations_match_table() {
+		let mut rng = thread_rng();
+		let packed = PackedAESBinaryField64x8b::random(&mut rng);
+		let state: [AESTowerField8b; STATE_LEN] = array::from_fn(|i| packed.get(i));
+		let expected_p = Groestl256TableCore.permutation_p(packed);
+		let expected_q = Groestl256TableCore.permutation_q(packed);
+
+		assert_eq!(GroestlP.permute(packed), expected_p);
+		assert_eq!(GroestlQ.permute(packed), expected_q);
+		assert_eq!(GroestlP.permute(state), array::from_fn(|i| expected_p.get(i)));
+		assert_eq!(GroestlQ.permute(state), array::from_fn(|i| expected_q.get(i)));
+
+		let state_bin = state.map(BinaryField8b::from);
+		assert_eq!(
+			GroestlP.permute(state_bin),
+			array::from_fn(|i| BinaryField8b::from(expected_p.get(i)))
+		);
+	}
+
+	#[test]
+	fn test_bitsliced_permutations_match_single() {
+		let mut rng = thread_rng();
+		let mut states = (0..BITSLICED_LANES + 7)
+			.map(|_| array::from_fn(|_| <AESTowerField8b as Field>::random(&mut rng)))
+			.collect::<Vec<[AESTowerField8b; STATE_LEN]>>();
+		let expected_p = states
+			.iter()
+			.map(|&state| GroestlP.permute(state))
+			.collect::<Vec<_>>();
+		let expected_q = expected_p
+			.iter()
+			.map(|&state| GroestlQ.permute(state))
+			.collect::<Vec<_>>();
+
+		BsGroestlP.permute_many(&mut states);
+		assert_eq!(states, expected_p);
+		BsGroestlQ.permute_many(&mut states);
+		assert_eq!(states, expected_q);
+	}
+}
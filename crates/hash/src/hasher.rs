@@ -1,6 +1,6 @@
 // Copyright 2023-2024 Irreducible Inc.
 
-use std::{marker::PhantomData, mem::MaybeUninit};
+use std::{borrow::Cow, iter, marker::PhantomData, mem::MaybeUninit};
 
 /// Trait representing the simplest and most straight forward use case of a hash function
 ///
@@ -60,6 +60,46 @@ pub trait Hasher<T> {
 	fn reset(&mut self);
 }
 
+/// Extension of [`Hasher`] for hash functions that can digest many independent messages at once.
+///
+/// The default implementation absorbs the messages one after another. Implementations that hash
+/// several messages in parallel, such as bitsliced ones, override
+/// [`MultiHasher::hash_many_parts`] and advertise their preferred group size through
+/// [`MultiHasher::BATCH_WIDTH`], while keeping the digests identical to those of the incremental
+/// interface.
+pub trait MultiHasher<T>: Hasher<T> + Sized {
+	/// Number of messages that [`MultiHasher::hash_many_parts`] processes together most
+	/// efficiently.
+	const BATCH_WIDTH: usize = 1;
+
+	/// Hashes every message independently and returns the digests in the same order.
+	fn hash_many(messages: &[&[T]]) -> Vec<Self::Digest> {
+		Self::hash_many_parts(messages.iter().map(|&msg| iter::once(msg)))
+	}
+
+	/// Hashes every message independently and returns the digests in the same order, where every
+	/// message is the concatenation of its parts.
+	///
+	/// The parts let callers hash messages scattered over several buffers without copying them
+	/// together first.
+	fn hash_many_parts<'a, M>(messages: impl IntoIterator<Item = M>) -> Vec<Self::Digest>
+	where
+		T: 'a,
+		M: IntoIterator<Item = &'a [T]>,
+	{
+		messages
+			.into_iter()
+			.map(|parts| {
+				let mut hasher = Self::new();
+				for part in parts {
+					hasher.update(part);
+				}
+				hasher.finalize()
+			})
+			.collect()
+	}
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum HashError {
 	#[error("Not enough data to finalize hash (expected {committed} elements, hashed {hashed} elements)")]
@@ -101,6 +141,20 @@ pub fn hasher_hash<T, H: Hasher<T>>(data: impl AsRef<[T]>) -> H::Digest {
 	H::new().chain_update(data).finalize()
 }
 
+/// Concatenates the parts of a message, borrowing the message if it consists of a single part.
+pub(crate) fn concat_parts<'a, T: Clone>(
+	parts: impl IntoIterator<Item = Cow<'a, [T]>>,
+) -> Cow<'a, [T]> {
+	let mut parts = parts.into_iter();
+	let Some(mut message) = parts.next() else {
+		return Cow::Borrowed(&[]);
+	};
+	for part in parts {
+		message.to_mut().extend_from_slice(&part);
+	}
+	message
+}
+
 pub fn fixed_len_hash<T, H: FixedLenHasher<T>>(data: impl AsRef<[T]>) -> H::Digest {
 	H::new(data.as_ref().len() as u64)
 		.chain_update(data)
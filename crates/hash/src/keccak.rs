@@ -0,0 +1,471 @@
+// Copyright 2024 Irreducible Inc.
+
+//! [Keccak-256](https://keccak.team/keccak.html), the variant of SHA3-256 with the original padding
+//! used by Ethereum, over messages of `BinaryField8b` bytes.
+//!
+//! Commitments with this hash can be checked by EVM verifiers, and the preimages are provable with
+//! the `keccakf` gadget of `binius_circuits`. [`MultiKeccak256`] hashes several messages at once
+//! with a Keccak-f[1600] permutation on [`KECCAK_LANES`] states, written so that the compiler
+//! vectorizes it across the lanes.
+
+use crate::{
+	algorithm_id::{FieldTowerId, HashAlgorithmId},
+	compression::MultiPseudoCompressionFunction,
+	hasher::{concat_parts, Hasher, MultiHasher},
+};
+use binius_field::{
+	BinaryField8b, PackedBinaryField32x8b, PackedExtension, PackedField, PackedFieldIndexable,
+};
+use bytemuck::TransparentWrapper;
+use p3_symmetric::{CompressionFunction, PseudoCompressionFunction};
+use rayon::prelude::*;
+use std::{borrow::Cow, collections::BTreeMap, fmt, marker::PhantomData, mem::MaybeUninit};
+use tiny_keccak::{Hasher as _, Keccak};
+
+/// The output digest of [`Keccak256`]
+pub type Keccak256Digest = PackedBinaryField32x8b;
+
+/// Number of Keccak-f[1600] states permuted together by [`MultiKeccak256`]
+pub const KECCAK_LANES: usize = if cfg!(target_feature = "avx512f") {
+	8
+} else {
+	4
+};
+
+const RATE_BYTES: usize = 136;
+const DIGEST_BYTES: usize = 32;
+
+const ROUND_CONSTANTS: [u64; 24] = [
+	0x0000000000000001,
+	0x0000000000008082,
+	0x800000000000808a,
+	0x8000000080008000,
+	0x000000000000808b,
+	0x0000000080000001,
+	0x8000000080008081,
+	0x8000000000008009,
+	0x000000000000008a,
+	0x0000000000000088,
+	0x0000000080008009,
+	0x000000008000000a,
+	0x000000008000808b,
+	0x800000000000008b,
+	0x8000000000008089,
+	0x8000000000008003,
+	0x8000000000008002,
+	0x8000000000000080,
+	0x000000000000800a,
+	0x800000008000000a,
+	0x8000000080008081,
+	0x8000000000008080,
+	0x0000000080000001,
+	0x8000000080008008,
+];
+
+/// Rotation offsets of the rho step, in the order the lanes are visited by the pi step
+const RHO: [u32; 24] = [
+	1, 3, 6, 10, 15, 21, 28, 36, 45, 55, 2, 14, 27, 41, 56, 8, 25, 43, 62, 18, 39, 61, 20, 44,
+];
+
+/// The lanes visited by the pi step, starting from lane 1
+const PI: [usize; 24] = [
+	10, 7, 11, 17, 18, 3, 5, 16, 8, 21, 24, 4, 15, 23, 19, 13, 12, 2, 20, 14, 22, 9, 6, 1,
+];
+
+#[inline(always)]
+fn xor<const N: usize>(a: [u64; N], b: [u64; N]) -> [u64; N] {
+	let mut out = a;
+	for (out, b) in out.iter_mut().zip(b) {
+		*out ^= b;
+	}
+	out
+}
+
+#[inline(always)]
+fn rotate_left<const N: usize>(a: [u64; N], n: u32) -> [u64; N] {
+	a.map(|x| x.rotate_left(n))
+}
+
+/// The Keccak-f[1600] permutation on `N` independent states.
+///
+/// The state is indexed as `state[x + 5 * y][lane]`, the same layout as `tiny_keccak::keccakf` uses
+/// for a single state.
+pub fn keccakf_lanes<const N: usize>(state: &mut [[u64; N]; 25]) {
+	for rc in ROUND_CONSTANTS {
+		// Theta
+		let mut parity = [[0u64; N]; 5];
+		for (x, parity) in parity.iter_mut().enumerate() {
+			for y in 0..5 {
+				*parity = xor(*parity, state[x + 5 * y]);
+			}
+		}
+		for x in 0..5 {
+			let d = xor(parity[(x + 4) % 5], rotate_left(parity[(x + 1) % 5], 1));
+			for y in 0..5 {
+				state[x + 5 * y] = xor(state[x + 5 * y], d);
+			}
+		}
+
+		// Rho and pi
+		let mut last = state[1];
+		for (&pi, &rho) in PI.iter().zip(&RHO) {
+			let next = state[pi];
+			state[pi] = rotate_left(last, rho);
+			last = next;
+		}
+
+		// Chi
+		for y in 0..5 {
+			let row: [[u64; N]; 5] = std::array::from_fn(|x| state[x + 5 * y]);
+			for x in 0..5 {
+				let (a, b, c) = (row[x], row[(x + 1) % 5], row[(x + 2) % 5]);
+				for (((out, a), b), c) in state[x + 5 * y].iter_mut().zip(a).zip(b).zip(c) {
+					*out = a ^ (!b & c);
+				}
+			}
+		}
+
+		// Iota
+		for lane in state[0].iter_mut() {
+			*lane ^= rc;
+		}
+	}
+}
+
+/// The Keccak-256 hash function.
+///
+/// The input type `P` is any packed extension of `BinaryField8b`, whose bytes are hashed in the
+/// order of [`PackedExtension::unpack_base_scalars`].
+#[derive(Clone)]
+pub struct Keccak256<P> {
+	keccak: Keccak,
+	_p_marker: PhantomData<P>,
+}
+
+impl<P> fmt::Debug for Keccak256<P> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("Keccak256").finish_non_exhaustive()
+	}
+}
+
+impl<P> Default for Keccak256<P> {
+	fn default() -> Self {
+		Self {
+			keccak: Keccak::v256(),
+			_p_marker: PhantomData,
+		}
+	}
+}
+
+fn digest_from_bytes(bytes: &[u8; DIGEST_BYTES]) -> Keccak256Digest {
+	Keccak256Digest::from_fn(|i| BinaryField8b::new(bytes[i]))
+}
+
+impl<P> Hasher<P> for Keccak256<P>
+where
+	P: PackedExtension<BinaryField8b, PackedSubfield: PackedFieldIndexable>,
+{
+	type Digest = Keccak256Digest;
+
+	fn new() -> Self {
+		Self::default()
+	}
+
+	fn update(&mut self, data: impl AsRef<[P]>) {
+		let bytes = BinaryField8b::peel_slice(P::unpack_base_scalars(data.as_ref()));
+		self.keccak.update(bytes);
+	}
+
+	fn chain_update(mut self, data: impl AsRef<[P]>) -> Self {
+		self.update(data);
+		self
+	}
+
+	fn finalize(self) -> Self::Digest {
+		let mut out = [0u8; DIGEST_BYTES];
+		self.keccak.finalize(&mut out);
+		digest_from_bytes(&out)
+	}
+
+	fn finalize_into(self, out: &mut MaybeUninit<Self::Digest>) {
+		out.write(self.finalize());
+	}
+
+	fn finalize_reset(&mut self) -> Self::Digest {
+		let keccak = std::mem::replace(&mut self.keccak, Keccak::v256());
+		let mut out = [0u8; DIGEST_BYTES];
+		keccak.finalize(&mut out);
+		digest_from_bytes(&out)
+	}
+
+	fn finalize_into_reset(&mut self, out: &mut MaybeUninit<Self::Digest>) {
+		out.write(self.finalize_reset());
+	}
+
+	fn reset(&mut self) {
+		self.keccak = Keccak::v256();
+	}
+}
+
+impl<P> MultiHasher<P> for Keccak256<P>
+where
+	P: PackedExtension<BinaryField8b, PackedSubfield: PackedFieldIndexable>,
+{
+	const BATCH_WIDTH: usize = KECCAK_LANES;
+
+	fn hash_many_parts<'a, M>(messages: impl IntoIterator<Item = M>) -> Vec<Self::Digest>
+	where
+		P: 'a,
+		M: IntoIterator<Item = &'a [P]>,
+	{
+		let messages = messages
+			.into_iter()
+			.map(|parts| {
+				concat_parts(parts.into_iter().map(|part| {
+					Cow::Borrowed(BinaryField8b::peel_slice(P::unpack_base_scalars(part)))
+				}))
+			})
+			.collect::<Vec<_>>();
+		let messages = messages.iter().map(AsRef::as_ref).collect::<Vec<_>>();
+		MultiKeccak256::hash_many(&messages)
+			.iter()
+			.map(digest_from_bytes)
+			.collect()
+	}
+}
+
+impl<P> HashAlgorithmId for Keccak256<P> {
+	const NAME: &'static str = "keccak256";
+	const VERSION: u16 = 1;
+	const TOWER: &'static str = BinaryField8b::TOWER;
+	const DIGEST_BYTES: usize = DIGEST_BYTES;
+}
+
+/// Keccak-256 hasher for batches of independent messages, [`KECCAK_LANES`] at a time.
+///
+/// The digests are identical to those of [`Keccak256`].
+#[derive(Debug, Default, Clone, Copy)]
+pub struct MultiKeccak256;
+
+impl MultiKeccak256 {
+	/// Hashes every message in `messages` and returns the digests in the same order.
+	///
+	/// Messages may have any length, they are grouped internally by their number of blocks.
+	pub fn hash_many(messages: &[&[u8]]) -> Vec<[u8; DIGEST_BYTES]> {
+		let mut indices_by_blocks = BTreeMap::<usize, Vec<usize>>::new();
+		for (i, msg) in messages.iter().enumerate() {
+			indices_by_blocks
+				.entry(num_padded_blocks(msg.len()))
+				.or_default()
+				.push(i);
+		}
+
+		let batches = indices_by_blocks
+			.iter()
+			.flat_map(|(&num_blocks, indices)| {
+				indices
+					.chunks(KECCAK_LANES)
+					.map(move |chunk| (num_blocks, chunk))
+			})
+			.collect::<Vec<_>>();
+		let digests = batches
+			.par_iter()
+			.map(|&(num_blocks, chunk)| {
+				let group = chunk.iter().map(|&i| messages[i]).collect::<Vec<_>>();
+				hash_lanes(&group, num_blocks)
+			})
+			.collect::<Vec<_>>();
+
+		let mut out = vec![[0u8; DIGEST_BYTES]; messages.len()];
+		for ((_, chunk), digests) in batches.iter().zip(digests) {
+			for (&i, digest) in chunk.iter().zip(digests) {
+				out[i] = digest;
+			}
+		}
+		out
+	}
+}
+
+/// The number of blocks of the message after the `0x01 .. 0x80` padding
+fn num_padded_blocks(msg_len: usize) -> usize {
+	msg_len / RATE_BYTES + 1
+}
+
+/// Returns the block `block_idx` of the padded message
+fn padded_block(msg: &[u8], block_idx: usize, num_blocks: usize) -> [u8; RATE_BYTES] {
+	let mut block = [0u8; RATE_BYTES];
+	let start = (block_idx * RATE_BYTES).min(msg.len());
+	let end = ((block_idx + 1) * RATE_BYTES).min(msg.len());
+	block[..end - start].copy_from_slice(&msg[start..end]);
+	if block_idx == num_blocks - 1 {
+		block[end - start] ^= 0x01;
+		block[RATE_BYTES - 1] ^= 0x80;
+	}
+	block
+}
+
+/// Hashes up to [`KECCAK_LANES`] messages with `num_blocks` padded blocks each
+fn hash_lanes(messages: &[&[u8]], num_blocks: usize) -> Vec<[u8; DIGEST_BYTES]> {
+	debug_assert!(messages.len() <= KECCAK_LANES);
+
+	let mut state = [[0u64; KECCAK_LANES]; 25];
+	for block_idx in 0..num_blocks {
+		for (lane, msg) in messages.iter().enumerate() {
+			let block = padded_block(msg, block_idx, num_blocks);
+			for (word, bytes) in state.iter_mut().zip(block.chunks_exact(8)) {
+				word[lane] ^= u64::from_le_bytes(bytes.try_into().expect("chunk of 8 bytes"));
+			}
+		}
+		keccakf_lanes(&mut state);
+	}
+
+	(0..messages.len())
+		.map(|lane| {
+			let mut digest = [0u8; DIGEST_BYTES];
+			for (bytes, word) in digest.chunks_exact_mut(8).zip(&state) {
+				bytes.copy_from_slice(&word[lane].to_le_bytes());
+			}
+			digest
+		})
+		.collect()
+}
+
+/// A 512-bit to 256-bit compression function for [`Keccak256Digest`]s, the Keccak-256 hash of
+/// the concatenated inputs.
+///
+/// This is the node hash of the Merkle trees commonly verified on the EVM.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Keccak256DigestCompression;
+
+impl PseudoCompressionFunction<Keccak256Digest, 2> for Keccak256DigestCompression {
+	fn compress(&self, input: [Keccak256Digest; 2]) -> Keccak256Digest {
+		Keccak256::<Keccak256Digest>::new()
+			.chain_update(input)
+			.finalize()
+	}
+}
+
+impl CompressionFunction<Keccak256Digest, 2> for Keccak256DigestCompression {}
+
+impl MultiPseudoCompressionFunction<Keccak256Digest, 2> for Keccak256DigestCompression {
+	const BATCH_WIDTH: usize = KECCAK_LANES;
+
+	fn compress_many(&self, inputs: &[[Keccak256Digest; 2]]) -> Vec<Keccak256Digest> {
+		let messages = inputs.iter().map(|x| x.as_slice()).collect::<Vec<_>>();
+		Keccak256::<Keccak256Digest>::hash_many(&messages)
+	}
+}
+
+impl HashAlgorithmId for Keccak256DigestCompression {
+	const NAME: &'static str = "keccak256-concat";
+	const VERSION: u16 = 1;
+	const TOWER: &'static str = BinaryField8b::TOWER;
+	const DIGEST_BYTES: usize = DIGEST_BYTES;
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{HashDigest, HasherDigest};
+	use binius_field::PackedBinaryField16x8b;
+	use hex_literal::hex;
+	use rand::{thread_rng, Rng, RngCore};
+
+	fn to_bytes(digest: Keccak256Digest) -> Vec<u8> {
+		digest.iter().map(|x| x.val()).collect()
+	}
+
+	fn hash_bytes(msg: &[u8]) -> Vec<u8> {
+		to_bytes(HasherDigest::<_, Keccak256<_>>::hash(BinaryField8b::wrap_slice(msg)))
+	}
+
+	#[test]
+	fn test_keccakf_lanes_matches_tiny_keccak() {
+		let mut rng = thread_rng();
+		let mut states = [[0u64; 25]; 8];
+		for state in states.iter_mut() {
+			rng.fill(&mut state[..]);
+		}
+
+		let mut lanes: [[u64; 8]; 25] = std::array::from_fn(|i| states.map(|state| state[i]));
+		keccakf_lanes(&mut lanes);
+		for (lane, mut state) in states.into_iter().enumerate() {
+			tiny_keccak::keccakf(&mut state);
+			assert_eq!(state, std::array::from_fn(|i| lanes[i][lane]));
+		}
+	}
+
+	#[test]
+	fn test_keccak256_known_answers() {
+		assert_eq!(
+			hash_bytes(b""),
+			hex!("c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470")
+		);
+		assert_eq!(
+			hash_bytes(b"abc"),
+			hex!("4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45")
+		);
+	}
+
+	#[test]
+	fn test_keccak256_packed_inputs() {
+		let mut rng = thread_rng();
+		let data = (0..20)
+			.map(|_| PackedBinaryField16x8b::random(&mut rng))
+			.collect::<Vec<_>>();
+		let bytes = data
+			.iter()
+			.flat_map(|x| x.iter().map(|y| y.val()))
+			.collect::<Vec<_>>();
+
+		// Updates not aligned to the block size
+		let mut hasher = Keccak256::new();
+		for chunk in data.chunks(3) {
+			hasher.update(chunk);
+		}
+		assert_eq!(to_bytes(hasher.finalize()), hash_bytes(&bytes));
+	}
+
+	#[test]
+	fn test_multi_keccak256_matches_keccak256() {
+		let mut rng = thread_rng();
+		// Lengths around the padding boundaries, more than one batch each
+		let messages = [0, 1, 135, 136, 137, 271, 272, 300]
+			.into_iter()
+			.cycle()
+			.take(3 * KECCAK_LANES + 1)
+			.map(|len| {
+				let mut msg = vec![0u8; len];
+				rng.fill_bytes(&mut msg);
+				msg
+			})
+			.collect::<Vec<_>>();
+		let messages = messages.iter().map(Vec::as_slice).collect::<Vec<_>>();
+
+		let digests = MultiKeccak256::hash_many(&messages);
+		assert_eq!(digests.len(), messages.len());
+		for (digest, msg) in digests.iter().zip(&messages) {
+			assert_eq!(digest.to_vec(), hash_bytes(msg));
+		}
+	}
+
+	#[test]
+	fn test_keccak256_digest_compression() {
+		let mut rng = thread_rng();
+		let inputs = (0..KECCAK_LANES + 3)
+			.map(|_| {
+				[
+					Keccak256Digest::random(&mut rng),
+					Keccak256Digest::random(&mut rng),
+				]
+			})
+			.collect::<Vec<_>>();
+
+		let compressed = Keccak256DigestCompression.compress_many(&inputs);
+		for (input, out) in inputs.iter().zip(compressed) {
+			let bytes = input.iter().flat_map(|x| to_bytes(*x)).collect::<Vec<_>>();
+			assert_eq!(to_bytes(out), hash_bytes(&bytes));
+			assert_eq!(Keccak256DigestCompression.compress(*input), out);
+		}
+	}
+}
@@ -1,13 +1,28 @@
 // Copyright 2023-2024 Irreducible Inc.
-#![cfg_attr(target_arch = "x86_64", feature(stdarch_x86_avx512))]
+#![cfg_attr(
+	target_arch = "x86_64",
+	feature(avx512_target_feature, stdarch_x86_avx512)
+)]
 
+pub mod algorithm_id;
+pub mod compression;
 pub mod groestl;
 pub mod hasher;
+pub mod keccak;
+pub mod sponge;
+pub mod vision_constants;
 
+mod multi_vision;
 mod vision;
-mod vision_constants;
+mod vision_wide;
+mod vision_wide_constants;
 
+pub use algorithm_id::*;
+pub use compression::*;
 pub use groestl::*;
 pub use hasher::*;
+pub use keccak::*;
+pub use multi_vision::*;
+pub use sponge::*;
 pub use vision::*;
-pub use bs_groestl::*;
+pub use vision_wide::*;
@@ -0,0 +1,417 @@
+// Copyright 2024 Irreducible Inc.
+
+//! Multi-lane Vision-32b, which permutes and hashes many independent states at once.
+//!
+//! The states are stored lane-major: the `i`th elements of [`Vision32bLanes::WIDTH`] independent
+//! states are packed into the `i`th element of a `[Vision32bLanes; 24]`. Every step of the
+//! permutation, including the NTT-based MDS transform, then is a short sequence of packed
+//! operations that processes all lanes together. [`MultiVision32b::hash_many`] additionally spreads
+//! the batches of lanes over the rayon thread pool.
+
+use crate::{
+	vision::ADDITIVE_NTT_AES,
+	vision_constants::{
+		AFFINE_FWD_AES, AFFINE_FWD_CONST_AES, AFFINE_INV_AES, AFFINE_INV_CONST_AES, NUM_ROUNDS,
+		ROUND_KEYS,
+	},
+};
+use binius_field::{
+	arch::ArchOptimal,
+	as_packed_field::{PackScalar, PackedType},
+	linear_transformation::{
+		FieldLinearTransformation, PackedTransformationFactory, Transformation,
+	},
+	underlier::{Divisible, WithUnderlier},
+	AESTowerField32b, AESTowerField8b, BinaryField, BinaryField32b, ExtensionField, Field,
+	PackedAESBinaryField8x32b, PackedExtension, PackedExtensionIndexable, PackedField,
+	PackedFieldIndexable,
+};
+use binius_ntt::AdditiveNTT;
+use lazy_static::lazy_static;
+use p3_symmetric::{CryptographicPermutation, Permutation};
+use rayon::prelude::*;
+use std::{array, collections::BTreeMap, marker::PhantomData};
+
+const STATE_SIZE: usize = 24;
+const RATE_AS_U32: usize = 16;
+const DIGEST_SIZE: usize = 8;
+/// Number of state elements transformed by each of the additive NTTs of the MDS step
+const NTT_SIZE: usize = 8;
+/// Number of additive NTTs of the MDS step in each direction, on disjoint cosets
+const NUM_NTT_COSETS: usize = STATE_SIZE / NTT_SIZE;
+/// Base-2 logarithm of the number of `AESTowerField8b` bytes of an `AESTowerField32b` element
+const LOG_BYTES_PER_ELEM: usize = 2;
+
+/// The packed field used for the lanes of [`MultiVision32b`], the widest one the target
+/// architecture handles natively.
+pub type Vision32bLanes = <AESTowerField32b as ArchOptimal>::OptimalThroughputPacked;
+
+type SboxTransformation =
+	<Vision32bLanes as PackedTransformationFactory<Vision32bLanes>>::PackedTransformation<
+		&'static [AESTowerField32b],
+	>;
+type LanesBytes = <Vision32bLanes as PackedExtension<AESTowerField8b>>::PackedSubfield;
+
+lazy_static! {
+	static ref INV_SBOX: SboxTransformation = make_sbox_transformation(&AFFINE_INV_AES);
+	static ref FWD_SBOX: SboxTransformation = make_sbox_transformation(&AFFINE_FWD_AES);
+
+	static ref ROUND_KEYS_LANES: [[Vision32bLanes; STATE_SIZE]; 2 * NUM_ROUNDS + 1] =
+		ROUND_KEYS.map(|key| key.map(|elem| Vision32bLanes::broadcast(elem.into())));
+
+	/// The constants `x`, `y` and `z` of [`Vision32MDSTransform`](crate::Vision32MDSTransform)
+	static ref MDS_CONSTS: [LanesBytes; 3] = [
+		LanesBytes::broadcast(ADDITIVE_NTT_AES.get_subspace_eval(3, 1)),
+		LanesBytes::broadcast(ADDITIVE_NTT_AES.get_subspace_eval(3, 2)),
+		LanesBytes::broadcast(ADDITIVE_NTT_AES.get_subspace_eval(4, 1)),
+	];
+}
+
+fn make_sbox_transformation(matrix: &'static [AESTowerField32b]) -> SboxTransformation {
+	<Vision32bLanes as PackedTransformationFactory<Vision32bLanes>>::make_packed_transformation(
+		FieldLinearTransformation::new_const(matrix),
+	)
+}
+
+/// The Vision-32b permutation applied to [`Vision32bLanes::WIDTH`] independent states at once.
+///
+/// The state of lane `j` consists of the `j`th scalars of the 24 packed elements. The output of
+/// every lane is identical to that of [`Vision32bPermutation`](crate::Vision32bPermutation) on
+/// the lane's state.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MultiVision32bPermutation;
+
+impl MultiVision32bPermutation {
+	pub fn new() -> Self {
+		Self
+	}
+
+	/// Permutes any number of states, [`Vision32bLanes::WIDTH`] at a time.
+	pub fn permute_many<F>(&self, states: &mut [[F; STATE_SIZE]])
+	where
+		F: Field + From<AESTowerField32b> + Into<AESTowerField32b>,
+	{
+		for chunk in states.chunks_mut(Vision32bLanes::WIDTH) {
+			let mut lanes: [Vision32bLanes; STATE_SIZE] = array::from_fn(|i| {
+				Vision32bLanes::from_fn(|lane| {
+					chunk
+						.get(lane)
+						.map_or(AESTowerField32b::ZERO, |state| state[i].into())
+				})
+			});
+			self.permute_mut(&mut lanes);
+			for (lane, state) in chunk.iter_mut().enumerate() {
+				*state = array::from_fn(|i| F::from(lanes[i].get(lane)));
+			}
+		}
+	}
+
+	fn sbox_step(
+		&self,
+		state: &mut [Vision32bLanes; STATE_SIZE],
+		trans: &SboxTransformation,
+		constant: AESTowerField32b,
+	) {
+		let constant = Vision32bLanes::broadcast(constant);
+		for elem in state.iter_mut() {
+			*elem = trans.transform(&elem.invert_or_zero()) + constant;
+		}
+	}
+
+	/// The MDS step, the lane-major counterpart of
+	/// [`Vision32MDSTransform`](crate::Vision32MDSTransform).
+	///
+	/// The bytes of all lanes form the batch of the additive NTTs, so every butterfly is a single
+	/// packed operation.
+	fn mds(&self, state: &mut [Vision32bLanes; STATE_SIZE]) {
+		let log_batch_size = Vision32bLanes::LOG_WIDTH + LOG_BYTES_PER_ELEM;
+
+		for (coset, chunk) in state.chunks_exact_mut(NTT_SIZE).enumerate() {
+			ADDITIVE_NTT_AES
+				.inverse_transform(
+					PackedExtension::<AESTowerField8b>::cast_bases_mut(chunk),
+					coset as u32,
+					log_batch_size,
+				)
+				.expect("the NTT domain fits all cosets of the MDS step");
+		}
+
+		{
+			let [x, y, z] = *MDS_CONSTS;
+			let data = PackedExtension::<AESTowerField8b>::cast_bases_mut(state);
+			for i in 0..NTT_SIZE {
+				let (mut d0, mut d1, mut d2) =
+					(data[i], data[NTT_SIZE + i], data[2 * NTT_SIZE + i]);
+
+				d1 += d0;
+				let x = x * d1;
+				d2 += x + d0;
+
+				let y = y * d1;
+				let z = z * d2;
+
+				let stash_0 = d0;
+				let stash_1 = d1;
+				d0 += x + d1 + d2;
+				d1 = stash_0 + y + z;
+				d2 = d1 + stash_1;
+
+				(data[i], data[NTT_SIZE + i], data[2 * NTT_SIZE + i]) = (d0, d1, d2);
+			}
+		}
+
+		for (coset, chunk) in state.chunks_exact_mut(NTT_SIZE).enumerate() {
+			ADDITIVE_NTT_AES
+				.forward_transform(
+					PackedExtension::<AESTowerField8b>::cast_bases_mut(chunk),
+					(NUM_NTT_COSETS + coset) as u32,
+					log_batch_size,
+				)
+				.expect("the NTT domain fits all cosets of the MDS step");
+		}
+	}
+}
+
+impl Permutation<[Vision32bLanes; STATE_SIZE]> for MultiVision32bPermutation {
+	fn permute_mut(&self, input: &mut [Vision32bLanes; STATE_SIZE]) {
+		add_round_key(input, &ROUND_KEYS_LANES[0]);
+		for r in 0..NUM_ROUNDS {
+			self.sbox_step(input, &INV_SBOX, AFFINE_INV_CONST_AES);
+			self.mds(input);
+			add_round_key(input, &ROUND_KEYS_LANES[1 + 2 * r]);
+			self.sbox_step(input, &FWD_SBOX, AFFINE_FWD_CONST_AES);
+			self.mds(input);
+			add_round_key(input, &ROUND_KEYS_LANES[2 + 2 * r]);
+		}
+	}
+}
+
+impl CryptographicPermutation<[Vision32bLanes; STATE_SIZE]> for MultiVision32bPermutation {}
+
+#[inline]
+fn add_round_key(state: &mut [Vision32bLanes; STATE_SIZE], key: &[Vision32bLanes; STATE_SIZE]) {
+	for (elem, &key) in state.iter_mut().zip(key) {
+		*elem += key;
+	}
+}
+
+/// Multi-lane Vision-32b hasher for batches of independent messages.
+///
+/// The digests of [`Self::hash_many`] are identical to those of
+/// [`VisionHasher<F, P>`](crate::VisionHasher) on every message, and the digests of
+/// [`Self::hash_many_padded`] to those of [`Vision32bLeafHasher`](crate::Vision32bLeafHasher).
+#[derive(Debug, Default, Clone)]
+pub struct MultiVision32b<F = BinaryField32b> {
+	_f_marker: PhantomData<F>,
+}
+
+/// How a message is encoded into the sponge before hashing its blocks
+#[derive(Debug, Clone, Copy)]
+enum Padding {
+	/// The byte-length of the message is in the initial state and the last block is filled with
+	/// zeros, as in `VisionHasher`
+	LengthPrefixed,
+	/// The message is padded with `10*` to a positive number of blocks, as in
+	/// `Vision32bLeafHasher`
+	OneZero,
+}
+
+impl<F> MultiVision32b<F>
+where
+	F: BinaryField + From<AESTowerField32b> + Into<AESTowerField32b>,
+{
+	/// Hashes every message in `messages` and returns the digests in the same order.
+	///
+	/// Messages may have any length, they are grouped internally by their length and every group
+	/// is hashed [`Vision32bLanes::WIDTH`] messages at a time, in parallel.
+	pub fn hash_many<U, P>(messages: &[&[P]]) -> Vec<PackedType<U, F>>
+	where
+		U: PackScalar<F> + Divisible<u32>,
+		P: PackedExtension<F, PackedSubfield: PackedFieldIndexable> + Sync,
+		P::Scalar: ExtensionField<F>,
+		PackedAESBinaryField8x32b: WithUnderlier<Underlier = U>,
+	{
+		Self::hash_many_with(messages, Padding::LengthPrefixed)
+	}
+
+	/// Hashes every message in `messages` with the `10*`-padded sponge of
+	/// [`Vision32bLeafHasher`](crate::Vision32bLeafHasher), grouping and parallelizing as
+	/// [`Self::hash_many`] does.
+	pub fn hash_many_padded<U, P>(messages: &[&[P]]) -> Vec<PackedType<U, F>>
+	where
+		U: PackScalar<F> + Divisible<u32>,
+		P: PackedExtension<F, PackedSubfield: PackedFieldIndexable> + Sync,
+		P::Scalar: ExtensionField<F>,
+		PackedAESBinaryField8x32b: WithUnderlier<Underlier = U>,
+	{
+		Self::hash_many_with(messages, Padding::OneZero)
+	}
+
+	fn hash_many_with<U, P>(messages: &[&[P]], padding: Padding) -> Vec<PackedType<U, F>>
+	where
+		U: PackScalar<F> + Divisible<u32>,
+		P: PackedExtension<F, PackedSubfield: PackedFieldIndexable> + Sync,
+		P::Scalar: ExtensionField<F>,
+		PackedAESBinaryField8x32b: WithUnderlier<Underlier = U>,
+	{
+		let mut groups = BTreeMap::<usize, Vec<usize>>::new();
+		for (i, msg) in messages.iter().enumerate() {
+			groups.entry(msg.len()).or_default().push(i);
+		}
+		let batches = groups
+			.values()
+			.flat_map(|indices| indices.chunks(Vision32bLanes::WIDTH))
+			.collect::<Vec<_>>();
+
+		let batch_digests = batches
+			.par_iter()
+			.map(|batch| {
+				let scalars = batch
+					.iter()
+					.map(|&i| P::unpack_base_scalars(messages[i]))
+					.collect::<Vec<_>>();
+				hash_lanes(&scalars, padding)
+			})
+			.collect::<Vec<_>>();
+
+		let mut digests = vec![PackedType::<U, F>::default(); messages.len()];
+		for (batch, batch_digests) in batches.iter().zip(batch_digests) {
+			for (&i, digest) in batch.iter().zip(batch_digests) {
+				digests[i] = PackedType::<U, F>::from_fn(|j| F::from(digest[j]));
+			}
+		}
+		digests
+	}
+}
+
+/// Hashes up to [`Vision32bLanes::WIDTH`] messages of the same length, one per lane
+fn hash_lanes<F>(messages: &[&[F]], padding: Padding) -> Vec<[AESTowerField32b; DIGEST_SIZE]>
+where
+	F: Field + Into<AESTowerField32b>,
+{
+	let len = messages[0].len();
+	debug_assert!(messages.len() <= Vision32bLanes::WIDTH);
+	debug_assert!(messages.iter().all(|msg| msg.len() == len));
+
+	let mut state = [Vision32bLanes::zero(); STATE_SIZE];
+	let padded_len = match padding {
+		Padding::LengthPrefixed => {
+			// The byte-length of the message is written into the initial state
+			let msg_len_bytes =
+				(len * <BinaryField32b as ExtensionField<AESTowerField8b>>::DEGREE) as u64;
+			state[RATE_AS_U32] =
+				Vision32bLanes::broadcast(BinaryField32b::new(msg_len_bytes as u32).into());
+			state[RATE_AS_U32 + 1] =
+				Vision32bLanes::broadcast(BinaryField32b::new((msg_len_bytes >> 32) as u32).into());
+			len.next_multiple_of(RATE_AS_U32)
+		}
+		Padding::OneZero => (len + 1).next_multiple_of(RATE_AS_U32),
+	};
+
+	for block_start in (0..padded_len).step_by(RATE_AS_U32) {
+		for (j, elem) in state[..RATE_AS_U32].iter_mut().enumerate() {
+			let idx = block_start + j;
+			*elem = if idx < len {
+				Vision32bLanes::from_fn(|lane| {
+					messages
+						.get(lane)
+						.map_or(AESTowerField32b::ZERO, |msg| msg[idx].into())
+				})
+			} else if idx == len && matches!(padding, Padding::OneZero) {
+				Vision32bLanes::one()
+			} else {
+				Vision32bLanes::zero()
+			};
+		}
+		MultiVision32bPermutation.permute_mut(&mut state);
+	}
+
+	(0..messages.len())
+		.map(|lane| array::from_fn(|i| state[i].get(lane)))
+		.collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{FixedLenHasherDigest, HashDigest, Vision32bPermutation, VisionHasher};
+	use binius_field::PackedBinaryField4x64b;
+	use rand::thread_rng;
+
+	#[test]
+	fn test_permute_many_matches_single() {
+		let mut rng = thread_rng();
+		let mut states = (0..Vision32bLanes::WIDTH + 3)
+			.map(|_| array::from_fn(|_| <BinaryField32b as Field>::random(&mut rng)))
+			.collect::<Vec<[BinaryField32b; STATE_SIZE]>>();
+		let expected = states
+			.iter()
+			.map(|&state| Vision32bPermutation::default().permute(state))
+			.collect::<Vec<_>>();
+
+		MultiVision32bPermutation.permute_many(&mut states);
+		assert_eq!(states, expected);
+	}
+
+	#[test]
+	fn test_hash_many_matches_vision_hasher() {
+		let mut rng = thread_rng();
+		// Lengths around the block boundaries, more messages of some lengths than there are lanes
+		let messages = [0, 1, 15, 16, 17, 32, 45]
+			.into_iter()
+			.cycle()
+			.take(3 * Vision32bLanes::WIDTH + 2)
+			.map(|len| {
+				(0..len)
+					.map(|_| <BinaryField32b as Field>::random(&mut rng))
+					.collect::<Vec<_>>()
+			})
+			.collect::<Vec<_>>();
+		let messages = messages.iter().map(Vec::as_slice).collect::<Vec<_>>();
+
+		assert!(MultiVision32b::<BinaryField32b>::hash_many::<_, BinaryField32b>(&[]).is_empty());
+		let digests = MultiVision32b::<BinaryField32b>::hash_many(&messages);
+		for (digest, msg) in digests.iter().zip(&messages) {
+			assert_eq!(
+				*digest,
+				FixedLenHasherDigest::<_, VisionHasher<BinaryField32b, _>>::hash(msg)
+			);
+		}
+
+		let messages_aes = messages
+			.iter()
+			.map(|msg| {
+				msg.iter()
+					.map(|&x| AESTowerField32b::from(x))
+					.collect::<Vec<_>>()
+			})
+			.collect::<Vec<_>>();
+		let messages_aes = messages_aes.iter().map(Vec::as_slice).collect::<Vec<_>>();
+		let digests_aes = MultiVision32b::<AESTowerField32b>::hash_many(&messages_aes);
+		for (digest, msg) in digests_aes.iter().zip(&messages_aes) {
+			assert_eq!(
+				*digest,
+				FixedLenHasherDigest::<_, VisionHasher<AESTowerField32b, _>>::hash(msg)
+			);
+		}
+	}
+
+	#[test]
+	fn test_hash_many_packed_extension_inputs() {
+		let mut rng = thread_rng();
+		let messages = (0..Vision32bLanes::WIDTH + 1)
+			.map(|i| {
+				(0..i % 5)
+					.map(|_| PackedBinaryField4x64b::random(&mut rng))
+					.collect::<Vec<_>>()
+			})
+			.collect::<Vec<_>>();
+		let messages = messages.iter().map(Vec::as_slice).collect::<Vec<_>>();
+
+		let digests = MultiVision32b::<BinaryField32b>::hash_many(&messages);
+		for (digest, msg) in digests.iter().zip(&messages) {
+			let expected = FixedLenHasherDigest::<_, VisionHasher<BinaryField32b, _>>::hash(msg);
+			assert_eq!(*digest, expected);
+		}
+	}
+}
@@ -0,0 +1,466 @@
+// Copyright 2024 Irreducible Inc.
+
+//! Sponge constructions over any [`CryptographicPermutation`] of an array of field elements.
+//!
+//! [`DuplexSponge`] is the absorb/squeeze core shared by [`SpongeHasher`] and the duplex
+//! challenger in `binius_core`, so that a new permutation gets both a hash function and a
+//! Fiat-Shamir challenger.
+
+use crate::hasher::{FixedLenHasher, HashError, Hasher, MultiHasher};
+use binius_field::{
+	BinaryField, BinaryField1b, ExtensionField, Field, PackedExtension, PackedFieldIndexable,
+};
+use p3_symmetric::{CryptographicPermutation, Permutation};
+use std::{marker::PhantomData, mem::MaybeUninit};
+
+/// A duplex sponge absorbing by overwriting the first `RATE` elements of its state.
+///
+/// The permutation is applied lazily: the state is permuted when absorbing into a full block and
+/// when squeezing, which leaves the last absorbed block open for padding.
+#[derive(Debug, Clone)]
+pub struct DuplexSponge<F, Perm, const RATE: usize, const STATE: usize> {
+	permutation: Perm,
+	state: [F; STATE],
+	// Number of elements absorbed into the current block
+	index: usize,
+}
+
+impl<F, Perm, const RATE: usize, const STATE: usize> Default for DuplexSponge<F, Perm, RATE, STATE>
+where
+	F: Default + Copy,
+	Perm: Default,
+{
+	fn default() -> Self {
+		Self {
+			permutation: Perm::default(),
+			state: [F::default(); STATE],
+			index: 0,
+		}
+	}
+}
+
+impl<F, Perm, const RATE: usize, const STATE: usize> DuplexSponge<F, Perm, RATE, STATE>
+where
+	F: Field,
+	Perm: Permutation<[F; STATE]>,
+{
+	pub fn new(permutation: Perm, initial_state: [F; STATE]) -> Self {
+		assert!(0 < RATE && RATE < STATE, "the rate must be positive and less than the state size");
+		Self {
+			permutation,
+			state: initial_state,
+			index: 0,
+		}
+	}
+
+	/// Number of elements absorbed into the current block, `RATE` if the block is full
+	pub fn block_len(&self) -> usize {
+		self.index
+	}
+
+	pub fn absorb(&mut self, input: &[F]) {
+		for &elem in input {
+			if self.index == RATE {
+				self.permutation.permute_mut(&mut self.state);
+				self.index = 0;
+			}
+			self.state[self.index] = elem;
+			self.index += 1;
+		}
+	}
+
+	/// Overwrites the rest of the current block with zeros
+	pub fn fill_block(&mut self) {
+		self.state[self.index..RATE].fill(F::ZERO);
+		self.index = RATE;
+	}
+
+	/// Permutes the state and writes its first `output.len()` elements to `output`.
+	///
+	/// ## Preconditions
+	///
+	/// * `output.len()` is at most `RATE`
+	pub fn squeeze(&mut self, output: &mut [F]) {
+		assert!(output.len() <= RATE, "cannot squeeze more than the rate at once");
+		self.permutation.permute_mut(&mut self.state);
+		self.index = 0;
+		output.copy_from_slice(&self.state[..output.len()]);
+	}
+}
+
+/// The padding of the last block of a message, absorbed before squeezing the digest.
+pub trait SpongePadding {
+	fn pad<F, Perm, const RATE: usize, const STATE: usize>(
+		sponge: &mut DuplexSponge<F, Perm, RATE, STATE>,
+	) where
+		F: Field,
+		Perm: Permutation<[F; STATE]>;
+}
+
+/// Fills a partial last block with zeros.
+///
+/// Messages differing only in trailing zeros collide, so this padding is only suitable when the
+/// message length is fixed, as for [`FixedLenHasher`] which commits to it in the initial state.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ZeroPadding;
+
+/// Appends a one and then fills the block with zeros, the injective `10*` padding.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct OneZeroPadding;
+
+impl SpongePadding for ZeroPadding {
+	fn pad<F, Perm, const RATE: usize, const STATE: usize>(
+		sponge: &mut DuplexSponge<F, Perm, RATE, STATE>,
+	) where
+		F: Field,
+		Perm: Permutation<[F; STATE]>,
+	{
+		if sponge.block_len() != 0 {
+			sponge.fill_block();
+		}
+	}
+}
+
+impl SpongePadding for OneZeroPadding {
+	fn pad<F, Perm, const RATE: usize, const STATE: usize>(
+		sponge: &mut DuplexSponge<F, Perm, RATE, STATE>,
+	) where
+		F: Field,
+		Perm: Permutation<[F; STATE]>,
+	{
+		sponge.absorb(&[F::ONE]);
+		sponge.fill_block();
+	}
+}
+
+/// A sponge hash function over the permutation `Perm` of `STATE` elements of `F`, absorbing
+/// `RATE` elements at a time.
+///
+/// The initial state is zero except for the capacity, which holds
+///
+/// * the domain separator in its last element, see [`SpongeHasher::with_domain`], and
+/// * for the [`FixedLenHasher`] instances, the message length as a number of `F` elements in the
+///   first `⌈64 / F::N_BITS⌉` elements.
+///
+/// The message is absorbed as its `F` elements, so that packings and extensions of the same
+/// elements have the same digest, and padded with `Pad` at the end. The digest is the rate part of
+/// the state after the final permutation, which can be truncated if a shorter digest suffices.
+///
+/// Since [`Hasher`] and [`FixedLenHasher`] both have a `new` constructor, it has to be called with
+/// the trait named, as in `<SpongeHasher<..> as FixedLenHasher<F>>::new(len)`.
+#[derive(Debug, Clone)]
+pub struct SpongeHasher<F, Perm, const RATE: usize, const STATE: usize, Pad = OneZeroPadding> {
+	sponge: DuplexSponge<F, Perm, RATE, STATE>,
+	domain: F,
+	// The number of `F` elements committed to by a fixed-length hasher
+	committed_len: Option<u64>,
+	// The number of `F` elements absorbed so far
+	current_len: u64,
+	_pad_marker: PhantomData<Pad>,
+}
+
+impl<F, Perm, const RATE: usize, const STATE: usize, Pad> SpongeHasher<F, Perm, RATE, STATE, Pad>
+where
+	F: BinaryField,
+	Perm: CryptographicPermutation<[F; STATE]> + Default,
+	Pad: SpongePadding,
+{
+	fn with_committed_len(committed_len: Option<u64>, domain: F) -> Self {
+		let mut this = Self {
+			sponge: DuplexSponge::default(),
+			domain,
+			committed_len,
+			current_len: 0,
+			_pad_marker: PhantomData,
+		};
+		this.reset_state();
+		this
+	}
+
+	/// Sets the domain separator, which has to happen before any data is absorbed
+	pub fn with_domain(mut self, domain: F) -> Self {
+		assert_eq!(self.current_len, 0, "the domain must be set before absorbing data");
+		self.domain = domain;
+		self.reset_state();
+		self
+	}
+
+	fn reset_state(&mut self) {
+		let mut initial_state = [F::ZERO; STATE];
+		initial_state[STATE - 1] = self.domain;
+		if let Some(len) = self.committed_len {
+			let len_elems = 64usize.div_ceil(F::N_BITS);
+			assert!(
+				RATE + len_elems < STATE,
+				"the capacity is too small to hold the message length and the domain"
+			);
+			for (j, dst) in initial_state[RATE..RATE + len_elems].iter_mut().enumerate() {
+				let offset = j * F::N_BITS;
+				*dst = (0..F::N_BITS.min(64 - offset))
+					.filter(|i| (len >> (offset + i)) & 1 == 1)
+					.map(|i| {
+						<F as ExtensionField<BinaryField1b>>::basis(i)
+							.expect("i is less than N_BITS")
+					})
+					.sum();
+			}
+		}
+		self.sponge = DuplexSponge::new(Perm::default(), initial_state);
+		self.current_len = 0;
+	}
+
+	fn absorb<P>(&mut self, data: &[P])
+	where
+		P: PackedExtension<F, PackedSubfield: PackedFieldIndexable>,
+		P::Scalar: ExtensionField<F>,
+	{
+		let elems = P::unpack_base_scalars(data);
+		self.sponge.absorb(elems);
+		self.current_len = self
+			.current_len
+			.checked_add(elems.len() as u64)
+			.expect("Overflow on message length");
+	}
+
+	fn finalize_state(&mut self) -> [F; RATE] {
+		Pad::pad(&mut self.sponge);
+		let mut digest = [F::ZERO; RATE];
+		self.sponge.squeeze(&mut digest);
+		digest
+	}
+}
+
+/// Number of `F` elements in a `P` element
+fn elems_per_packed<F, P>() -> u64
+where
+	P: PackedExtension<F>,
+	P::Scalar: ExtensionField<F>,
+	F: Field,
+{
+	(P::WIDTH * P::Scalar::DEGREE) as u64
+}
+
+impl<F, Perm, const RATE: usize, const STATE: usize, Pad, P> Hasher<P>
+	for SpongeHasher<F, Perm, RATE, STATE, Pad>
+where
+	F: BinaryField,
+	Perm: CryptographicPermutation<[F; STATE]> + Default,
+	Pad: SpongePadding,
+	P: PackedExtension<F, PackedSubfield: PackedFieldIndexable>,
+	P::Scalar: ExtensionField<F>,
+{
+	type Digest = [F; RATE];
+
+	fn new() -> Self {
+		Self::with_committed_len(None, F::ZERO)
+	}
+
+	fn update(&mut self, data: impl AsRef<[P]>) {
+		self.absorb(data.as_ref());
+	}
+
+	fn chain_update(mut self, data: impl AsRef<[P]>) -> Self {
+		self.absorb(data.as_ref());
+		self
+	}
+
+	fn finalize(mut self) -> Self::Digest {
+		self.finalize_state()
+	}
+
+	fn finalize_into(mut self, out: &mut MaybeUninit<Self::Digest>) {
+		out.write(self.finalize_state());
+	}
+
+	fn finalize_reset(&mut self) -> Self::Digest {
+		let digest = self.finalize_state();
+		self.reset_state();
+		digest
+	}
+
+	fn finalize_into_reset(&mut self, out: &mut MaybeUninit<Self::Digest>) {
+		out.write(self.finalize_state());
+		self.reset_state();
+	}
+
+	fn reset(&mut self) {
+		self.reset_state();
+	}
+}
+
+impl<F, Perm, const RATE: usize, const STATE: usize, Pad, P> MultiHasher<P>
+	for SpongeHasher<F, Perm, RATE, STATE, Pad>
+where
+	F: BinaryField,
+	Perm: CryptographicPermutation<[F; STATE]> + Default,
+	Pad: SpongePadding,
+	P: PackedExtension<F, PackedSubfield: PackedFieldIndexable>,
+	P::Scalar: ExtensionField<F>,
+{
+}
+
+impl<F, Perm, const RATE: usize, const STATE: usize, Pad, P> FixedLenHasher<P>
+	for SpongeHasher<F, Perm, RATE, STATE, Pad>
+where
+	F: BinaryField,
+	Perm: CryptographicPermutation<[F; STATE]> + Default,
+	Pad: SpongePadding,
+	P: PackedExtension<F, PackedSubfield: PackedFieldIndexable>,
+	P::Scalar: ExtensionField<F>,
+{
+	type Digest = [F; RATE];
+
+	fn new(msg_len: u64) -> Self {
+		let committed_len = msg_len
+			.checked_mul(elems_per_packed::<F, P>())
+			.expect("Overflow on message length");
+		Self::with_committed_len(Some(committed_len), F::ZERO)
+	}
+
+	fn update(&mut self, data: impl AsRef<[P]>) {
+		self.absorb(data.as_ref());
+	}
+
+	fn chain_update(mut self, data: impl AsRef<[P]>) -> Self {
+		self.absorb(data.as_ref());
+		self
+	}
+
+	fn finalize(mut self) -> Result<Self::Digest, HashError> {
+		let committed = self.committed_len.unwrap_or(self.current_len);
+		let elems_per_packed = elems_per_packed::<F, P>();
+		if self.current_len < committed {
+			return Err(HashError::NotEnoughData {
+				committed: committed / elems_per_packed,
+				hashed: self.current_len / elems_per_packed,
+			});
+		}
+		if self.current_len > committed {
+			return Err(HashError::TooMuchData {
+				committed: committed / elems_per_packed,
+				received: self.current_len / elems_per_packed,
+			});
+		}
+
+		Ok(self.finalize_state())
+	}
+
+	fn reset(&mut self) {
+		self.reset_state();
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{FixedLenHasherDigest, GroestlP, HashDigest, HasherDigest, Vision32bPermutation};
+	use binius_field::{BinaryField128b, BinaryField32b, BinaryField8b};
+	use rand::thread_rng;
+	use std::array;
+
+	type VisionSponge<Pad = OneZeroPadding> =
+		SpongeHasher<BinaryField32b, Vision32bPermutation, 16, 24, Pad>;
+
+	fn random_elems(n: usize) -> Vec<BinaryField32b> {
+		let mut rng = thread_rng();
+		(0..n)
+			.map(|_| <BinaryField32b as Field>::random(&mut rng))
+			.collect()
+	}
+
+	#[test]
+	fn test_sponge_hasher_matches_permutation() {
+		let msg = random_elems(19);
+		let digest = HasherDigest::<_, VisionSponge>::hash(&msg);
+
+		let mut state = [BinaryField32b::ZERO; 24];
+		state[..16].copy_from_slice(&msg[..16]);
+		Vision32bPermutation::default().permute_mut(&mut state);
+		state[..3].copy_from_slice(&msg[16..]);
+		state[3] = BinaryField32b::ONE;
+		state[4..16].fill(BinaryField32b::ZERO);
+		Vision32bPermutation::default().permute_mut(&mut state);
+		assert_eq!(digest[..], state[..16]);
+	}
+
+	#[test]
+	fn test_sponge_hasher_padding_and_domain() {
+		let msg = random_elems(16);
+		let hash = |msg: &[BinaryField32b]| HasherDigest::<_, VisionSponge>::hash(msg);
+
+		// A full block of padding is absorbed for aligned messages
+		assert_ne!(hash(&msg[..15]), hash(&msg[..16]));
+		let mut msg_zero = msg[..15].to_vec();
+		msg_zero.push(BinaryField32b::ZERO);
+		assert_ne!(hash(&msg[..15]), hash(&msg_zero));
+
+		let mut hasher =
+			<VisionSponge as Hasher<BinaryField32b>>::new().with_domain(BinaryField32b::new(1));
+		Hasher::update(&mut hasher, &msg);
+		let with_domain = Hasher::finalize(hasher);
+		assert_ne!(with_domain, hash(&msg));
+	}
+
+	#[test]
+	fn test_sponge_hasher_extensions_and_packings() {
+		let msg = random_elems(12);
+		let msg_128b = msg
+			.chunks(4)
+			.map(|x| BinaryField128b::from_bases(x).unwrap())
+			.collect::<Vec<_>>();
+
+		assert_eq!(
+			HasherDigest::<_, VisionSponge>::hash(&msg),
+			HasherDigest::<_, VisionSponge>::hash(&msg_128b)
+		);
+		assert_eq!(
+			FixedLenHasherDigest::<_, VisionSponge<ZeroPadding>>::hash(&msg),
+			FixedLenHasherDigest::<_, VisionSponge<ZeroPadding>>::hash(&msg_128b)
+		);
+	}
+
+	#[test]
+	fn test_fixed_len_sponge_hasher() {
+		let msg = random_elems(5);
+		let hash = |msg: &[BinaryField32b]| {
+			FixedLenHasherDigest::<_, VisionSponge<ZeroPadding>>::hash(msg)
+		};
+
+		// The length in the initial state separates messages differing in trailing zeros
+		let mut msg_zero = msg.clone();
+		msg_zero.push(BinaryField32b::ZERO);
+		assert_ne!(hash(&msg), hash(&msg_zero));
+
+		let hasher = <VisionSponge<ZeroPadding> as FixedLenHasher<BinaryField32b>>::new(6);
+		assert!(matches!(
+			FixedLenHasher::finalize(FixedLenHasher::chain_update(hasher, &msg)),
+			Err(HashError::NotEnoughData {
+				committed: 6,
+				hashed: 5
+			})
+		));
+
+		let hasher = <VisionSponge<ZeroPadding> as FixedLenHasher<BinaryField128b>>::new(1);
+		assert!(matches!(
+			FixedLenHasher::finalize(FixedLenHasher::chain_update(
+				hasher,
+				[BinaryField128b::ZERO; 2]
+			)),
+			Err(HashError::TooMuchData {
+				committed: 1,
+				received: 2
+			})
+		));
+	}
+
+	#[test]
+	fn test_sponge_hasher_with_groestl_permutation() {
+		type GroestlSponge = SpongeHasher<BinaryField8b, GroestlP, 32, 64>;
+
+		let msg: [BinaryField8b; 40] = array::from_fn(|i| BinaryField8b::new(i as u8));
+		let mut hasher = <GroestlSponge as Hasher<BinaryField8b>>::new();
+		Hasher::update(&mut hasher, &msg[..7]);
+		Hasher::update(&mut hasher, &msg[7..]);
+		assert_eq!(Hasher::finalize(hasher), HasherDigest::<_, GroestlSponge>::hash(msg));
+	}
+}
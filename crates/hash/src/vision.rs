@@ -1,7 +1,11 @@
 // Copyright 2024 Irreducible Inc.
 
 use crate::{
-	hasher::{FixedLenHasher, HashError},
+	algorithm_id::{FieldTowerId, HashAlgorithmId},
+	compression::MultiPseudoCompressionFunction,
+	hasher::{concat_parts, fixed_len_hash, FixedLenHasher, HashError, Hasher, MultiHasher},
+	multi_vision::{MultiVision32b, Vision32bLanes},
+	sponge::SpongeHasher,
 	vision_constants::{
 		AFFINE_FWD_AES, AFFINE_FWD_CONST_AES, AFFINE_INV_AES, AFFINE_INV_CONST_AES, NUM_ROUNDS,
 		ROUND_KEYS,
@@ -23,8 +27,10 @@ use binius_ntt::{
 	SingleThreadedNTT,
 };
 use lazy_static::lazy_static;
-use p3_symmetric::{CryptographicPermutation, Permutation};
-use std::{iter::repeat, marker::PhantomData};
+use p3_symmetric::{
+	CompressionFunction, CryptographicPermutation, Permutation, PseudoCompressionFunction,
+};
+use std::{borrow::Cow, iter::repeat, marker::PhantomData, mem::MaybeUninit};
 
 const RATE_AS_U32: usize = 16;
 
@@ -41,9 +47,13 @@ type AdditiveNTT8b = SingleThreadedNTT<AESTowerField8b, OnTheFlyTwiddleAccess<AE
 /// The vision specialization over `BinaryField32b` as per [Vision Mark-32](https://eprint.iacr.org/2024/633)
 pub type Vision32b<P> = VisionHasher<BinaryField32b, P>;
 
+/// The output digest of [`Vision32b`]
+pub type Vision32bDigest =
+	PackedType<<PackedAESBinaryField8x32b as WithUnderlier>::Underlier, BinaryField32b>;
+
 lazy_static! {
 	/// We use this object only to calculate twiddles for the fast NTT.
-	static ref ADDITIVE_NTT_AES: AdditiveNTT8b = {
+	pub(crate) static ref ADDITIVE_NTT_AES: AdditiveNTT8b = {
 		let log_h = 3;
 		let log_rate = 1;
 		SingleThreadedNTT::<AESTowerField8b>::with_domain_field::<BinaryField8b>(log_h + 2 + log_rate)
@@ -131,7 +141,7 @@ impl Vision32MDSTransform {
 
 /// This is the complete permutation function for the Vision hash which implements `Permutation`
 /// and `CryptographicPermutation` traits over `PackedAESBinary8x32b` as well as `BinaryField32b`
-#[derive(Clone, Default)]
+#[derive(Debug, Clone, Default)]
 pub struct Vision32bPermutation {
 	mds: Vision32MDSTransform,
 }
@@ -317,6 +327,137 @@ where
 	}
 }
 
+/// Vision-32b over `BinaryField32b` as a [`Hasher`], for inputs whose length is not known when
+/// hashing starts, such as the leaves of Merkle trees.
+///
+/// The input is absorbed as it arrives into a [`SpongeHasher`] over [`Vision32bPermutation`] with
+/// the injective `10*` padding, so the message length does not have to be committed to up front as
+/// with [`Vision32b`]. The digest is the first [`Vision32bDigest`] worth of the rate.
+#[derive(Debug, Clone)]
+pub struct Vision32bLeafHasher<P> {
+	sponge: SpongeHasher<BinaryField32b, Vision32bPermutation, RATE_AS_U32, 24>,
+	_p_marker: PhantomData<P>,
+}
+
+impl<P> Vision32bLeafHasher<P> {
+	fn truncate(state: [BinaryField32b; RATE_AS_U32]) -> Vision32bDigest {
+		Vision32bDigest::from_fn(|i| state[i])
+	}
+}
+
+impl<P> Hasher<P> for Vision32bLeafHasher<P>
+where
+	P: PackedExtension<BinaryField32b, PackedSubfield: PackedFieldIndexable>,
+	P::Scalar: ExtensionField<BinaryField32b>,
+{
+	type Digest = Vision32bDigest;
+
+	fn new() -> Self {
+		Self {
+			sponge: <SpongeHasher<_, _, RATE_AS_U32, 24> as Hasher<P>>::new(),
+			_p_marker: PhantomData,
+		}
+	}
+
+	fn update(&mut self, data: impl AsRef<[P]>) {
+		Hasher::<P>::update(&mut self.sponge, data);
+	}
+
+	fn chain_update(mut self, data: impl AsRef<[P]>) -> Self {
+		self.update(data);
+		self
+	}
+
+	fn finalize(self) -> Self::Digest {
+		Self::truncate(Hasher::<P>::finalize(self.sponge))
+	}
+
+	fn finalize_into(self, out: &mut MaybeUninit<Self::Digest>) {
+		out.write(self.finalize());
+	}
+
+	fn finalize_reset(&mut self) -> Self::Digest {
+		Self::truncate(Hasher::<P>::finalize_reset(&mut self.sponge))
+	}
+
+	fn finalize_into_reset(&mut self, out: &mut MaybeUninit<Self::Digest>) {
+		out.write(self.finalize_reset());
+	}
+
+	fn reset(&mut self) {
+		Hasher::<P>::reset(&mut self.sponge);
+	}
+}
+
+impl<P> MultiHasher<P> for Vision32bLeafHasher<P>
+where
+	P: PackedExtension<BinaryField32b, PackedSubfield: PackedFieldIndexable>,
+	P::Scalar: ExtensionField<BinaryField32b>,
+{
+	const BATCH_WIDTH: usize = Vision32bLanes::WIDTH;
+
+	fn hash_many_parts<'a, M>(messages: impl IntoIterator<Item = M>) -> Vec<Self::Digest>
+	where
+		P: 'a,
+		M: IntoIterator<Item = &'a [P]>,
+	{
+		let messages = messages
+			.into_iter()
+			.map(|parts| {
+				concat_parts(
+					parts
+						.into_iter()
+						.map(|part| Cow::Borrowed(P::unpack_base_scalars(part))),
+				)
+			})
+			.collect::<Vec<_>>();
+		let messages = messages.iter().map(AsRef::as_ref).collect::<Vec<_>>();
+		MultiVision32b::<BinaryField32b>::hash_many_padded::<_, BinaryField32b>(&messages)
+	}
+}
+
+impl<P> HashAlgorithmId for Vision32bLeafHasher<P> {
+	const NAME: &'static str = "vision32b-sponge";
+	const VERSION: u16 = 1;
+	const TOWER: &'static str = BinaryField32b::TOWER;
+	const DIGEST_BYTES: usize = size_of::<Vision32bDigest>();
+}
+
+/// A 512-bit to 256-bit compression function for [`Vision32bDigest`]s.
+///
+/// The output is the [`Vision32b`] hash of the concatenated inputs, which takes a single Vision
+/// permutation since both digests together fill exactly one block of the rate. Collisions of the
+/// compression function are thus collisions of the sponge.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Vision32bDigestCompression;
+
+impl PseudoCompressionFunction<Vision32bDigest, 2> for Vision32bDigestCompression {
+	fn compress(&self, input: [Vision32bDigest; 2]) -> Vision32bDigest {
+		fixed_len_hash::<Vision32bDigest, Vision32b<Vision32bDigest>>(input)
+	}
+}
+
+impl CompressionFunction<Vision32bDigest, 2> for Vision32bDigestCompression {}
+
+impl MultiPseudoCompressionFunction<Vision32bDigest, 2> for Vision32bDigestCompression {
+	const BATCH_WIDTH: usize = Vision32bLanes::WIDTH;
+
+	fn compress_many(&self, inputs: &[[Vision32bDigest; 2]]) -> Vec<Vision32bDigest> {
+		let messages = inputs
+			.iter()
+			.map(|input| input.as_slice())
+			.collect::<Vec<_>>();
+		MultiVision32b::<BinaryField32b>::hash_many(&messages)
+	}
+}
+
+impl HashAlgorithmId for Vision32bDigestCompression {
+	const NAME: &'static str = "vision32b-concat";
+	const VERSION: u16 = 1;
+	const TOWER: &'static str = BinaryField32b::TOWER;
+	const DIGEST_BYTES: usize = size_of::<Vision32bDigest>();
+}
+
 /// This structure represents fast additive NTT transformation that transforms
 /// 3 x `PackedAESBinaryField8x32b` with a different coset for each item in a single go.
 struct FastNTT {
@@ -826,6 +967,55 @@ mod tests {
 		assert_eq!(expected, out);
 	}
 
+	#[test]
+	fn test_leaf_hasher_and_compression() {
+		type Sponge = SpongeHasher<BinaryField32b, Vision32bPermutation, RATE_AS_U32, 24>;
+
+		fn sponge_hash(msg: &[BinaryField32b]) -> Vision32bDigest {
+			let state = <Sponge as Hasher<BinaryField32b>>::new()
+				.chain_update(msg)
+				.finalize();
+			Vision32bDigest::from_fn(|i| state[i])
+		}
+
+		let mut rng = thread_rng();
+		let data: [BinaryField32b; 37] =
+			array::from_fn(|_| <BinaryField32b as Field>::random(&mut rng));
+
+		let mut hasher = Vision32bLeafHasher::<BinaryField32b>::new();
+		hasher.update(&data[..20]);
+		hasher.update(&data[20..]);
+		assert_eq!(hasher.finalize_reset(), sponge_hash(&data));
+		assert_eq!(hasher.chain_update(&data[..5]).finalize(), sponge_hash(&data[..5]));
+
+		// Unlike with zero padding, trailing zeros change the digest
+		assert_ne!(
+			sponge_hash(&data[..5]),
+			sponge_hash(&[&data[..5], &[BinaryField32b::ZERO][..]].concat())
+		);
+
+		// Lengths around the block boundaries, including the empty message
+		let messages = [0, 3, 15, 16, 17, 37]
+			.map(|len| &data[..len])
+			.into_iter()
+			.cycle()
+			.take(2 * Vision32bLanes::WIDTH + 1)
+			.collect::<Vec<_>>();
+		let digests = Vision32bLeafHasher::<BinaryField32b>::hash_many(&messages);
+		for (digest, msg) in digests.iter().zip(messages) {
+			assert_eq!(*digest, sponge_hash(msg));
+		}
+
+		let inputs: [[Vision32bDigest; 2]; 5] =
+			array::from_fn(|_| array::from_fn(|_| Vision32bDigest::random(&mut rng)));
+		let compressed = Vision32bDigestCompression.compress_many(&inputs);
+		for (out, input) in compressed.iter().zip(inputs) {
+			let concat = PackedFieldIndexable::unpack_scalars(&input);
+			assert_eq!(*out, FixedLenHasherDigest::<_, Vision32b<_>>::hash(concat));
+			assert_eq!(*out, Vision32bDigestCompression.compress(input));
+		}
+	}
+
 	#[test]
 	fn test_aes_to_binary_hash() {
 		let mut rng = thread_rng();
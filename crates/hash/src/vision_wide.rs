@@ -0,0 +1,448 @@
+// Copyright 2024 Irreducible Inc.
+
+//! Vision instances over `BinaryField64b` and `BinaryField128b`.
+//!
+//! Both instances keep the 768-bit state, the 256-bit capacity and the 256-bit digest of
+//! [`Vision32b`](crate::Vision32b), with 12 and 6 state elements respectively, but have more
+//! rounds, see `NUM_ROUNDS_WIDE`. Unlike Vision-32b, which is evaluated over `AESTowerField32b`,
+//! they are defined directly in the canonical tower basis, so that a recursive verifier working
+//! over `BinaryField128b` does not need to decompose the state into subfield elements.
+//!
+//! The S-boxes are the inversion followed by a linearized affine polynomial
+//! `B(x) = b_0 + b_1 x + b_2 x^2 + b_3 x^4` or its inverse. The MDS matrices are Cauchy matrices
+//! `1 / (x_i + y_j)` over `BinaryField8b`, with `x_i = i` and `y_j = m + j` for a state of `m`
+//! elements. The coefficients of `B` and the round constants are read from SHAKE256 of the
+//! instance name, see `testgen/hash/vision_wide.py` which generated the constants and the known
+//! answers of the tests.
+//!
+//! # Experimental
+//!
+//! These instances have not been analyzed and their number of rounds is not derived from any
+//! attack, see `NUM_ROUNDS_WIDE`. They are not meant for use in proofs.
+
+use crate::{
+	hasher::{FixedLenHasher, HashError},
+	vision_wide_constants::*,
+};
+use binius_field::{
+	linear_transformation::{FieldLinearTransformation, Transformation},
+	BinaryField, BinaryField128b, BinaryField64b, BinaryField8b, ExtensionField, Field,
+	PackedBinaryField2x128b, PackedBinaryField4x64b, PackedExtension, PackedField,
+	PackedFieldIndexable,
+};
+use p3_symmetric::{CryptographicPermutation, Permutation};
+use std::marker::PhantomData;
+
+/// Number of rounds of the Vision-64b and Vision-128b permutations, each with two S-box layers.
+///
+/// This is a placeholder, not the result of a security analysis. The 8 rounds of Vision-32b come
+/// from its analysis for a state of 24 elements and do not carry over: the algebraic attacks that
+/// bound the number of rounds model every state element as a variable, and the 12 and 6 wider
+/// elements here give a system with fewer variables, which makes Gröbner basis attacks cheaper.
+/// Deriving the number of rounds for these instances is left open, which is why they are
+/// experimental.
+pub(crate) const NUM_ROUNDS_WIDE: usize = 10;
+
+/// The output digest of [`Vision64b`]
+pub type Vision64bDigest = PackedBinaryField4x64b;
+
+/// The output digest of [`Vision128b`]
+pub type Vision128bDigest = PackedBinaryField2x128b;
+
+#[inline]
+fn add_round_key<F: Field, const N: usize>(state: &mut [F; N], key: &[F; N]) {
+	for (x, &k) in state.iter_mut().zip(key) {
+		*x += k;
+	}
+}
+
+#[inline]
+fn sbox_step<F: BinaryField>(
+	state: &mut [F],
+	affine_trans: &FieldLinearTransformation<F>,
+	constant: F,
+) {
+	for x in state {
+		*x = affine_trans.transform(&x.invert_or_zero()) + constant;
+	}
+}
+
+/// Multiplies the state by an MDS matrix with coefficients in `BinaryField8b`
+#[inline]
+fn mds_step<F: ExtensionField<BinaryField8b>, const N: usize>(
+	state: &mut [F; N],
+	mds: &[[u8; N]; N],
+) {
+	let input = *state;
+	for (out, row) in state.iter_mut().zip(mds) {
+		*out = row
+			.iter()
+			.zip(input)
+			.fold(F::ZERO, |acc, (&coeff, x)| acc + x * BinaryField8b::new(coeff));
+	}
+}
+
+macro_rules! impl_vision_instance {
+	(
+		$(#[$perm_meta:meta])*
+		$perm:ident,
+		$(#[$hasher_meta:meta])*
+		$hasher:ident<P> -> $digest:ty,
+		$field:ty,
+		state_len: $state_len:literal,
+		rate: $rate:literal,
+		affine_fwd: ($affine_fwd:ident, $affine_fwd_const:ident),
+		affine_inv: ($affine_inv:ident, $affine_inv_const:ident),
+		mds: $mds:ident,
+		round_keys: $round_keys:ident $(,)?
+	) => {
+		$(#[$perm_meta])*
+		#[derive(Debug, Clone, Copy, Default)]
+		pub struct $perm;
+
+		impl $perm {
+			const FWD_TRANS: FieldLinearTransformation<$field> =
+				FieldLinearTransformation::new_const(&$affine_fwd);
+			const INV_TRANS: FieldLinearTransformation<$field> =
+				FieldLinearTransformation::new_const(&$affine_inv);
+
+			pub fn new() -> Self {
+				Self
+			}
+		}
+
+		impl Permutation<[$field; $state_len]> for $perm {
+			fn permute_mut(&self, input: &mut [$field; $state_len]) {
+				add_round_key(input, &$round_keys[0]);
+				for r in 0..NUM_ROUNDS_WIDE {
+					sbox_step(input, &Self::INV_TRANS, $affine_inv_const);
+					mds_step(input, &$mds);
+					add_round_key(input, &$round_keys[1 + 2 * r]);
+					sbox_step(input, &Self::FWD_TRANS, $affine_fwd_const);
+					mds_step(input, &$mds);
+					add_round_key(input, &$round_keys[2 + 2 * r]);
+				}
+			}
+		}
+
+		impl CryptographicPermutation<[$field; $state_len]> for $perm {}
+
+		$(#[$hasher_meta])*
+		#[derive(Debug, Clone)]
+		pub struct $hasher<P> {
+			// The hashed state
+			state: [$field; $state_len],
+			// The length that are committing to hash
+			committed_len: u64,
+			// Current length we have hashed so far
+			current_len: u64,
+			_p_marker: PhantomData<P>,
+		}
+
+		impl<P> FixedLenHasher<P> for $hasher<P>
+		where
+			P: PackedExtension<$field, PackedSubfield: PackedFieldIndexable>,
+			P::Scalar: ExtensionField<$field>,
+		{
+			type Digest = $digest;
+
+			fn new(msg_len: u64) -> Self {
+				let mut this = Self {
+					state: [<$field>::ZERO; $state_len],
+					committed_len: msg_len,
+					current_len: 0,
+					_p_marker: PhantomData,
+				};
+				this.reset();
+				this
+			}
+
+			fn update(&mut self, msg: impl AsRef<[P]>) {
+				let msg = msg.as_ref();
+				if msg.is_empty() {
+					return;
+				}
+
+				let cur_block = (self.current_len as usize * P::WIDTH * P::Scalar::DEGREE) % $rate;
+				for (i, &x) in P::unpack_base_scalars(msg).iter().enumerate() {
+					let block_idx = (cur_block + i) % $rate;
+					self.state[block_idx] = x;
+					if block_idx == $rate - 1 {
+						$perm.permute_mut(&mut self.state);
+					}
+				}
+
+				self.current_len = self
+					.current_len
+					.checked_add(msg.len() as u64)
+					.expect("Overflow on message length");
+			}
+
+			fn chain_update(mut self, msg: impl AsRef<[P]>) -> Self {
+				self.update(msg);
+				self
+			}
+
+			fn finalize(mut self) -> Result<Self::Digest, HashError> {
+				if self.current_len < self.committed_len {
+					return Err(HashError::NotEnoughData {
+						committed: self.committed_len,
+						hashed: self.current_len,
+					});
+				}
+
+				if self.current_len > self.committed_len {
+					return Err(HashError::TooMuchData {
+						committed: self.committed_len,
+						received: self.current_len,
+					});
+				}
+
+				let cur_block = (self.current_len as usize * P::WIDTH * P::Scalar::DEGREE) % $rate;
+				if cur_block != 0 {
+					// Pad and absorb
+					self.state[cur_block..$rate].fill(<$field>::ZERO);
+					$perm.permute_mut(&mut self.state);
+				}
+
+				Ok(Self::Digest::from_fn(|i| self.state[i]))
+			}
+
+			fn reset(&mut self) {
+				self.state.fill(<$field>::ZERO);
+
+				// Write the byte-length of the message into the initial state
+				let bytes_per_elem = P::WIDTH
+					* P::Scalar::DEGREE
+					* <$field as ExtensionField<BinaryField8b>>::DEGREE;
+				let msg_len_bytes = self
+					.committed_len
+					.checked_mul(bytes_per_elem as u64)
+					.expect("Overflow on message length");
+				self.state[$rate] = <$field>::new(msg_len_bytes.into());
+			}
+		}
+	};
+}
+
+impl_vision_instance!(
+	/// The Vision-64b permutation on a state of 12 `BinaryField64b` elements.
+	///
+	/// Experimental: its number of rounds is not derived from a security analysis, so it is not
+	/// meant for use in proofs.
+	Vision64bPermutation,
+	/// The Vision-64b hash over `BinaryField64b`, with a rate of 8 elements and a capacity of 4.
+	/// Here the generic `P` represents the input type to the `update` function.
+	///
+	/// Experimental: its number of rounds is not derived from a security analysis, so it is not
+	/// meant for use in proofs.
+	Vision64b<P> -> Vision64bDigest,
+	BinaryField64b,
+	state_len: 12,
+	rate: 8,
+	affine_fwd: (VISION64B_AFFINE_FWD, VISION64B_AFFINE_FWD_CONST),
+	affine_inv: (VISION64B_AFFINE_INV, VISION64B_AFFINE_INV_CONST),
+	mds: VISION64B_MDS,
+	round_keys: VISION64B_ROUND_KEYS,
+);
+
+impl_vision_instance!(
+	/// The Vision-128b permutation on a state of 6 `BinaryField128b` elements.
+	///
+	/// Experimental: its number of rounds is not derived from a security analysis, so it is not
+	/// meant for use in proofs.
+	Vision128bPermutation,
+	/// The Vision-128b hash over `BinaryField128b`, with a rate of 4 elements and a capacity of 2.
+	/// Here the generic `P` represents the input type to the `update` function.
+	///
+	/// Experimental: its number of rounds is not derived from a security analysis, so it is not
+	/// meant for use in proofs.
+	Vision128b<P> -> Vision128bDigest,
+	BinaryField128b,
+	state_len: 6,
+	rate: 4,
+	affine_fwd: (VISION128B_AFFINE_FWD, VISION128B_AFFINE_FWD_CONST),
+	affine_inv: (VISION128B_AFFINE_INV, VISION128B_AFFINE_INV_CONST),
+	mds: VISION128B_MDS,
+	round_keys: VISION128B_ROUND_KEYS,
+);
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{FixedLenHasherDigest, HashDigest};
+	use binius_field::PackedBinaryField2x64b;
+	use rand::thread_rng;
+	use std::array;
+
+	fn hash_64b(msg: &[BinaryField64b]) -> Vision64bDigest {
+		FixedLenHasherDigest::<_, Vision64b<_>>::hash(msg)
+	}
+
+	fn hash_128b(msg: &[BinaryField128b]) -> Vision128bDigest {
+		FixedLenHasherDigest::<_, Vision128b<_>>::hash(msg)
+	}
+
+	fn digest_64b(expected: [u64; 4]) -> Vision64bDigest {
+		Vision64bDigest::from_fn(|i| BinaryField64b::new(expected[i]))
+	}
+
+	fn digest_128b(expected: [u128; 2]) -> Vision128bDigest {
+		Vision128bDigest::from_fn(|i| BinaryField128b::new(expected[i]))
+	}
+
+	#[test]
+	fn test_affine_transformations_are_inverse() {
+		let mut rng = thread_rng();
+		for _ in 0..16 {
+			let x = <BinaryField64b as Field>::random(&mut rng);
+			let y = Vision64bPermutation::FWD_TRANS.transform(&x) + VISION64B_AFFINE_FWD_CONST;
+			let z = Vision64bPermutation::INV_TRANS.transform(&y) + VISION64B_AFFINE_INV_CONST;
+			assert_eq!(x, z);
+
+			let x = <BinaryField128b as Field>::random(&mut rng);
+			let y = Vision128bPermutation::FWD_TRANS.transform(&x) + VISION128B_AFFINE_FWD_CONST;
+			let z = Vision128bPermutation::INV_TRANS.transform(&y) + VISION128B_AFFINE_INV_CONST;
+			assert_eq!(x, z);
+		}
+	}
+
+	#[test]
+	fn test_permutation_known_answers() {
+		let out = Vision64bPermutation.permute(array::from_fn(|i| BinaryField64b::new(i as u64)));
+		let expected = [
+			0xca414b8dc6774398,
+			0x206e3c85170ff86c,
+			0xdfc54cd4b702d1a7,
+			0x2bc6a394f0834e92,
+			0x128aaef4206b0ffe,
+			0xce5afc70d35e5a19,
+			0x92155572cf44448f,
+			0xb63d926428494fa0,
+			0x31191cbc0602a926,
+			0xa6784f8c100ce414,
+			0x23c3876f965bebf5,
+			0x9a3d0b67722ffd93,
+		];
+		assert_eq!(out, expected.map(BinaryField64b::new));
+
+		let out =
+			Vision128bPermutation.permute(array::from_fn(|i| BinaryField128b::new(i as u128)));
+		let expected = [
+			0x32f67b949aeaca855a2d63f99c43cb18,
+			0x6b174bc9efb8062da3c12aa8d5839ab0,
+			0xffc06af37a968a2c699c9d836b087a06,
+			0xb2be2f19ff8a8df9c54b27759da6d8f4,
+			0x007b43c90b78df84138041dc1251f04d,
+			0xf27f663cf380fbd92fb99530af8de77d,
+		];
+		assert_eq!(out, expected.map(BinaryField128b::new));
+	}
+
+	#[test]
+	fn test_simple_hash() {
+		assert_eq!(
+			hash_64b(&[BinaryField64b::new(0xdeadbeef)]),
+			digest_64b([
+				0xa3447a3bdefbf32a,
+				0x0d9d356445e739cc,
+				0xdcc8e107075bab14,
+				0x893f72647075d217
+			])
+		);
+		assert_eq!(
+			hash_128b(&[BinaryField128b::new(0xdeadbeef)]),
+			digest_128b([
+				0x6dd7b799d935c7b589dee9e73817f993,
+				0x62a66f217b1b2d8bc3d2b964cec5788a
+			])
+		);
+	}
+
+	#[test]
+	fn test_multi_block_aligned() {
+		let msg = (0..16).map(BinaryField64b::new).collect::<Vec<_>>();
+		assert_eq!(
+			hash_64b(&msg),
+			digest_64b([
+				0xe590ed418d867da5,
+				0x4fd99aacda241a24,
+				0x734c56c7040d7319,
+				0x73f6bff32476e2d5
+			])
+		);
+
+		let msg = (0..8).map(BinaryField128b::new).collect::<Vec<_>>();
+		assert_eq!(
+			hash_128b(&msg),
+			digest_128b([
+				0x18b52b3ecf796776ad1fd9ea37ba8df5,
+				0xac69962a98c1b943c6ac2e9b7900f7d4
+			])
+		);
+	}
+
+	#[test]
+	fn test_multi_block_unaligned() {
+		let msg = (0..11).map(BinaryField64b::new).collect::<Vec<_>>();
+		assert_eq!(
+			hash_64b(&msg),
+			digest_64b([
+				0x994d097cfdb0c682,
+				0x3be652f69296d680,
+				0x1f1b62d85466d348,
+				0x294f0564904bde71
+			])
+		);
+
+		let msg = (0..7).map(BinaryField128b::new).collect::<Vec<_>>();
+		assert_eq!(
+			hash_128b(&msg),
+			digest_128b([
+				0xa4dc1449830b2a0f6e8bb08459df39f8,
+				0x34b73a67a79fc6ee01b9d27aec7d69f7
+			])
+		);
+	}
+
+	#[test]
+	fn test_extensions_and_packings() {
+		let mut rng = thread_rng();
+		let data = (0..5)
+			.map(|_| <BinaryField128b as Field>::random(&mut rng))
+			.collect::<Vec<_>>();
+		let data_64b = data
+			.iter()
+			.flat_map(ExtensionField::<BinaryField64b>::iter_bases)
+			.collect::<Vec<_>>();
+		let data_packed = data_64b
+			.chunks(2)
+			.map(|x| PackedBinaryField2x64b::from_fn(|i| x[i]))
+			.collect::<Vec<_>>();
+
+		let expected = hash_64b(&data_64b);
+		assert_eq!(FixedLenHasherDigest::<_, Vision64b<_>>::hash(&data), expected);
+		assert_eq!(FixedLenHasherDigest::<_, Vision64b<_>>::hash(&data_packed), expected);
+	}
+
+	#[test]
+	fn test_fixed_length_errors() {
+		let hasher = Vision128b::<BinaryField128b>::new(3);
+		assert!(matches!(
+			hasher.chain_update([BinaryField128b::ZERO; 2]).finalize(),
+			Err(HashError::NotEnoughData {
+				committed: 3,
+				hashed: 2
+			})
+		));
+
+		let hasher = Vision64b::<BinaryField64b>::new(3);
+		assert!(matches!(
+			hasher.chain_update([BinaryField64b::ZERO; 4]).finalize(),
+			Err(HashError::TooMuchData {
+				committed: 3,
+				received: 4
+			})
+		));
+	}
+}
@@ -0,0 +1,909 @@
+// Copyright 2024 Irreducible Inc.
+
+//! Constants of the Vision-64b and Vision-128b instances, generated by
+//! `testgen/hash/vision_wide.py`.
+
+use crate::vision_wide::NUM_ROUNDS_WIDE;
+use binius_field::{BinaryField128b, BinaryField64b};
+
+pub const VISION64B_AFFINE_FWD: [BinaryField64b; 64] = [
+	BinaryField64b::new(0x042035eb7810dde5),
+	BinaryField64b::new(0xb875b3c818340e93),
+	BinaryField64b::new(0xc41844b12480d95b),
+	BinaryField64b::new(0xc88f9e51c25a211b),
+	BinaryField64b::new(0x70124599a5dadfb8),
+	BinaryField64b::new(0xa21f9509107fac2a),
+	BinaryField64b::new(0x1e4cba41bc583a22),
+	BinaryField64b::new(0x59f14d3f653c4917),
+	BinaryField64b::new(0x0a4fd9a619bf63a5),
+	BinaryField64b::new(0x49eba3b441697e85),
+	BinaryField64b::new(0xd048263780b529ea),
+	BinaryField64b::new(0xc4c767bb825f6cad),
+	BinaryField64b::new(0x6fea8aa941b4e308),
+	BinaryField64b::new(0x4aa065a81c965916),
+	BinaryField64b::new(0x046e508bb551aefc),
+	BinaryField64b::new(0x2a75b1f9b10918c5),
+	BinaryField64b::new(0x2154f6419fbf195d),
+	BinaryField64b::new(0x8b2a9c877115facb),
+	BinaryField64b::new(0xb25b3e0e0c4dc287),
+	BinaryField64b::new(0x55534a046ed68985),
+	BinaryField64b::new(0x108c1f2cbd40f52d),
+	BinaryField64b::new(0xbb38e5e1b817dd2a),
+	BinaryField64b::new(0x70aa75a6903c25b2),
+	BinaryField64b::new(0xf8daca38ab79c746),
+	BinaryField64b::new(0x6326d05a9e7f5f2f),
+	BinaryField64b::new(0x5b0237a661bc3025),
+	BinaryField64b::new(0x5d6e76cec3f55273),
+	BinaryField64b::new(0x17c1a641b5bcc001),
+	BinaryField64b::new(0xec0fe403cfdfe732),
+	BinaryField64b::new(0x41e042a82d045697),
+	BinaryField64b::new(0xdb0d4ff456375013),
+	BinaryField64b::new(0x1eb5f44ee45042db),
+	BinaryField64b::new(0x6f339c89e14ca89c),
+	BinaryField64b::new(0x68bb0e6a31952448),
+	BinaryField64b::new(0x7cb1af5d89f01e8f),
+	BinaryField64b::new(0x58c344bc69c9e8d0),
+	BinaryField64b::new(0x3f7338da10c9aa18),
+	BinaryField64b::new(0x3b4d294d0a8b04e6),
+	BinaryField64b::new(0xa9e50b9343d10f3e),
+	BinaryField64b::new(0x15bf7e0f60312d46),
+	BinaryField64b::new(0x9c824ea7a28194fb),
+	BinaryField64b::new(0xdd847203cb77614c),
+	BinaryField64b::new(0x21d1538051e78801),
+	BinaryField64b::new(0x44cf82e1ddfa3a9b),
+	BinaryField64b::new(0xb4f6aa20e1fee86e),
+	BinaryField64b::new(0x3de5f181d6b0ab72),
+	BinaryField64b::new(0xb9bd82b26ba2e29d),
+	BinaryField64b::new(0x6d5dc85aa74ebeb5),
+	BinaryField64b::new(0x876bbd2cf6a79c52),
+	BinaryField64b::new(0xd7ba7b4d3185894c),
+	BinaryField64b::new(0xcb17374574751f5b),
+	BinaryField64b::new(0xd624055cd94f691f),
+	BinaryField64b::new(0x9bd242d7aed86e3e),
+	BinaryField64b::new(0x97340865bcb5abf7),
+	BinaryField64b::new(0x56a1e8e4188f2569),
+	BinaryField64b::new(0x7acf847d8403af0e),
+	BinaryField64b::new(0x669c44a2d20c7528),
+	BinaryField64b::new(0xaf426930d5cee515),
+	BinaryField64b::new(0xe9d9eebaed1dbc55),
+	BinaryField64b::new(0xe47ace88f277064c),
+	BinaryField64b::new(0x3e314f565e4fee2b),
+	BinaryField64b::new(0x07b29bff83786d5c),
+	BinaryField64b::new(0x83579115dfe0162b),
+	BinaryField64b::new(0x51b979108f5cba74),
+];
+
+pub const VISION64B_AFFINE_FWD_CONST: BinaryField64b = BinaryField64b::new(0xe7b18c78ec6de864);
+
+pub const VISION64B_AFFINE_INV: [BinaryField64b; 64] = [
+	BinaryField64b::new(0xb24c999e3714edd9),
+	BinaryField64b::new(0x9ff27ecca115daa4),
+	BinaryField64b::new(0x8d61fbcc6e9d111c),
+	BinaryField64b::new(0xa6fd9d15166076c7),
+	BinaryField64b::new(0xf4b60b387f400752),
+	BinaryField64b::new(0xdd0c722d3671c682),
+	BinaryField64b::new(0x4caf47655c6cd6c2),
+	BinaryField64b::new(0x3b840592d69b3d80),
+	BinaryField64b::new(0xcab0bf6e15494a36),
+	BinaryField64b::new(0xfdbe9d7da49470de),
+	BinaryField64b::new(0x33580a9ed094c9df),
+	BinaryField64b::new(0xb1838f055e1f8cc6),
+	BinaryField64b::new(0x3b996adca515ceb1),
+	BinaryField64b::new(0xf19fd2db0494ecb3),
+	BinaryField64b::new(0xed755138e21d4a83),
+	BinaryField64b::new(0x0f401501fd561d84),
+	BinaryField64b::new(0x5836fd1917252162),
+	BinaryField64b::new(0x00df08e7452f768f),
+	BinaryField64b::new(0xabfbb544f5c1e7e3),
+	BinaryField64b::new(0x8f88fee17c90638e),
+	BinaryField64b::new(0xab991b981f62919f),
+	BinaryField64b::new(0x78e38dce74dddb5c),
+	BinaryField64b::new(0xd6c5a26648caab7a),
+	BinaryField64b::new(0xf720af34cfc78e63),
+	BinaryField64b::new(0x7455d9ed02e4e931),
+	BinaryField64b::new(0xc6641114714820b1),
+	BinaryField64b::new(0x01aad28d575be522),
+	BinaryField64b::new(0x988541a339b03d1a),
+	BinaryField64b::new(0xd5e1f37b1bef3e72),
+	BinaryField64b::new(0x5ff45239592d0b12),
+	BinaryField64b::new(0xdd2789a8f506024a),
+	BinaryField64b::new(0xde4b0e7ea1772abb),
+	BinaryField64b::new(0x4cafc262c14481e6),
+	BinaryField64b::new(0x61aba71460ccc09d),
+	BinaryField64b::new(0xc378d743f99fbdf1),
+	BinaryField64b::new(0xc523e08ee7e4fa70),
+	BinaryField64b::new(0x4488bcb54b2079fa),
+	BinaryField64b::new(0xb9d365cafb78c4ab),
+	BinaryField64b::new(0x49b8f439e5393851),
+	BinaryField64b::new(0x35c9e38e864b1acd),
+	BinaryField64b::new(0xb964ac3a8b8bbc6a),
+	BinaryField64b::new(0x171ed68ec91e7d07),
+	BinaryField64b::new(0xc89d1a9207070f17),
+	BinaryField64b::new(0xea8da8bc72d5f919),
+	BinaryField64b::new(0xa3d0738201386e4e),
+	BinaryField64b::new(0xa8d1da962fd5c180),
+	BinaryField64b::new(0x8aaad5773d75d3a8),
+	BinaryField64b::new(0x96a375ec79aad954),
+	BinaryField64b::new(0x5b4617f2af7f57e7),
+	BinaryField64b::new(0xa9eb4102fabdb83c),
+	BinaryField64b::new(0xdb70dd5e8e54662a),
+	BinaryField64b::new(0xc087321dfcdcc7b7),
+	BinaryField64b::new(0x3654b531297964b4),
+	BinaryField64b::new(0x476395117c0a5b17),
+	BinaryField64b::new(0x816ad00ec3bc59ca),
+	BinaryField64b::new(0x3c7caa6f2fde9f45),
+	BinaryField64b::new(0xe81e85fe8f0e9e9b),
+	BinaryField64b::new(0xa6fbd003d53928ea),
+	BinaryField64b::new(0x70fd49615588c6aa),
+	BinaryField64b::new(0x9f82c023d8b9dacb),
+	BinaryField64b::new(0x4d5f2f0e4bc5db1b),
+	BinaryField64b::new(0x50db061131b2ead8),
+	BinaryField64b::new(0xa8741c30f37675c2),
+	BinaryField64b::new(0xd0ebe21f2d8a4595),
+];
+
+pub const VISION64B_AFFINE_INV_CONST: BinaryField64b = BinaryField64b::new(0x6c1d7ba30b4a15a3);
+
+#[rustfmt::skip]
+pub const VISION64B_MDS: [[u8; 12]; 12] = [
+	[0x0b, 0x08, 0x05, 0x07, 0x14, 0x67, 0x94, 0x7b, 0x10, 0x66, 0x9e, 0x7e],
+	[0x08, 0x0b, 0x07, 0x05, 0x67, 0x14, 0x7b, 0x94, 0x66, 0x10, 0x7e, 0x9e],
+	[0x05, 0x07, 0x0b, 0x08, 0x94, 0x7b, 0x14, 0x67, 0x9e, 0x7e, 0x10, 0x66],
+	[0x07, 0x05, 0x08, 0x0b, 0x7b, 0x94, 0x67, 0x14, 0x7e, 0x9e, 0x66, 0x10],
+	[0x0d, 0x0a, 0x09, 0x0c, 0x10, 0x66, 0x9e, 0x7e, 0x14, 0x67, 0x94, 0x7b],
+	[0x0a, 0x0d, 0x0c, 0x09, 0x66, 0x10, 0x7e, 0x9e, 0x67, 0x14, 0x7b, 0x94],
+	[0x09, 0x0c, 0x0d, 0x0a, 0x9e, 0x7e, 0x10, 0x66, 0x94, 0x7b, 0x14, 0x67],
+	[0x0c, 0x09, 0x0a, 0x0d, 0x7e, 0x9e, 0x66, 0x10, 0x7b, 0x94, 0x67, 0x14],
+	[0x06, 0x0e, 0x04, 0x0f, 0xd2, 0x81, 0x27, 0x4b, 0xd1, 0x8f, 0x2f, 0x42],
+	[0x0e, 0x06, 0x0f, 0x04, 0x81, 0xd2, 0x4b, 0x27, 0x8f, 0xd1, 0x42, 0x2f],
+	[0x04, 0x0f, 0x06, 0x0e, 0x27, 0x4b, 0xd2, 0x81, 0x2f, 0x42, 0xd1, 0x8f],
+	[0x0f, 0x04, 0x0e, 0x06, 0x4b, 0x27, 0x81, 0xd2, 0x42, 0x2f, 0x8f, 0xd1],
+];
+
+#[rustfmt::skip]
+pub const VISION64B_ROUND_KEYS: [[BinaryField64b; 12]; 2 * NUM_ROUNDS_WIDE + 1] = [
+	[
+		BinaryField64b::new(0x5ce5e60e93befe02),
+		BinaryField64b::new(0x7f4bcb8b0446828a),
+		BinaryField64b::new(0x9ccf56a1045de139),
+		BinaryField64b::new(0xb6059e67944315b2),
+		BinaryField64b::new(0xc52d4a6abad0e82c),
+		BinaryField64b::new(0x104cc29075af0414),
+		BinaryField64b::new(0x78190780dd3b4ac1),
+		BinaryField64b::new(0x6a4bcaf838def183),
+		BinaryField64b::new(0xea25067da20d573f),
+		BinaryField64b::new(0xd4a9cfdc8c3c8ccf),
+		BinaryField64b::new(0x9b52d6eefb3027da),
+		BinaryField64b::new(0x34eecfab5eb375de),
+	],
+	[
+		BinaryField64b::new(0x5aa3c4ba19200618),
+		BinaryField64b::new(0xaa13036ec407a817),
+		BinaryField64b::new(0x4f5b677491863b6e),
+		BinaryField64b::new(0x64dc8457d8615b67),
+		BinaryField64b::new(0x573bd05e942a4865),
+		BinaryField64b::new(0xc5c471e8020143f7),
+		BinaryField64b::new(0x0182d364028b54a2),
+		BinaryField64b::new(0x060a37aa8e0b353f),
+		BinaryField64b::new(0x13395a71cb6339a6),
+		BinaryField64b::new(0x5abb4fc3f35e9c11),
+		BinaryField64b::new(0x65fdc04b9c6dd043),
+		BinaryField64b::new(0x24baf1972f1c849c),
+	],
+	[
+		BinaryField64b::new(0x484fc6f08dde6986),
+		BinaryField64b::new(0x3abeb9c49b1c3436),
+		BinaryField64b::new(0xc26350736dd622d4),
+		BinaryField64b::new(0x9263a8a31377bca9),
+		BinaryField64b::new(0x8e7080903022c153),
+		BinaryField64b::new(0x2d864008c9dd3846),
+		BinaryField64b::new(0xd841d76c28788bdc),
+		BinaryField64b::new(0x2aa2e601bce774d6),
+		BinaryField64b::new(0x4296061525f20de9),
+		BinaryField64b::new(0x5179ed6e43ff2698),
+		BinaryField64b::new(0x10b163da001957c8),
+		BinaryField64b::new(0x5e05a0f8bcddc163),
+	],
+	[
+		BinaryField64b::new(0xb835432aab9aa122),
+		BinaryField64b::new(0x82fa2aebcbd00db5),
+		BinaryField64b::new(0xb244b55e14f175bc),
+		BinaryField64b::new(0xdb8f9a56f7486a10),
+		BinaryField64b::new(0x8d00efafb069a488),
+		BinaryField64b::new(0x1f7c74fc0d8b7241),
+		BinaryField64b::new(0xfc7afba06becafe1),
+		BinaryField64b::new(0x66b27a94cd793e1d),
+		BinaryField64b::new(0xd872aa6527553247),
+		BinaryField64b::new(0x6b0508f4ec1e48de),
+		BinaryField64b::new(0x7074f34785ee7333),
+		BinaryField64b::new(0x7266bec3d972d54d),
+	],
+	[
+		BinaryField64b::new(0xce3dabd283bd9254),
+		BinaryField64b::new(0x82ea827a57a8f1b4),
+		BinaryField64b::new(0x0243a298c2ba8a44),
+		BinaryField64b::new(0x8c6d10c21fce00ea),
+		BinaryField64b::new(0x19539e7b78c55f34),
+		BinaryField64b::new(0x19d30921e2f04423),
+		BinaryField64b::new(0x5d25d728cb4827e2),
+		BinaryField64b::new(0x64b98c570b3e56f2),
+		BinaryField64b::new(0x5688044df0720235),
+		BinaryField64b::new(0x34a28563b7e671d8),
+		BinaryField64b::new(0xee8de4aa1172d7fb),
+		BinaryField64b::new(0x03d5b7e6096a3b1e),
+	],
+	[
+		BinaryField64b::new(0x7dffc4143959be84),
+		BinaryField64b::new(0x3b5f6fdd00eb7d24),
+		BinaryField64b::new(0x2abf782612931b39),
+		BinaryField64b::new(0x1dd944717ba0398e),
+		BinaryField64b::new(0x35994b57d5148a70),
+		BinaryField64b::new(0xf7425543c4e0abc1),
+		BinaryField64b::new(0x14641f036417d035),
+		BinaryField64b::new(0x3148525b87325764),
+		BinaryField64b::new(0x2f091f41ee91bd05),
+		BinaryField64b::new(0x5e6bae38290d293c),
+		BinaryField64b::new(0x2c72a9ec431260a4),
+		BinaryField64b::new(0x2aaa145a199c6ca7),
+	],
+	[
+		BinaryField64b::new(0x19fbf2137f5da020),
+		BinaryField64b::new(0x4cf78b4fc7b18f2c),
+		BinaryField64b::new(0x5bd8f8e3e0f3dde3),
+		BinaryField64b::new(0x72aeccdea6052794),
+		BinaryField64b::new(0x1f483ac4c70caef7),
+		BinaryField64b::new(0xc8458c591c6de721),
+		BinaryField64b::new(0x24866edd9cbeba18),
+		BinaryField64b::new(0xe129d6e1d714278c),
+		BinaryField64b::new(0xbd78aec340469a52),
+		BinaryField64b::new(0x447bba15ca0e0050),
+		BinaryField64b::new(0x429817518aa39a14),
+		BinaryField64b::new(0x5aa657aeb3688300),
+	],
+	[
+		BinaryField64b::new(0xd56457bc83a75e1b),
+		BinaryField64b::new(0x83d7fe2baeee4dcd),
+		BinaryField64b::new(0xf37d2d7ae2970298),
+		BinaryField64b::new(0x97e4fa53631f7d05),
+		BinaryField64b::new(0xc7035773eb96bbba),
+		BinaryField64b::new(0x3ee87a9527be4b94),
+		BinaryField64b::new(0x01e0f740d1c3623c),
+		BinaryField64b::new(0x7ffb2a64609bc315),
+		BinaryField64b::new(0x4764cbb25a9b431a),
+		BinaryField64b::new(0xac145e6841d76074),
+		BinaryField64b::new(0xf7c278f4ef5bcbe6),
+		BinaryField64b::new(0x49e81edbb2d6898c),
+	],
+	[
+		BinaryField64b::new(0x6f59a20151e48673),
+		BinaryField64b::new(0xc5694e19eb82bcfa),
+		BinaryField64b::new(0x4380114e57b08267),
+		BinaryField64b::new(0x2bf88be96af102a1),
+		BinaryField64b::new(0xcc2642da506185ba),
+		BinaryField64b::new(0xfb19af35633f2c4b),
+		BinaryField64b::new(0x8a1641e55a786fc1),
+		BinaryField64b::new(0x12ce2bc64ad9cb26),
+		BinaryField64b::new(0xee2746c8b2e619dc),
+		BinaryField64b::new(0x2e07d4864a5dfabc),
+		BinaryField64b::new(0x8f6a6187ae02f0d3),
+		BinaryField64b::new(0x33c970e67b13bcf3),
+	],
+	[
+		BinaryField64b::new(0x545166e44e05b6c3),
+		BinaryField64b::new(0x64d110c56276d66d),
+		BinaryField64b::new(0xfa2a874ced31db4c),
+		BinaryField64b::new(0xbef9b88767715e80),
+		BinaryField64b::new(0x20ce8e1f7c84f6d4),
+		BinaryField64b::new(0xefb7880b5cf8b737),
+		BinaryField64b::new(0x20ee058b3cb70e0b),
+		BinaryField64b::new(0xd07d0eeb56040d7d),
+		BinaryField64b::new(0x5fb4e17e3f5c3468),
+		BinaryField64b::new(0x8fb41874356bd5ab),
+		BinaryField64b::new(0xbc4b3b0a5508bc36),
+		BinaryField64b::new(0x9a0e814efb328416),
+	],
+	[
+		BinaryField64b::new(0x511a6b6c79f87845),
+		BinaryField64b::new(0xde2f35f08cf7c4ba),
+		BinaryField64b::new(0x3524ac71723358a5),
+		BinaryField64b::new(0xb901feec1c9c604f),
+		BinaryField64b::new(0xf777da63c6f76fab),
+		BinaryField64b::new(0x3f0c42329a7159f3),
+		BinaryField64b::new(0x15ae5449cf7a5bbf),
+		BinaryField64b::new(0x4f67d28b5638f659),
+		BinaryField64b::new(0x99f86e5a9d3cd60a),
+		BinaryField64b::new(0xbff068dde3834d6c),
+		BinaryField64b::new(0x52506590e03cf47d),
+		BinaryField64b::new(0x1fba0602165b8a46),
+	],
+	[
+		BinaryField64b::new(0x97f02f5de9da5af2),
+		BinaryField64b::new(0x0b0d028e5cd8c326),
+		BinaryField64b::new(0x908b7ef44bf0016c),
+		BinaryField64b::new(0x4400ea8d722ac0b8),
+		BinaryField64b::new(0x5ba3d03a38ec8e31),
+		BinaryField64b::new(0xb10792e9ffe3138a),
+		BinaryField64b::new(0xb446391a3790ee76),
+		BinaryField64b::new(0x34ce3109695b8a16),
+		BinaryField64b::new(0x1277aedbee3ff39a),
+		BinaryField64b::new(0xea37b8d659843115),
+		BinaryField64b::new(0xc6465c4b97f794ee),
+		BinaryField64b::new(0x0b33c34f572b484c),
+	],
+	[
+		BinaryField64b::new(0x22c5b40df7ad6247),
+		BinaryField64b::new(0x524d11fceeb53a2d),
+		BinaryField64b::new(0x458ca37f6ad3ee5d),
+		BinaryField64b::new(0x3af3e6cffc6bc387),
+		BinaryField64b::new(0xe33b70a81d5e38f5),
+		BinaryField64b::new(0x6a302df9b3119f31),
+		BinaryField64b::new(0x43204af0e2910eeb),
+		BinaryField64b::new(0xc9262df34613fc72),
+		BinaryField64b::new(0x7bb134e61b219803),
+		BinaryField64b::new(0x361ac7b7735a5fc5),
+		BinaryField64b::new(0x3e22e3dab857bec2),
+		BinaryField64b::new(0x1d6da03f758148a7),
+	],
+	[
+		BinaryField64b::new(0x195c9e59ed600571),
+		BinaryField64b::new(0xd3908fd4711c8a74),
+		BinaryField64b::new(0x46487332730c0510),
+		BinaryField64b::new(0xcb4eb0498df28ef9),
+		BinaryField64b::new(0x531203117d6a8cd0),
+		BinaryField64b::new(0x03a6d3f511cc7433),
+		BinaryField64b::new(0x5e82131516e414d5),
+		BinaryField64b::new(0x97391f552de0c657),
+		BinaryField64b::new(0x6df75a3c5ece059a),
+		BinaryField64b::new(0x9f18a38ed4e58c93),
+		BinaryField64b::new(0x34e26297e7d4ac27),
+		BinaryField64b::new(0x7b9684fc8414a370),
+	],
+	[
+		BinaryField64b::new(0xcf9dba83f3f715fe),
+		BinaryField64b::new(0xb16a66ab6c94bd96),
+		BinaryField64b::new(0x954c2ecc1b9ad58e),
+		BinaryField64b::new(0x1829efa215fb7018),
+		BinaryField64b::new(0x222138632eedfc7a),
+		BinaryField64b::new(0xe7e8055e94d249ab),
+		BinaryField64b::new(0xcf6c79919667ae87),
+		BinaryField64b::new(0x21a07c8b250ab848),
+		BinaryField64b::new(0x86fafca4491ebe72),
+		BinaryField64b::new(0x6c677292d80dabd4),
+		BinaryField64b::new(0x0f4700ee0fc3eb37),
+		BinaryField64b::new(0xb8b6e3c770597905),
+	],
+	[
+		BinaryField64b::new(0x03c96e48a036acbf),
+		BinaryField64b::new(0x229a358fecc47aac),
+		BinaryField64b::new(0xead34ac220b10209),
+		BinaryField64b::new(0x78f3c679d0b1bdca),
+		BinaryField64b::new(0x83e5d7e69ec02df4),
+		BinaryField64b::new(0x58ebf7fd1a852ef0),
+		BinaryField64b::new(0xe7710510d4e98731),
+		BinaryField64b::new(0x9616e0dd5daa7698),
+		BinaryField64b::new(0x4b6dc4c8a50eaa21),
+		BinaryField64b::new(0x8dd87cec0931c076),
+		BinaryField64b::new(0x991d30c18c375208),
+		BinaryField64b::new(0xd5ea451efc8802e3),
+	],
+	[
+		BinaryField64b::new(0x14b2944eafcb82c1),
+		BinaryField64b::new(0x5cf049e89bece05b),
+		BinaryField64b::new(0x1d531fbc51e0ad86),
+		BinaryField64b::new(0x9edf66a53ee8bea7),
+		BinaryField64b::new(0x80154d207a7ef842),
+		BinaryField64b::new(0x39b9400f418fc3d7),
+		BinaryField64b::new(0x213c6370096f33d7),
+		BinaryField64b::new(0x71309e7a172e97c1),
+		BinaryField64b::new(0x1b193eae929239d8),
+		BinaryField64b::new(0x9d77b20c69cb516e),
+		BinaryField64b::new(0x0c530472c061ea2d),
+		BinaryField64b::new(0x2c204b3617f7d2a9),
+	],
+	[
+		BinaryField64b::new(0x5cfed505cbe84cce),
+		BinaryField64b::new(0xe8bc328ec0b147e9),
+		BinaryField64b::new(0xa94d909c58f07c2c),
+		BinaryField64b::new(0xf238d4e66a67af2e),
+		BinaryField64b::new(0x8d458b4da6b5847b),
+		BinaryField64b::new(0xb5f46cf77c65a38f),
+		BinaryField64b::new(0xf7be1813b10a994f),
+		BinaryField64b::new(0xad2030aa741aca00),
+		BinaryField64b::new(0x25ebe3ff974097cd),
+		BinaryField64b::new(0x0445a5c549092db1),
+		BinaryField64b::new(0xdd7448446cfd5ca2),
+		BinaryField64b::new(0x3d3bf4b5d65e79e7),
+	],
+	[
+		BinaryField64b::new(0x68000cfbecce7d39),
+		BinaryField64b::new(0x4a4732edcdd69c68),
+		BinaryField64b::new(0x1a150c6bfecc2f1e),
+		BinaryField64b::new(0x220e8d3d8e65ef2e),
+		BinaryField64b::new(0x75bbc76558af2050),
+		BinaryField64b::new(0x45653e5ef3dde02e),
+		BinaryField64b::new(0x68ac84d8e930d72f),
+		BinaryField64b::new(0x616e3cd9693a0292),
+		BinaryField64b::new(0x87e30630101b675c),
+		BinaryField64b::new(0x7f8d5b9bef4f82b6),
+		BinaryField64b::new(0xefe47ce30fc8078f),
+		BinaryField64b::new(0x235e5448f2e797b6),
+	],
+	[
+		BinaryField64b::new(0xf7a98db2d71836a2),
+		BinaryField64b::new(0x01b1fb83fbb2b34c),
+		BinaryField64b::new(0x84ef014310b8d8f3),
+		BinaryField64b::new(0x530407fe61f69884),
+		BinaryField64b::new(0xeccd459af17ddd57),
+		BinaryField64b::new(0x81d0c97cfaa31617),
+		BinaryField64b::new(0x62f5bd6f43d10d3f),
+		BinaryField64b::new(0xf6b72795e0f67909),
+		BinaryField64b::new(0xadc9b7719855310d),
+		BinaryField64b::new(0x12208c2dc23cc362),
+		BinaryField64b::new(0xb0a65a8a70816a99),
+		BinaryField64b::new(0x6a2eaa7526cd1fd1),
+	],
+	[
+		BinaryField64b::new(0x5829c59232dc50b7),
+		BinaryField64b::new(0x8791c79f4e424ec2),
+		BinaryField64b::new(0xbe171ea0b0ae7adc),
+		BinaryField64b::new(0x2a120b518e4df543),
+		BinaryField64b::new(0xf1959427dd1ed5b1),
+		BinaryField64b::new(0x8177ad932e02b60d),
+		BinaryField64b::new(0x16ddad6132148545),
+		BinaryField64b::new(0xa2207804ae19b060),
+		BinaryField64b::new(0x505e87e9a5cf9c02),
+		BinaryField64b::new(0x0ac72f1debd9c426),
+		BinaryField64b::new(0x8732a56be353fec9),
+		BinaryField64b::new(0xe2973d4f194ca360),
+	],
+];
+
+pub const VISION128B_AFFINE_FWD: [BinaryField128b; 128] = [
+	BinaryField128b::new(0x3be2816e5f829b45a4b6bd390c27a7ca),
+	BinaryField128b::new(0x7dbf5c8395f5049e0fdf7a28a65cd8c9),
+	BinaryField128b::new(0x978b45f5fdc0b183fca0cf1357b1f955),
+	BinaryField128b::new(0xa998e1202634645b79fa5055b791b75e),
+	BinaryField128b::new(0x396ab2603f56ed353a4520e7b4ac2b60),
+	BinaryField128b::new(0x3f54d76859f1800a931efa23ec00b4d8),
+	BinaryField128b::new(0x61e864e2daf6f7c1a5f9334277360e46),
+	BinaryField128b::new(0x5bc4b2bcca6a7dd20f3440b2e331f1c4),
+	BinaryField128b::new(0x49be596c8e3b1117b44882177f34daee),
+	BinaryField128b::new(0x730db7c918e83c53e34706b545374f63),
+	BinaryField128b::new(0x51c8e4fa433846b8add9cdf69a5cd849),
+	BinaryField128b::new(0x8e8dee83433f100d8e36153e2f468c14),
+	BinaryField128b::new(0x1379190b93f1ceb30d4d549610ff3a08),
+	BinaryField128b::new(0xc7e703954d5c3d1e613aea6d3322d71c),
+	BinaryField128b::new(0x7b3c0bb604572d2debc90c7fa6d29991),
+	BinaryField128b::new(0x29c2e1b41da2e76430cbaa616cbeb172),
+	BinaryField128b::new(0x750d437bd6aa3bf6fb4f5551e7043220),
+	BinaryField128b::new(0x7a8ec5c9fed5017f0de6cd631f631d14),
+	BinaryField128b::new(0x3b860ee2458d0af4e8313a489c850902),
+	BinaryField128b::new(0xad34ad49e9279ab24c5552d5a08205e9),
+	BinaryField128b::new(0xac03a0d0821fca4bbe8ae18405a83360),
+	BinaryField128b::new(0xab978d99ec87b7d1ba2d041e3df6efec),
+	BinaryField128b::new(0x631663e404bf469df530327af9bc3388),
+	BinaryField128b::new(0x834f6d5eeacba4d316aec75b23d53af9),
+	BinaryField128b::new(0xd4e014f57c43cc2ad151e65aef8475b1),
+	BinaryField128b::new(0xbf0684da0c52629a7914060882d5a816),
+	BinaryField128b::new(0x22526b9fd1bc9a1e804f68547326a64c),
+	BinaryField128b::new(0xfe77a8150d130b34580160b5bca785de),
+	BinaryField128b::new(0xb014e63cb86eccc286d876561bd6a827),
+	BinaryField128b::new(0x949bb16ace679a206448034aa1d2323a),
+	BinaryField128b::new(0x95a7ca2a344b6f6334ede8efba77cd9b),
+	BinaryField128b::new(0x407e1ee4864d7ef60a9c13cc2a1f1dea),
+	BinaryField128b::new(0x2beb8dd1e54bbf2785e32c5a49d34a81),
+	BinaryField128b::new(0xa11d2c340eef5ce163325d3d4bc55940),
+	BinaryField128b::new(0x76747911349c6cd7331bc7bfdc873b9a),
+	BinaryField128b::new(0xfed82f16c2aa42a6078a0fbb37d3d911),
+	BinaryField128b::new(0x813de550cf1801a12c922da63e98c598),
+	BinaryField128b::new(0x44374fdb748fd5092bc9be8355ce4f10),
+	BinaryField128b::new(0xf67b75f77b9e038e8651a149c7d2d802),
+	BinaryField128b::new(0xaf74e981e4f0ccef59430d51e78d6f26),
+	BinaryField128b::new(0x15cea8fbb32ead71d9e4b31e2f262f80),
+	BinaryField128b::new(0x264e9655a24215327cc1b93398b32e1f),
+	BinaryField128b::new(0x74d525866b04cd0644653a05cccbcb00),
+	BinaryField128b::new(0x9d6c7bc46adbf11b163c509058b2d5c2),
+	BinaryField128b::new(0xa2cd76e79e688d882d6350ce90698e95),
+	BinaryField128b::new(0x8fefd09181cc5a7a302a69576b286846),
+	BinaryField128b::new(0x9cb958e78e5835c81543300198206c2a),
+	BinaryField128b::new(0x31c804c6837c7fc82ee946921454a62a),
+	BinaryField128b::new(0x7c5bd5440b8a9fd6e9705cb64538c2b0),
+	BinaryField128b::new(0x0c2e8942aa5119c7d10374e03de61d97),
+	BinaryField128b::new(0x9f438262425420ba7b9f757121dfd410),
+	BinaryField128b::new(0x8a7674e29ec315ca8f825aeabf44538d),
+	BinaryField128b::new(0xe6f4682798889d399dee04d1b52e055f),
+	BinaryField128b::new(0x69e520c40dc841d4ef4e1b05bf86991b),
+	BinaryField128b::new(0x38068a84c11c2eca202d32f5f7eb87c8),
+	BinaryField128b::new(0x8287aae4fb2e2a1e364084171af4aec7),
+	BinaryField128b::new(0x68959a20c196d4305539a6c8e335628a),
+	BinaryField128b::new(0xee404c344d6f99c395174b4abcbb9abd),
+	BinaryField128b::new(0x46bed654d85d12e2c9a50faac51f0290),
+	BinaryField128b::new(0xb5c67ca26e5c0ab43cfc85fb163ce53e),
+	BinaryField128b::new(0x9c8cd476977876b1dfcc2f0b3f3cc856),
+	BinaryField128b::new(0x0b181d0f5676010296ed071bbfe32d8c),
+	BinaryField128b::new(0x05f254efee46ca8cf5d6b538c4f6d281),
+	BinaryField128b::new(0xb8967b1ff53a2e047d7da48859762a2f),
+	BinaryField128b::new(0x0e2df8e711ae3c4945b686175b9f425d),
+	BinaryField128b::new(0x3e3efe9991a7d87409bdeec76f4c3c14),
+	BinaryField128b::new(0xe9f2bfdc6557cf9865723ea93989ab6f),
+	BinaryField128b::new(0x085ccbd43d9910cd4dd7f44540d87f3b),
+	BinaryField128b::new(0x4716a81f4ff969e365abbc69ccb4407e),
+	BinaryField128b::new(0x6cc3c87a743355c007cac91f51c70d27),
+	BinaryField128b::new(0x8c6384341615642634489ef475c9755e),
+	BinaryField128b::new(0x770eb22a026600b294f17b4109dc6627),
+	BinaryField128b::new(0xe5c6bff710bdd303b40d848f92e9121a),
+	BinaryField128b::new(0x2610bdf3b79d594790b8eef9ce8b1a46),
+	BinaryField128b::new(0x2f3ecebc093b43c9c0de1dc8e5631a47),
+	BinaryField128b::new(0x002f08c563b5feeaefecc239969259cf),
+	BinaryField128b::new(0x578b027a330ed675b5b1c47b3d4a403b),
+	BinaryField128b::new(0xb88bca43bcc5cf65db1e96ade3816595),
+	BinaryField128b::new(0x9d3584e240aacafcd07a94fbdea829be),
+	BinaryField128b::new(0x7fbe9eb69ca1557669ca232d44f78d28),
+	BinaryField128b::new(0xdb518807f7ca8700107e5fac30ebb549),
+	BinaryField128b::new(0xa25335d03b984e49d76a3e99969e90fe),
+	BinaryField128b::new(0x059aa3d43b95843d6d5ff11c35f96c41),
+	BinaryField128b::new(0x58c6d7ae532735b09b893a68eee91552),
+	BinaryField128b::new(0xf0c9b49f91714f20e0e1f5c23bcd4183),
+	BinaryField128b::new(0x78d4aa5f41d6ac70fc948b5f75da0164),
+	BinaryField128b::new(0x1eae40022f6e8b18ea8d92bb41477e6d),
+	BinaryField128b::new(0xc8965e82221d2b8c8a722a48cdec60a5),
+	BinaryField128b::new(0x75fcfd2ff2a62576fc4620db0805b05b),
+	BinaryField128b::new(0xd22970c3df1a904a40b9bc192c614ee9),
+	BinaryField128b::new(0xfacc5533e397772cfa73eaec6ddb24e7),
+	BinaryField128b::new(0xf0984e35845088fdc5ef8aae562553d3),
+	BinaryField128b::new(0x8737ed982e97f52ad547aa7f16a9d56d),
+	BinaryField128b::new(0xc881a82135f7866d7a47fda4e3f46ecb),
+	BinaryField128b::new(0xcb52088f605c653b79c3b48b34ef930e),
+	BinaryField128b::new(0x44ccc799be3f7f230e1ad6b44774ebdb),
+	BinaryField128b::new(0xa9b84a5f473208666eb6fc8e5ed18718),
+	BinaryField128b::new(0x8f6c67ccc39af949893028edfea6f5f8),
+	BinaryField128b::new(0xc42e1d92e7431e0fe4d69179925bca0f),
+	BinaryField128b::new(0x91b799a5d877042b03d3377fd5f73acf),
+	BinaryField128b::new(0x2e7bd800ffbd8cfc5dbde1a219f4cc1c),
+	BinaryField128b::new(0xd3afdb88d601ca0d14a2ab51ea5b74a3),
+	BinaryField128b::new(0xe7c0703d90509db70e1f428ee59d076d),
+	BinaryField128b::new(0xc33e1de9176dedef89ff45d920e1794b),
+	BinaryField128b::new(0xfe601e268a58b8048c5cf2e6fbe8c31e),
+	BinaryField128b::new(0xe947fe2c7e11b815066cd4c6fcfd30f0),
+	BinaryField128b::new(0x532ea772b848c42053f16597ecf540b9),
+	BinaryField128b::new(0x2a1a3f0e550002acff0d47656da35913),
+	BinaryField128b::new(0xa73df95992169d87ed53e093f54e1427),
+	BinaryField128b::new(0x359ae47d9024537063de59eeadb8cc7d),
+	BinaryField128b::new(0x066799bd4fc2dce3606ff7170e5e6c93),
+	BinaryField128b::new(0x5e4c6f115241e7f72367bf2b2ad50e4b),
+	BinaryField128b::new(0x7a4d10395ba539290f076ba6eeab5cb6),
+	BinaryField128b::new(0x47293f064b3b5686445609ef58841956),
+	BinaryField128b::new(0xa5d8d38577db2a83c0a63b7a18d3a245),
+	BinaryField128b::new(0x357ef3552d34904ee9c99705ceb77f76),
+	BinaryField128b::new(0x09928c45aa46c78433c09f86f6bf92ee),
+	BinaryField128b::new(0xf5eaffe6b65d4812b99243db2cd9ec34),
+	BinaryField128b::new(0x6ccde05b8836978d173bce2b47cdad44),
+	BinaryField128b::new(0x9c4008e86adfe00736d353ad5443f42f),
+	BinaryField128b::new(0xb09d4da243a88c6c58e73b8820df9c03),
+	BinaryField128b::new(0x69f4635a42a3261860a20eb3af6e6e77),
+	BinaryField128b::new(0x6c41e956f7e8f866b56a30d17e9517bb),
+	BinaryField128b::new(0x321ccdbd0e4ee2ae8f7787b0f5e2d809),
+	BinaryField128b::new(0x13fa84c66d8ce66a993ca4eba68639f1),
+	BinaryField128b::new(0xba594254e216a675052cbf67bee00ae1),
+	BinaryField128b::new(0x544805a206150d6509393179f0c10776),
+	BinaryField128b::new(0xc088fb6131e4caebb661bf0ff3aa931c),
+];
+
+pub const VISION128B_AFFINE_FWD_CONST: BinaryField128b =
+	BinaryField128b::new(0x22845f9ce8fe27c3c2c5078bd2d0a977);
+
+pub const VISION128B_AFFINE_INV: [BinaryField128b; 128] = [
+	BinaryField128b::new(0xca599d467eb0b9941a092374828b40eb),
+	BinaryField128b::new(0xec2c7e136b82c0f9958cca30afd81822),
+	BinaryField128b::new(0x1d812b994f9edc108b0f9350b6513a3b),
+	BinaryField128b::new(0x71ecdfec47b56ff6666c0e5b7115f0fc),
+	BinaryField128b::new(0x2079a12b94cbf37aaa6194a78fab4f16),
+	BinaryField128b::new(0x93b2093f1cc93dfeb1be00760c34ff6b),
+	BinaryField128b::new(0xc92caeea67b9472da95feb23bc88c53e),
+	BinaryField128b::new(0x32905f7accf8565a0a58501684442c5c),
+	BinaryField128b::new(0x5af4afa1160cd088b8618011f967826c),
+	BinaryField128b::new(0x1f26c47380e4cfcbbe009efe703fe6cb),
+	BinaryField128b::new(0xd5b812bf806301716254f31f9e1f555d),
+	BinaryField128b::new(0x8a9b0e72a728710d587ee0e3fa957247),
+	BinaryField128b::new(0xa83b5eac73c0191740e59e6a1903c1b3),
+	BinaryField128b::new(0x0ac47158452936ad2622baf99f1a23e2),
+	BinaryField128b::new(0xb47abeb92892065983ed7ade8aa1ecbb),
+	BinaryField128b::new(0x5dd8097d968110f8be4d8e2d5f642267),
+	BinaryField128b::new(0xc7e6166683b350a3e04272ec2312e748),
+	BinaryField128b::new(0xb567eae46fe31b2b55cb058196f3c1af),
+	BinaryField128b::new(0x29660de2dda10a66e96247296793f6c1),
+	BinaryField128b::new(0x350e78b6303fbbf611dccb45227d7365),
+	BinaryField128b::new(0x89c03d308679ca1aaaa044b0681cb29c),
+	BinaryField128b::new(0xdca0937584c0768460fb14c720ef63f6),
+	BinaryField128b::new(0x07ba067d30e2ce01bb16499d20d9f9db),
+	BinaryField128b::new(0x855ba851a9f7431c971eb4110d5ccbf7),
+	BinaryField128b::new(0xe380ec43ccdb7d600a0a6af70a057b8c),
+	BinaryField128b::new(0xd6ebb51a96063ce9c0d6f6fc78adcc3f),
+	BinaryField128b::new(0x30fbd3485e8c35e7e2f34bd89c175811),
+	BinaryField128b::new(0x9903ed358b65b49478672e0b5dad2c96),
+	BinaryField128b::new(0x289bb49d4cb7b37e13a3ea8074107401),
+	BinaryField128b::new(0x09b1cba31c47f3afffb1e4d4065e5b39),
+	BinaryField128b::new(0xed120d2643ea7bdd21fe7f440bd727f9),
+	BinaryField128b::new(0x832657bf409c515322caa8f21b90c6ad),
+	BinaryField128b::new(0x7eb571fceeb5220fc11799b7d8abbb0a),
+	BinaryField128b::new(0x6ddd1281ac9bccb83ebe538212d0f914),
+	BinaryField128b::new(0x90fb7712bc3f1c9ca7c5a85006caa1ea),
+	BinaryField128b::new(0x147b912a47a5f9906944d2340bf1c35a),
+	BinaryField128b::new(0xc3f736d60d5afc1e461117aa0151aaf3),
+	BinaryField128b::new(0xcf869b24509b74c1fc6037cb3050177f),
+	BinaryField128b::new(0xe60b2165f640d8715927d491a1d03e16),
+	BinaryField128b::new(0x508e01b29c6341e2667e4c1c87cbd866),
+	BinaryField128b::new(0xa2cd7279d06424af24520958f610a860),
+	BinaryField128b::new(0xe4a2a872e0ca98f52b094aec8283aac6),
+	BinaryField128b::new(0x0205faa9317c0222058455f02a03a01b),
+	BinaryField128b::new(0xf6a7fa065005061373207745567e6516),
+	BinaryField128b::new(0x5d318acc104f41755200a88ee05baaf4),
+	BinaryField128b::new(0x48bcdd95078eec1d6a5a14a35c4226b1),
+	BinaryField128b::new(0x9c50878527410ee63eaaa666736b6043),
+	BinaryField128b::new(0x67220de55caecf06f8a3372af540e2fb),
+	BinaryField128b::new(0x2da911e412984513b4fb1d488cba6528),
+	BinaryField128b::new(0x97c607ca99d78c0cea5e1974a38fb1a4),
+	BinaryField128b::new(0x2e76ad5b0e238ff6b597b62c028e2683),
+	BinaryField128b::new(0x5f69bc6ed88cce85739d06c8cee8b839),
+	BinaryField128b::new(0x613d327edf3bfa0556470cb0ad00675d),
+	BinaryField128b::new(0x286950ee3d49d0afbe2f82ba317aff1d),
+	BinaryField128b::new(0x09bf7af80e01456ccbb9847375a29a8d),
+	BinaryField128b::new(0xe87789f3ae1298dc14abd2d73a19c64a),
+	BinaryField128b::new(0x520b7c8864f3b693ed2d76b3fd72e416),
+	BinaryField128b::new(0x0066c3f80045775a43c3d4e94b08f2ef),
+	BinaryField128b::new(0x1f6efce62cafe918b640254308ca7bb7),
+	BinaryField128b::new(0xfbeae2dfb478a81bfd96ff0ce1b74f55),
+	BinaryField128b::new(0x60861ffcc7c178f053397109c82b91ca),
+	BinaryField128b::new(0x387082416672600c7cb329fba8f92a94),
+	BinaryField128b::new(0x38ccad5a52f5cfd555d0d3a16abd2deb),
+	BinaryField128b::new(0xbe737a8cb019864542159f82769ce7f8),
+	BinaryField128b::new(0xca8c39c1effa9667c1f37e04175270bf),
+	BinaryField128b::new(0x4e628ccb67974eff7ea28a1de11043e1),
+	BinaryField128b::new(0x613a9950b74a6e9c4acfd734709db9ee),
+	BinaryField128b::new(0xa5ae7a04b63137bfe3b0d685e836b993),
+	BinaryField128b::new(0x624f6c7183482514b7e9e253a049d942),
+	BinaryField128b::new(0xe8dc8de0ec62c6bce7a3161892e7b250),
+	BinaryField128b::new(0x99067a18dbb017fe3c2086ed604287f3),
+	BinaryField128b::new(0xd80b4d02c6cab8c90801528a9c1a120e),
+	BinaryField128b::new(0x5938e66d8ddd11434a67c356d74e42a4),
+	BinaryField128b::new(0x8d17bace4b3132dbefd5d24647caf026),
+	BinaryField128b::new(0xfcec5a153b966109fd6c971d76af977c),
+	BinaryField128b::new(0xf543e74c0238942be6a8ea04396170a9),
+	BinaryField128b::new(0xeed6a15676de4ab681fa4f611c22fbbc),
+	BinaryField128b::new(0xa0a293c5083bc32daa11c7bc4578f5ee),
+	BinaryField128b::new(0x099bc9ddfa3b36e24fdaa73e6e646ea1),
+	BinaryField128b::new(0x2bbc2afe5e4a78300cb7ff9d08c30035),
+	BinaryField128b::new(0x658a617a55c2f0003b9300bf0d2c7579),
+	BinaryField128b::new(0xa836584087d436c7dbf330655d3e2262),
+	BinaryField128b::new(0x0074a77e3891db99a449ab17bb1542ab),
+	BinaryField128b::new(0x92106f3c304ea33f644d52e618eb0855),
+	BinaryField128b::new(0xf65c77759a044bd1bfc9839de2bee246),
+	BinaryField128b::new(0x5aa60e5a34f71c6c877676dbcc987f36),
+	BinaryField128b::new(0x00b5150065e4e267678ed37a9e11094c),
+	BinaryField128b::new(0xb49236eccee1d3b93f284f88a401288a),
+	BinaryField128b::new(0xb1020d5ef115f79cafe36c8138d0af43),
+	BinaryField128b::new(0xf58603880a3b7827efb476b86762eced),
+	BinaryField128b::new(0xf3e50b5cd5c434df31861d551fe93179),
+	BinaryField128b::new(0x1b763fca027eb8a425afaed125c89586),
+	BinaryField128b::new(0x396758a692f3fbe3d94ee8ceab3a874d),
+	BinaryField128b::new(0xca86c5284f9346d494959aa375aa0b33),
+	BinaryField128b::new(0x023b2907c3725c986eb096c623c1f47a),
+	BinaryField128b::new(0x0149ad1d5eaf7e1b32b99e672884f17e),
+	BinaryField128b::new(0x413f6ab7a9bbbc83ba5f832101e0af20),
+	BinaryField128b::new(0xa0d1ce27796e856751abed7f4fcc7a83),
+	BinaryField128b::new(0x0aa76e460b5d36b83bb62b7fd1715d71),
+	BinaryField128b::new(0x5623c6d4e8af4466532a79d17fb57b1a),
+	BinaryField128b::new(0x6c970510b490a10766ef33204c5226a1),
+	BinaryField128b::new(0xfca8095e19cd952e315c13d87fda72fd),
+	BinaryField128b::new(0xb73f3aafb90493010f543d3e1eeac714),
+	BinaryField128b::new(0xda7072fb7ec2558355c12a7d0c5b2883),
+	BinaryField128b::new(0x70146e82f38908ea345e928764715448),
+	BinaryField128b::new(0x46de3d357f14b350aaa8f727ea204fef),
+	BinaryField128b::new(0x4e9c7aad07b524f1312844566b32e0fe),
+	BinaryField128b::new(0x0ebbe5f2cdbf7c945e0839269fbca5cf),
+	BinaryField128b::new(0xce9ae2c6e218d687d7fd0cc10aaefabd),
+	BinaryField128b::new(0xe1a7d83226db6c34f75936c441c47878),
+	BinaryField128b::new(0x7cc6b14e40de8c81ab82ab2e55d804f8),
+	BinaryField128b::new(0x1ed4a3e2f28e4612e44126e3109bdc3b),
+	BinaryField128b::new(0xa8760d809cd11da78a131fab6fe8c244),
+	BinaryField128b::new(0xe8b09e06ced18a319a467e443a8e4dd3),
+	BinaryField128b::new(0xbc1ad5e607865a7e873ab27beacc89f5),
+	BinaryField128b::new(0x1bf71e56a37faa03e052c74660a508da),
+	BinaryField128b::new(0x472cc989e421854f79eadfb28d4642bc),
+	BinaryField128b::new(0xd43274f6284186814de5fc2a62bd08c3),
+	BinaryField128b::new(0xc72594b48ada4d6cf47d94cf1e81096d),
+	BinaryField128b::new(0x6fbe800392d524f3df3df027a2fed686),
+	BinaryField128b::new(0x0a1c0872beeed6abcf57bc03d44f8aa6),
+	BinaryField128b::new(0x21ad2def6e477d0153bad06f030eb97e),
+	BinaryField128b::new(0xe0a319b8cb0fc940807e11a68492430c),
+	BinaryField128b::new(0x8d1497a5ba118b2a5ec41093aeba291a),
+	BinaryField128b::new(0x2307933fec740bbf027c85768cc283a9),
+	BinaryField128b::new(0xd9d4a53f151f45e0347ebaacb9c4c400),
+	BinaryField128b::new(0x4c75bbe8beef2cf8dab28ad3f2b4488f),
+	BinaryField128b::new(0x448469fe33c994926a5d7f1899b42922),
+];
+
+pub const VISION128B_AFFINE_INV_CONST: BinaryField128b =
+	BinaryField128b::new(0x92bc696dce6af335ee1d250a91897075);
+
+#[rustfmt::skip]
+pub const VISION128B_MDS: [[u8; 6]; 6] = [
+	[0x04, 0x0f, 0x0d, 0x0a, 0x09, 0x0c],
+	[0x0f, 0x04, 0x0a, 0x0d, 0x0c, 0x09],
+	[0x06, 0x0e, 0x09, 0x0c, 0x0d, 0x0a],
+	[0x0e, 0x06, 0x0c, 0x09, 0x0a, 0x0d],
+	[0x03, 0x02, 0x0b, 0x08, 0x05, 0x07],
+	[0x02, 0x03, 0x08, 0x0b, 0x07, 0x05],
+];
+
+#[rustfmt::skip]
+pub const VISION128B_ROUND_KEYS: [[BinaryField128b; 6]; 2 * NUM_ROUNDS_WIDE + 1] = [
+	[
+		BinaryField128b::new(0x5678a94932e4ec93d43a9c9e5997bf6e),
+		BinaryField128b::new(0x23d7b43dd23a42e7768c50be5176b9e1),
+		BinaryField128b::new(0xe4309cdc14b026a0ebf27c5ede5306c3),
+		BinaryField128b::new(0x7a8772f212d020c82898a84abad25502),
+		BinaryField128b::new(0x338abe66f8486b9b95134ab46d15fc69),
+		BinaryField128b::new(0xe44d91afa616ccfbba8df0918691f75d),
+	],
+	[
+		BinaryField128b::new(0x8e24d6f24ada3f1f45d22d1cfc5f9960),
+		BinaryField128b::new(0xb8a7ca20e59385402d1b02a2b2dd4aa3),
+		BinaryField128b::new(0x852c5990a70b91196c25b883a5933785),
+		BinaryField128b::new(0xa3f2c76ff9146ad546f4bc5aab8880cf),
+		BinaryField128b::new(0x11dd2cb070e1e6205446ed96b2883977),
+		BinaryField128b::new(0xe96d64ee1c64ef2b68b056b481c6e71e),
+	],
+	[
+		BinaryField128b::new(0x369a81e844f0568cb59322c3f27e0510),
+		BinaryField128b::new(0xa041aa3a5838e1b465105c8006debea6),
+		BinaryField128b::new(0x89943eb0671d6d430bdc447c67bf1c2b),
+		BinaryField128b::new(0xd40a00622bed82b6c655fe9f2f66855f),
+		BinaryField128b::new(0xe3249e263356bb3d1e58cda6276b2001),
+		BinaryField128b::new(0xce6d452547a44ff54f60ff673ba98b72),
+	],
+	[
+		BinaryField128b::new(0x8cc7a0a7446c3427e13f4ec46a955fab),
+		BinaryField128b::new(0x0ba08e2a12f263f14f96599f8d949e51),
+		BinaryField128b::new(0xbed1568795862e61d344ca21b8f4b883),
+		BinaryField128b::new(0xc04795a6a7c24655dfe8d3dc9bb33875),
+		BinaryField128b::new(0x0709e0b2227b203a7e0e2532ee9f3f65),
+		BinaryField128b::new(0x7612d34164426c5b901a15a1ce90f3d0),
+	],
+	[
+		BinaryField128b::new(0xf28def585495517b29a7c237c2265e59),
+		BinaryField128b::new(0x6c2fa372dcdce622ebfdaa126ce03c93),
+		BinaryField128b::new(0xdf399835038c11276adc815acf788593),
+		BinaryField128b::new(0x36b001150846c1bfb7fcc715f2bfcb3f),
+		BinaryField128b::new(0x71702bbf87338ede63e9418098f631ef),
+		BinaryField128b::new(0xe89cae7e17a64e87bf6ecc641639a6dd),
+	],
+	[
+		BinaryField128b::new(0x9355bc211884f2631267a7345981f937),
+		BinaryField128b::new(0x1957a5dc91228220b32bb10b4c0a46e2),
+		BinaryField128b::new(0x26a104a60c8ab6aba339a3ac6d4173f5),
+		BinaryField128b::new(0x2792728b713b9809dc8b7dc24f6ce29b),
+		BinaryField128b::new(0x15671116977b933c625e90f7777b1a73),
+		BinaryField128b::new(0x25f487ebf2bff5a3587fc92d39aad185),
+	],
+	[
+		BinaryField128b::new(0x9c7c3cef153bf2fd02a286f11d547880),
+		BinaryField128b::new(0x024cb99290a7d13e3603ae9ff565aa02),
+		BinaryField128b::new(0x1abaf7900bdfe8e78a335b05814d20e0),
+		BinaryField128b::new(0x14990cac9b0c893db87beb7512134569),
+		BinaryField128b::new(0x9843ddf7bded1de75b6acc2e7c98d439),
+		BinaryField128b::new(0x5ecd2fda80b0cbe3d38cbce9d6785469),
+	],
+	[
+		BinaryField128b::new(0x07aff82c843f6e6b2fc3a21b6499791d),
+		BinaryField128b::new(0x3d99289ed2b54e8b218da81bc0a8bd0b),
+		BinaryField128b::new(0xb53e3d7af7b83dfb022c63c77d4ba259),
+		BinaryField128b::new(0x6b9f4f97989f68716b70efd98f317fb2),
+		BinaryField128b::new(0x94a4468d776bd8b1e28e854b58f46844),
+		BinaryField128b::new(0xfb50fdfa59e97d7fa8bdd8969a49e21a),
+	],
+	[
+		BinaryField128b::new(0x48242d5d95cf88cb875d723edbb75c63),
+		BinaryField128b::new(0x9c9bbd0991a790b78b473cea28d5a667),
+		BinaryField128b::new(0xbbac41bcd7755f1f22e85a449a56bf1c),
+		BinaryField128b::new(0x2dc97ebca9407a793cb47ae5c824162a),
+		BinaryField128b::new(0x31387f1e18879a9c9976691646ac47e7),
+		BinaryField128b::new(0x97411456a81452cc100f851aa5917036),
+	],
+	[
+		BinaryField128b::new(0xcbd8c403973f9e0318d856705f7211b8),
+		BinaryField128b::new(0x03c17363073aa1715786e3a2b22267b7),
+		BinaryField128b::new(0x7f8dd95aaee86be2289b1298e5dbb015),
+		BinaryField128b::new(0xf2e3aa6d9ae36f11c400476fd2092a10),
+		BinaryField128b::new(0xf9a20ae97d30d19c541dab08fb4e364c),
+		BinaryField128b::new(0x7dda05944d9ce954db581057a284adba),
+	],
+	[
+		BinaryField128b::new(0x872dec5f04776dc8b1aa5b8b6569cfea),
+		BinaryField128b::new(0x040db210b6db33b21c3c5d799adbd7c9),
+		BinaryField128b::new(0xdf88f566af7abb3018e2a5537eef632a),
+		BinaryField128b::new(0x95db3ad85b3428ebe5bccc3b001b5793),
+		BinaryField128b::new(0xb3ed96773b88da6ba576161023df1b66),
+		BinaryField128b::new(0xf212b31049c36d73383effd5100c629b),
+	],
+	[
+		BinaryField128b::new(0xff807994687aa88a5f85e65fd0a23f4e),
+		BinaryField128b::new(0x4baa8077c8c125c82cfc71953f337e5a),
+		BinaryField128b::new(0xe1c93dde2970ff98ece4766712a139d6),
+		BinaryField128b::new(0xdcc9dc64369f20969eab5bb31276b91c),
+		BinaryField128b::new(0xb25f6bfb46586855c1836c0d5a0bf259),
+		BinaryField128b::new(0x4c69a7fa9fa10c5d14a24affa2918af8),
+	],
+	[
+		BinaryField128b::new(0xf7fdec5b60b8411dd596c424cc8a0d86),
+		BinaryField128b::new(0xef89322e612b2c12587efca6ec090bd4),
+		BinaryField128b::new(0xd9e95621b2d4228a86f43240587d18d0),
+		BinaryField128b::new(0xb40be56ae41d374effe81dbd572f3cc4),
+		BinaryField128b::new(0xdbe0d9b86123a0af6545201969581d8a),
+		BinaryField128b::new(0x5b185035677cb7cb0be394f3422e4de4),
+	],
+	[
+		BinaryField128b::new(0x7ab95a341707ddee3a9be380a6ddfe99),
+		BinaryField128b::new(0x51cbe5331b07fe31fb8c9db8ab9f1603),
+		BinaryField128b::new(0x57164e25ffbf27520988e4651e638dc3),
+		BinaryField128b::new(0x5c94b4a50e10407bed5610854bee59d2),
+		BinaryField128b::new(0xb388da04536ec934454883c2cd9d8b19),
+		BinaryField128b::new(0xcea15da998d9ed92fe4b655dd7625818),
+	],
+	[
+		BinaryField128b::new(0xef175f063c4a614407d5c8476fd47612),
+		BinaryField128b::new(0xb15b35b6ba1310233579e8c9dd3bab0d),
+		BinaryField128b::new(0xef78a079935f6b8ddf7e6df943dbf12d),
+		BinaryField128b::new(0x5a790762f6e35e3bcbba1a8936918484),
+		BinaryField128b::new(0x5bedb8b602450eeb87d6623c4bcd0c2c),
+		BinaryField128b::new(0x8fb487ac2e03dc002a892bcf83cd0ded),
+	],
+	[
+		BinaryField128b::new(0x06af37df96deffe482de769c5f1df834),
+		BinaryField128b::new(0xc2147ff28d0c1d6249e2d4469022ad89),
+		BinaryField128b::new(0xaf9b016155132f9674562a3a85d1f861),
+		BinaryField128b::new(0x27814aacfa3a5c9df602dd2979fb4405),
+		BinaryField128b::new(0x38882e9f41fc706a8de4880957be4f8b),
+		BinaryField128b::new(0x67986bd769596d881e8ac7c751edc773),
+	],
+	[
+		BinaryField128b::new(0xbee96458f35a4941a55d4f0bd75cf931),
+		BinaryField128b::new(0x648ee026f52cbe30d47e43dca2b21e51),
+		BinaryField128b::new(0x6bd8e509aac55212814b864042a5438b),
+		BinaryField128b::new(0x266737a8770fd104946e4e4f91b4bb98),
+		BinaryField128b::new(0x30d581c2fc2533cc2894c7db0c1112d2),
+		BinaryField128b::new(0x3a7aa807a8f2a73493a1a3b666edaa41),
+	],
+	[
+		BinaryField128b::new(0x3f3f66ffe424e954e6ca8e53a5ebeeb6),
+		BinaryField128b::new(0xab734b765dd0737ddbbf391ac7fffee4),
+		BinaryField128b::new(0x7df3e029ffda7c9ba1e740e91475f7ec),
+		BinaryField128b::new(0xb4a81f496cc6c83db03b5b5f68dda181),
+		BinaryField128b::new(0xff5e6bfc8cacbf5f8068334bd9015b19),
+		BinaryField128b::new(0x15961daa768c1ae0927ac3a3b0c2afbb),
+	],
+	[
+		BinaryField128b::new(0xf779f58842816bcf75b3c5d2d90def47),
+		BinaryField128b::new(0x5a556f9ba60ffb74d972f51eaed4831b),
+		BinaryField128b::new(0xbb0ea8346fc02beeec5e086f9db1a913),
+		BinaryField128b::new(0xc0e09098d28d1ec150925a195b16fa36),
+		BinaryField128b::new(0xa75f4b4e2004f98ea618f6ab7ba69920),
+		BinaryField128b::new(0x47d0220becf43e9423dc68883b764519),
+	],
+	[
+		BinaryField128b::new(0xd40439c77934e8f3b0c47ceabb771043),
+		BinaryField128b::new(0x3d5742f5624d9a14c08046dd0643626d),
+		BinaryField128b::new(0xbf830969458a39cd98293be4b16240c7),
+		BinaryField128b::new(0x3d637b13481cde4111e6262d29e6ca67),
+		BinaryField128b::new(0x3e8f1c15ecd51a75dad58a11bde203ef),
+		BinaryField128b::new(0x65bcd8f8c5ac4584a5e280cfaebe46b6),
+	],
+	[
+		BinaryField128b::new(0x4f54fd4e1623713e8ee15bee9e170efd),
+		BinaryField128b::new(0xbed9a0f3ca644a1d3addcaf07f4de68c),
+		BinaryField128b::new(0x41197c265d960dea8e3342ad2e6cc0d6),
+		BinaryField128b::new(0xced69b8147309f4ce651973019b5ca46),
+		BinaryField128b::new(0x360deccfcab6d9745639df3a745e5f01),
+		BinaryField128b::new(0xf9e4101d393fbdbe453ff3ddf8470131),
+	],
+];
@@ -0,0 +1,172 @@
+// Copyright 2024 Irreducible Inc.
+
+//! Statistical timing tests of the bitsliced Grøstl implementation, following
+//! [dudect](https://eprint.iacr.org/2016/1123.pdf).
+//!
+//! Every test times an operation on inputs of two classes, a fixed input and fresh random inputs,
+//! in random order, and runs Welch's t-test on the two timing distributions. The measurements are
+//! also cropped at several percentiles to remove the long tail caused by interrupts, each crop
+//! giving its own t-statistic. A large |t| means the running time depends on the input.
+//!
+//! The tests are ignored by default since they take a while and are sensitive to the machine
+//! load. Run them in release mode on an otherwise idle machine:
+//!
+//! ```text
+//! cargo test --release -p binius_hash --test constant_time -- --ignored --nocapture
+//! ```
+
+use binius_field::AESTowerField8b;
+use binius_hash::{groestl::arch::BITSLICED_LANES, BsGroestl256, BsGroestlP};
+use p3_symmetric::Permutation;
+use rand::{thread_rng, Rng, RngCore};
+use std::{array, hint::black_box, time::Instant};
+
+/// Number of timed executions of the operation, split between both classes
+const NUM_MEASUREMENTS: usize = 20_000;
+/// Number of measurements whose inputs are generated at once, before timing them
+const BATCH_SIZE: usize = 1_000;
+/// Number of untimed executions before measuring
+const NUM_WARMUP: usize = 1_000;
+/// |t| above which the timings of the classes are considered different. dudect considers values
+/// above 4.5 suspicious and values above 10 a definite leak, the latter is used here to keep the
+/// tests from failing on noisy machines.
+const T_THRESHOLD: f64 = 10.0;
+
+/// Running mean and variance of a sample, with Welford's method
+#[derive(Debug, Default, Clone, Copy)]
+struct Moments {
+	n: f64,
+	mean: f64,
+	m2: f64,
+}
+
+impl Moments {
+	fn push(&mut self, x: f64) {
+		self.n += 1.0;
+		let delta = x - self.mean;
+		self.mean += delta / self.n;
+		self.m2 += delta * (x - self.mean);
+	}
+
+	fn variance(&self) -> f64 {
+		self.m2 / (self.n - 1.0)
+	}
+}
+
+/// Welch's t-statistic of the two samples
+fn welch_t(a: &Moments, b: &Moments) -> f64 {
+	(a.mean - b.mean) / (a.variance() / a.n + b.variance() / b.n).sqrt()
+}
+
+/// Times `op` on inputs of the fixed class (`false`) and the random class (`true`) and returns the
+/// largest |t| over the uncropped measurements and the cropped ones.
+fn max_t_statistic<I>(
+	mut gen_input: impl FnMut(bool) -> I,
+	mut op: impl FnMut(&I),
+	name: &str,
+) -> f64 {
+	let mut rng = thread_rng();
+
+	for _ in 0..NUM_WARMUP {
+		op(&gen_input(rng.gen()));
+	}
+
+	let mut classes = Vec::with_capacity(NUM_MEASUREMENTS);
+	let mut timings = Vec::with_capacity(NUM_MEASUREMENTS);
+	while classes.len() < NUM_MEASUREMENTS {
+		// Inputs are generated beforehand so that only the operation itself is timed
+		let batch_classes = (0..BATCH_SIZE)
+			.map(|_| rng.gen::<bool>())
+			.collect::<Vec<_>>();
+		let inputs = batch_classes
+			.iter()
+			.map(|&class| gen_input(class))
+			.collect::<Vec<_>>();
+		for input in &inputs {
+			let start = Instant::now();
+			op(input);
+			timings.push(start.elapsed().as_nanos() as f64);
+		}
+		classes.extend(batch_classes);
+	}
+
+	let mut sorted = timings.clone();
+	sorted.sort_by(f64::total_cmp);
+	let thresholds = [1.0, 0.99, 0.95, 0.9, 0.75, 0.5]
+		.map(|fraction| sorted[((sorted.len() - 1) as f64 * fraction) as usize]);
+
+	let mut max_t = 0f64;
+	for threshold in thresholds {
+		let mut moments = [Moments::default(); 2];
+		for (&class, &timing) in classes.iter().zip(&timings) {
+			if timing <= threshold {
+				moments[class as usize].push(timing);
+			}
+		}
+		// Cropping at low percentiles can leave a class empty if its timings are all slower
+		if moments.iter().any(|m| m.n < 2.0) {
+			println!("{name}: crop at {threshold:>10.0}ns leaves a class without samples");
+			continue;
+		}
+		let t = welch_t(&moments[0], &moments[1]);
+		println!(
+			"{name}: crop at {threshold:>10.0}ns, {:>6} fixed / {:>6} random samples, t = {t:>7.3}",
+			moments[0].n, moments[1].n
+		);
+		max_t = max_t.max(t.abs());
+	}
+	println!("{name}: max |t| = {max_t:.3}");
+	max_t
+}
+
+#[test]
+#[ignore]
+fn test_bs_groestl256_hash_many_constant_time() {
+	const MSG_LEN: usize = 128;
+	let fixed = [[0x5au8; MSG_LEN]; BITSLICED_LANES];
+	let mut rng = thread_rng();
+
+	let max_t = max_t_statistic(
+		|random| {
+			if random {
+				array::from_fn(|_| {
+					let mut msg = [0u8; MSG_LEN];
+					rng.fill_bytes(&mut msg);
+					msg
+				})
+			} else {
+				fixed
+			}
+		},
+		|messages: &[[u8; MSG_LEN]; BITSLICED_LANES]| {
+			let messages = messages.each_ref().map(|msg| msg.as_slice());
+			black_box(BsGroestl256::<AESTowerField8b>::hash_many_constant_time(black_box(
+				&messages,
+			)));
+		},
+		"BsGroestl256::hash_many_constant_time",
+	);
+	assert!(max_t < T_THRESHOLD, "timing leakage detected, |t| = {max_t}");
+}
+
+#[test]
+#[ignore]
+fn test_bs_groestl_p_constant_time() {
+	let fixed = [[AESTowerField8b::new(0x5a); 64]; BITSLICED_LANES];
+	let mut rng = thread_rng();
+
+	let max_t = max_t_statistic(
+		|random| {
+			if random {
+				array::from_fn(|_| array::from_fn(|_| AESTowerField8b::new(rng.gen())))
+			} else {
+				fixed
+			}
+		},
+		|states: &[[AESTowerField8b; 64]; BITSLICED_LANES]| {
+			black_box(BsGroestlP.permute(black_box(*states)));
+		},
+		"BsGroestlP",
+	);
+	assert!(max_t < T_THRESHOLD, "timing leakage detected, |t| = {max_t}");
+}
@@ -0,0 +1,561 @@
+// Copyright 2024 Irreducible Inc.
+
+//! Known-answer tests of every hash function and every backend against the vectors in
+//! `tests/vectors`.
+//!
+//! The vector files follow the layout of the NIST `ShortMsgKAT` files: records of `Len`, `Msg`
+//! and `MD` lines separated by blank lines, with `#` comments. They are not the KATs of the
+//! submission packages but the output of the reference implementations in `testgen/hash`, which
+//! check themselves against published digests, where there are any, before writing anything.
+//!
+//! The `*_published.txt` files hold digests published with the algorithms instead, which every
+//! backend is checked against as well.
+//!
+//! A failing backend reports every vector it gets wrong, with the expected and actual digests and
+//! the first byte they differ at. Passing backends are printed, as are the backends that could not
+//! run on this CPU or with these features; setting `BINIUS_KAT_REQUIRE_ALL_BACKENDS` makes the
+//! latter fail the tests.
+
+use binius_field::{
+	AESTowerField8b, BinaryField128b, BinaryField32b, BinaryField64b, BinaryField8b,
+	PackedAESBinaryField64x8b, PackedField,
+};
+use binius_hash::{
+	arch::{Groestl256TableCore, GroestlBackend},
+	groestl_dispatch, BsGroestl224, BsGroestl256, BsGroestl256Hasher, BsGroestl512, BsGroestlP,
+	BsGroestlQ, FixedLenHasher, Groestl224, Groestl256, Groestl256Core, Groestl512, Hasher,
+	Keccak256, MultiHasher, MultiKeccak256, MultiVision32b, Vision128b, Vision32b, Vision64b,
+	BS_GROESTL_MSG_ALIGNMENT,
+};
+use bytemuck::TransparentWrapper;
+use std::{array, fmt::Write};
+
+#[derive(Debug)]
+struct Vector {
+	/// The message length, in bytes or in field elements depending on the file
+	len: usize,
+	msg: Vec<u8>,
+	md: Vec<u8>,
+}
+
+fn decode_hex(hex: &str) -> Vec<u8> {
+	assert!(hex.len() % 2 == 0, "odd number of hex digits in {hex:?}");
+	(0..hex.len())
+		.step_by(2)
+		.map(|i| u8::from_str_radix(&hex[i..i + 2], 16).expect("invalid hex digit"))
+		.collect()
+}
+
+fn encode_hex(bytes: &[u8]) -> String {
+	bytes.iter().fold(String::new(), |mut out, b| {
+		write!(out, "{b:02x}").unwrap();
+		out
+	})
+}
+
+/// Parses the records of a vector file, `elem_bytes` is the size of the units `Len` counts.
+fn load(name: &str, contents: &str, elem_bytes: usize) -> Vec<Vector> {
+	let mut vectors = Vec::new();
+	let mut len = None;
+	let mut msg = None;
+	for (line_no, line) in contents.lines().enumerate() {
+		let line = line.trim();
+		if line.is_empty() || line.starts_with('#') {
+			continue;
+		}
+		let (key, value) = line
+			.split_once('=')
+			.unwrap_or_else(|| panic!("{name}:{}: expected `key = value`", line_no + 1));
+		match key.trim() {
+			"Len" => len = Some(value.trim().parse::<usize>().unwrap()),
+			"Msg" => msg = Some(decode_hex(value.trim())),
+			"MD" => {
+				let (len, msg) = (len.take().unwrap(), msg.take().unwrap());
+				assert_eq!(
+					msg.len(),
+					len * elem_bytes,
+					"{name}:{}: Len does not match the message",
+					line_no + 1
+				);
+				vectors.push(Vector {
+					len,
+					msg,
+					md: decode_hex(value.trim()),
+				});
+			}
+			key => panic!("{name}:{}: unknown key {key:?}", line_no + 1),
+		}
+	}
+	assert!(!vectors.is_empty(), "{name} contains no vectors");
+	vectors
+}
+
+macro_rules! vectors {
+	($file:literal) => {
+		vectors!($file, 1)
+	};
+	($file:literal, $elem_bytes:expr) => {
+		load($file, include_str!(concat!("vectors/", $file)), $elem_bytes)
+	};
+}
+
+/// Describes how `actual` differs from the digest of `vector`, if it does.
+fn diff(index: usize, vector: &Vector, actual: &[u8]) -> Option<String> {
+	if actual == vector.md {
+		return None;
+	}
+	let first = first_difference(&vector.md, actual);
+	Some(format!(
+		"  vector #{index} (Len = {}):\n    expected {}\n    actual   {}\n    first difference at byte {first}",
+		vector.len,
+		encode_hex(&vector.md),
+		encode_hex(actual),
+	))
+}
+
+fn first_difference(a: &[u8], b: &[u8]) -> usize {
+	a.iter()
+		.zip(b)
+		.position(|(x, y)| x != y)
+		.unwrap_or(a.len().min(b.len()))
+}
+
+fn report(file: &str, backend: &str, n_vectors: usize, failures: Vec<String>) {
+	assert!(
+		failures.is_empty(),
+		"{file}: backend {backend} failed {} vector(s)\n{}",
+		failures.len(),
+		failures.join("\n")
+	);
+	eprintln!("{file}: backend {backend} passed {n_vectors} vector(s)");
+}
+
+/// When set, a backend this process cannot run fails the tests instead of being skipped, so that
+/// CI runners with the hardware or the features for it can make sure that it is tested.
+const REQUIRE_ALL_BACKENDS: &str = "BINIUS_KAT_REQUIRE_ALL_BACKENDS";
+
+fn skip(file: &str, backend: &str, reason: &str) {
+	assert!(
+		std::env::var_os(REQUIRE_ALL_BACKENDS).is_none(),
+		"{file}: backend {backend} did not run, as {reason}, but {REQUIRE_ALL_BACKENDS} is set"
+	);
+	eprintln!("{file}: skipped backend {backend}, as {reason}");
+}
+
+/// Reports the Grøstl-256 backends that the checks of `file` could not run.
+fn report_skipped_groestl_backends(file: &str) {
+	if groestl_dispatch().single != GroestlBackend::Avx512 {
+		skip(file, "Avx512", "the CPU lacks AVX-512 or GFNI");
+	}
+	if !cfg!(feature = "bs-groestl-c") {
+		skip(file, "bitsliced C", "the bs-groestl-c feature is disabled");
+	}
+}
+
+/// Checks a backend hashing one message at a time.
+fn check(file: &str, backend: &str, vectors: &[Vector], hash: impl Fn(&[u8]) -> Vec<u8>) {
+	let failures = vectors
+		.iter()
+		.enumerate()
+		.filter_map(|(i, vector)| diff(i, vector, &hash(&vector.msg)))
+		.collect();
+	report(file, backend, vectors.len(), failures);
+}
+
+/// Checks a backend hashing all the vectors `filter` accepts in one batch.
+fn check_batch(
+	file: &str,
+	backend: &str,
+	vectors: &[Vector],
+	filter: impl Fn(&Vector) -> bool,
+	hash_many: impl Fn(&[&[u8]]) -> Vec<Vec<u8>>,
+) {
+	let selected = vectors
+		.iter()
+		.enumerate()
+		.filter(|(_, vector)| filter(vector))
+		.collect::<Vec<_>>();
+	assert!(!selected.is_empty(), "{file}: backend {backend} accepts no vector");
+
+	let messages = selected
+		.iter()
+		.map(|(_, vector)| vector.msg.as_slice())
+		.collect::<Vec<_>>();
+	let digests = hash_many(&messages);
+	assert_eq!(digests.len(), messages.len(), "{file}: backend {backend} lost digests");
+
+	let failures = selected
+		.iter()
+		.zip(&digests)
+		.filter_map(|((i, vector), digest)| diff(*i, vector, digest))
+		.collect();
+	report(file, backend, selected.len(), failures);
+}
+
+fn aes_bytes(msg: &[u8]) -> Vec<AESTowerField8b> {
+	msg.iter().copied().map(AESTowerField8b::new).collect()
+}
+
+fn aes_digest(digest: impl IntoIterator<Item = AESTowerField8b>) -> Vec<u8> {
+	digest.into_iter().map(|x| x.val()).collect()
+}
+
+fn bitsliced_label() -> &'static str {
+	if cfg!(feature = "bs-groestl-c") {
+		"bitsliced C"
+	} else {
+		"bitsliced Rust"
+	}
+}
+
+fn to_packed(state: &[u8]) -> PackedAESBinaryField64x8b {
+	PackedAESBinaryField64x8b::from_fn(|i| AESTowerField8b::new(state[i]))
+}
+
+fn from_packed(state: PackedAESBinaryField64x8b) -> Vec<u8> {
+	aes_digest(state.iter())
+}
+
+/// Grøstl-256 written out on top of the P and Q permutations of a single-state backend.
+fn groestl256_with_core(
+	msg: &[u8],
+	permutation_pq: impl Fn(
+		PackedAESBinaryField64x8b,
+		PackedAESBinaryField64x8b,
+	) -> (PackedAESBinaryField64x8b, PackedAESBinaryField64x8b),
+	permutation_p: impl Fn(PackedAESBinaryField64x8b) -> PackedAESBinaryField64x8b,
+) -> Vec<u8> {
+	let num_blocks = (msg.len() + 9).div_ceil(64);
+	let mut padded = msg.to_vec();
+	padded.push(0x80);
+	padded.resize(num_blocks * 64 - 8, 0);
+	padded.extend_from_slice(&(num_blocks as u64).to_be_bytes());
+
+	let mut iv = [0u8; 64];
+	iv[62] = 0x01;
+	let mut h = to_packed(&iv);
+	for block in padded.chunks_exact(64) {
+		let m = to_packed(block);
+		let (p, q) = permutation_pq(h + m, m);
+		h += p + q;
+	}
+	let out = from_packed(permutation_p(h) + h);
+	out[32..].to_vec()
+}
+
+#[test]
+fn test_groestl256_permutations() {
+	let file = "groestl256_pq.txt";
+	let contents = include_str!("vectors/groestl256_pq.txt");
+	let mut vectors = Vec::new();
+	let mut input = None;
+	let mut p = None;
+	for line in contents.lines().filter(|line| !line.starts_with('#')) {
+		match line
+			.split_once('=')
+			.map(|(k, v)| (k.trim(), decode_hex(v.trim())))
+		{
+			Some(("In", v)) => input = Some(v),
+			Some(("P", v)) => p = Some(v),
+			Some(("Q", q)) => vectors.push((input.take().unwrap(), p.take().unwrap(), q)),
+			_ => {}
+		}
+	}
+	assert!(!vectors.is_empty());
+
+	let as_vectors = |select: fn(&(Vec<u8>, Vec<u8>, Vec<u8>)) -> &Vec<u8>| {
+		vectors
+			.iter()
+			.map(|v| Vector {
+				len: 64,
+				msg: v.0.clone(),
+				md: select(v).clone(),
+			})
+			.collect::<Vec<_>>()
+	};
+	let p_vectors = as_vectors(|v| &v.1);
+	let q_vectors = as_vectors(|v| &v.2);
+
+	let dispatched = format!("{:?}", groestl_dispatch().single);
+	check(file, "table P", &p_vectors, |x| {
+		from_packed(Groestl256TableCore.permutation_p(to_packed(x)))
+	});
+	check(file, "table Q", &q_vectors, |x| {
+		from_packed(Groestl256TableCore.permutation_q(to_packed(x)))
+	});
+	check(file, &format!("{dispatched} P"), &p_vectors, |x| {
+		from_packed(Groestl256Core.permutation_p(to_packed(x)))
+	});
+	check(file, &format!("{dispatched} Q"), &q_vectors, |x| {
+		from_packed(Groestl256Core.permutation_q(to_packed(x)))
+	});
+
+	check_batch(
+		file,
+		"bitsliced Rust P",
+		&p_vectors,
+		|_| true,
+		|states| permute_states(states, |states| BsGroestlP.permute_many(states)),
+	);
+	check_batch(
+		file,
+		"bitsliced Rust Q",
+		&q_vectors,
+		|_| true,
+		|states| permute_states(states, |states| BsGroestlQ.permute_many(states)),
+	);
+	report_skipped_groestl_backends(file);
+}
+
+fn permute_states(
+	states: &[&[u8]],
+	permute_many: impl Fn(&mut [[AESTowerField8b; 64]]),
+) -> Vec<Vec<u8>> {
+	let mut states = states
+		.iter()
+		.map(|x| array::from_fn(|i| AESTowerField8b::new(x[i])))
+		.collect::<Vec<_>>();
+	permute_many(&mut states);
+	states.into_iter().map(aes_digest).collect()
+}
+
+#[test]
+fn test_groestl256() {
+	check_groestl256("groestl256.txt", &vectors!("groestl256.txt"));
+	check_groestl256("groestl256_published.txt", &vectors!("groestl256_published.txt"));
+	report_skipped_groestl_backends("groestl256.txt");
+}
+
+fn check_groestl256(file: &str, vectors: &[Vector]) {
+	check(file, "table", vectors, |msg| {
+		groestl256_with_core(
+			msg,
+			|p, q| Groestl256TableCore.permutation_pq(p, q),
+			|p| Groestl256TableCore.permutation_p(p),
+		)
+	});
+	check(file, &format!("{:?}", groestl_dispatch().single), vectors, |msg| {
+		groestl256_with_core(
+			msg,
+			|p, q| Groestl256Core.permutation_pq(p, q),
+			|p| Groestl256Core.permutation_p(p),
+		)
+	});
+	check(file, "Groestl256 hasher", vectors, |msg| {
+		let hasher = Groestl256::<AESTowerField8b, AESTowerField8b>::new();
+		aes_digest(hasher.chain_update(aes_bytes(msg)).finalize().iter())
+	});
+	check(file, "BsGroestl256Hasher", vectors, |msg| {
+		let hasher = BsGroestl256Hasher::<AESTowerField8b, AESTowerField8b>::new();
+		aes_digest(hasher.chain_update(aes_bytes(msg)).finalize().iter())
+	});
+	check_batch(
+		file,
+		"BsGroestl256Hasher::hash_many",
+		vectors,
+		|_| true,
+		|messages| {
+			let messages = messages.iter().map(|x| aes_bytes(x)).collect::<Vec<_>>();
+			let messages = messages.iter().map(Vec::as_slice).collect::<Vec<_>>();
+			BsGroestl256Hasher::<AESTowerField8b, AESTowerField8b>::hash_many(&messages)
+				.into_iter()
+				.map(|digest| aes_digest(digest.iter()))
+				.collect()
+		},
+	);
+	check_batch(
+		file,
+		bitsliced_label(),
+		vectors,
+		// Only lengths every kernel accepts
+		|vector| vector.len > 0 && vector.len % BS_GROESTL_MSG_ALIGNMENT == 0,
+		|messages| {
+			BsGroestl256::<AESTowerField8b>::hash_many(messages)
+				.unwrap()
+				.into_iter()
+				.map(|digest| aes_digest(digest.iter()))
+				.collect()
+		},
+	);
+	check_batch(
+		file,
+		"bitsliced Rust, constant time",
+		vectors,
+		|_| true,
+		|messages| {
+			BsGroestl256::<AESTowerField8b>::hash_many_constant_time(messages)
+				.into_iter()
+				.map(|digest| aes_digest(digest.iter()))
+				.collect()
+		},
+	);
+}
+
+#[test]
+fn test_groestl224() {
+	check_groestl224("groestl224.txt", &vectors!("groestl224.txt"));
+	check_groestl224("groestl224_published.txt", &vectors!("groestl224_published.txt"));
+}
+
+fn check_groestl224(file: &str, vectors: &[Vector]) {
+	check(file, "Groestl224 hasher", vectors, |msg| {
+		let hasher = Groestl224::<AESTowerField8b, AESTowerField8b>::new();
+		aes_digest(hasher.chain_update(aes_bytes(msg)).finalize())
+	});
+	check_batch(
+		file,
+		"bitsliced Rust",
+		vectors,
+		|_| true,
+		|messages| {
+			BsGroestl224::<AESTowerField8b>::hash_many(messages)
+				.into_iter()
+				.map(aes_digest)
+				.collect()
+		},
+	);
+}
+
+#[test]
+fn test_groestl512() {
+	check_groestl512("groestl512.txt", &vectors!("groestl512.txt"));
+	check_groestl512("groestl512_published.txt", &vectors!("groestl512_published.txt"));
+}
+
+fn check_groestl512(file: &str, vectors: &[Vector]) {
+	check(file, "Groestl512 hasher", vectors, |msg| {
+		let hasher = Groestl512::<AESTowerField8b, AESTowerField8b>::new();
+		aes_digest(hasher.chain_update(aes_bytes(msg)).finalize().iter())
+	});
+	check_batch(
+		file,
+		"bitsliced Rust",
+		vectors,
+		|_| true,
+		|messages| {
+			BsGroestl512::<AESTowerField8b>::hash_many(messages)
+				.into_iter()
+				.map(|digest| aes_digest(digest.iter()))
+				.collect()
+		},
+	);
+}
+
+#[test]
+fn test_keccak256() {
+	check_keccak256("keccak256.txt", &vectors!("keccak256.txt"));
+	check_keccak256("keccak256_published.txt", &vectors!("keccak256_published.txt"));
+}
+
+fn check_keccak256(file: &str, vectors: &[Vector]) {
+	let keccak_digest = |digest: <Keccak256<BinaryField8b> as Hasher<_>>::Digest| {
+		digest.iter().map(|x| x.val()).collect::<Vec<_>>()
+	};
+	check(file, "Keccak256 hasher", vectors, |msg| {
+		let hasher = Keccak256::<BinaryField8b>::new();
+		keccak_digest(
+			hasher
+				.chain_update(BinaryField8b::wrap_slice(msg))
+				.finalize(),
+		)
+	});
+	check_batch(
+		file,
+		"Keccak256::hash_many",
+		vectors,
+		|_| true,
+		|messages| {
+			let messages = messages
+				.iter()
+				.map(|x| BinaryField8b::wrap_slice(x))
+				.collect::<Vec<_>>();
+			Keccak256::<BinaryField8b>::hash_many(&messages)
+				.into_iter()
+				.map(keccak_digest)
+				.collect()
+		},
+	);
+	check_batch(
+		file,
+		"MultiKeccak256 lanes",
+		vectors,
+		|_| true,
+		|messages| {
+			MultiKeccak256::hash_many(messages)
+				.into_iter()
+				.map(|digest| digest.to_vec())
+				.collect()
+		},
+	);
+}
+
+/// Decodes a message of little-endian field elements of `N` bytes.
+fn from_le<F, const N: usize>(bytes: &[u8], f: impl Fn([u8; N]) -> F) -> Vec<F> {
+	bytes
+		.chunks_exact(N)
+		.map(|chunk| f(chunk.try_into().unwrap()))
+		.collect()
+}
+
+fn b32s(bytes: &[u8]) -> Vec<BinaryField32b> {
+	from_le(bytes, |x| BinaryField32b::new(u32::from_le_bytes(x)))
+}
+
+fn b32_digest(digest: impl IntoIterator<Item = BinaryField32b>) -> Vec<u8> {
+	digest
+		.into_iter()
+		.flat_map(|x| x.val().to_le_bytes())
+		.collect()
+}
+
+#[test]
+fn test_vision32b() {
+	let file = "vision32b.txt";
+	let vectors = vectors!("vision32b.txt", 4);
+
+	check(file, "Vision32b", &vectors, |msg| {
+		let msg = b32s(msg);
+		let hasher = <Vision32b<_> as FixedLenHasher<_>>::new(msg.len() as u64);
+		b32_digest(hasher.chain_update(&msg).finalize().unwrap().iter())
+	});
+	check_batch(
+		file,
+		"MultiVision32b lanes",
+		&vectors,
+		|_| true,
+		|messages| {
+			let messages = messages.iter().map(|x| b32s(x)).collect::<Vec<_>>();
+			let messages = messages.iter().map(Vec::as_slice).collect::<Vec<_>>();
+			MultiVision32b::<BinaryField32b>::hash_many::<_, BinaryField32b>(&messages)
+				.into_iter()
+				.map(|digest| b32_digest(digest.iter()))
+				.collect()
+		},
+	);
+}
+
+#[test]
+fn test_vision64b() {
+	let file = "vision64b.txt";
+	let vectors = vectors!("vision64b.txt", 8);
+
+	check(file, "Vision64b", &vectors, |msg| {
+		let msg = from_le(msg, |x| BinaryField64b::new(u64::from_le_bytes(x)));
+		let hasher = <Vision64b<_> as FixedLenHasher<_>>::new(msg.len() as u64);
+		let digest = hasher.chain_update(&msg).finalize().unwrap();
+		digest.iter().flat_map(|x| x.val().to_le_bytes()).collect()
+	});
+}
+
+#[test]
+fn test_vision128b() {
+	let file = "vision128b.txt";
+	let vectors = vectors!("vision128b.txt", 16);
+
+	check(file, "Vision128b", &vectors, |msg| {
+		let msg = from_le(msg, |x| BinaryField128b::new(u128::from_le_bytes(x)));
+		let hasher = <Vision128b<_> as FixedLenHasher<_>>::new(msg.len() as u64);
+		let digest = hasher.chain_update(&msg).finalize().unwrap();
+		digest.iter().flat_map(|x| x.val().to_le_bytes()).collect()
+	});
+}
@@ -9,6 +9,7 @@ pub(crate) struct CompositionPolyItem {
 	pub name: syn::Ident,
 	pub vars: Vec<syn::Ident>,
 	pub poly_packed: syn::Expr,
+	pub expr: syn::Expr,
 	pub degree: usize,
 }
 
@@ -19,6 +20,7 @@ impl ToTokens for CompositionPolyItem {
 			name,
 			vars,
 			poly_packed,
+			expr,
 			degree,
 		} = self;
 		let n_vars = vars.len();
@@ -80,6 +82,10 @@ impl ToTokens for CompositionPolyItem {
 				fn binary_tower_level(&self) -> usize {
 					0
 				}
+
+				fn expression(&self) -> Option<binius_math::ArithExpr<P::Scalar>> {
+					Some(#expr)
+				}
 			}
 		};
 
@@ -112,12 +118,14 @@ impl Parse for CompositionPolyItem {
 		input.parse::<Token![=]>()?;
 		let mut poly_packed = input.parse::<syn::Expr>()?;
 		let degree = poly_degree(&poly_packed)?;
+		let expr = arith_expr(&poly_packed, &vars)?;
 		rewrite_literals(&mut poly_packed)?;
 		Ok(Self {
 			is_anonymous,
 			name,
 			vars,
 			poly_packed,
+			expr,
 			degree,
 		})
 	}
@@ -146,6 +154,48 @@ fn poly_degree(expr: &syn::Expr) -> Result<usize, syn::Error> {
 	})
 }
 
+/// Builds the `binius_math::ArithExpr` equivalent to the polynomial.
+///
+/// Make sure to run this before rewrite_literals, like poly_degree.
+fn arith_expr(expr: &syn::Expr, vars: &[syn::Ident]) -> Result<syn::Expr, syn::Error> {
+	Ok(match expr {
+		syn::Expr::Lit(exprlit) => match &exprlit.lit {
+			syn::Lit::Int(int) if int.to_string() == "0" => {
+				parse_quote!(binius_math::ArithExpr::Const(
+					<P::Scalar as binius_field::Field>::ZERO
+				))
+			}
+			syn::Lit::Int(int) if int.to_string() == "1" => {
+				parse_quote!(binius_math::ArithExpr::Const(<P::Scalar as binius_field::Field>::ONE))
+			}
+			_ => return Err(syn::Error::new(expr.span(), "Unsupported literal")),
+		},
+		syn::Expr::Path(p) => {
+			let i = vars
+				.iter()
+				.position(|var| p.path.is_ident(var))
+				.ok_or_else(|| syn::Error::new(p.span(), "unknown variable"))?;
+			parse_quote!(binius_math::ArithExpr::Var(#i))
+		}
+		syn::Expr::Paren(paren) => arith_expr(&paren.expr, vars)?,
+		syn::Expr::Binary(binary) => {
+			let left = arith_expr(&binary.left, vars)?;
+			let right = arith_expr(&binary.right, vars)?;
+			match binary.op {
+				// Subtraction is addition in characteristic 2
+				syn::BinOp::Add(_) | syn::BinOp::Sub(_) => parse_quote!(
+					binius_math::ArithExpr::Add(Box::new(#left), Box::new(#right))
+				),
+				syn::BinOp::Mul(_) => parse_quote!(
+					binius_math::ArithExpr::Mul(Box::new(#left), Box::new(#right))
+				),
+				op => return Err(syn::Error::new(op.span(), "Unsupported binop")),
+			}
+		}
+		expr => return Err(syn::Error::new(expr.span(), "Unsupported expression")),
+	})
+}
+
 /// Rewrites 0 => P::zero(), 1 => P::one()
 fn rewrite_literals(expr: &mut syn::Expr) -> Result<(), syn::Error> {
 	match expr {
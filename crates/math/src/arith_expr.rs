@@ -0,0 +1,253 @@
+// Copyright 2024 Irreducible Inc.
+
+use binius_field::{deserialize_canonical, serialize_canonical, Field, TowerField};
+use binius_utils::serialization::{DeserializeBytes, Error as SerializationError, SerializeBytes};
+use bytes::{Buf, BufMut};
+use std::{
+	cmp::max,
+	ops::{Add, Mul, Sub},
+};
+
+/// Represents an arithmetic expression that can be evaluated symbolically.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ArithExpr<F: Field> {
+	Const(F),
+	Var(usize),
+	Add(Box<ArithExpr<F>>, Box<ArithExpr<F>>),
+	Mul(Box<ArithExpr<F>>, Box<ArithExpr<F>>),
+	Pow(Box<ArithExpr<F>>, u64),
+}
+
+impl<F: Field> ArithExpr<F> {
+	pub fn n_vars(&self) -> usize {
+		match self {
+			ArithExpr::Const(_) => 0,
+			ArithExpr::Var(index) => *index + 1,
+			ArithExpr::Add(left, right) | ArithExpr::Mul(left, right) => {
+				max(left.n_vars(), right.n_vars())
+			}
+			ArithExpr::Pow(id, _) => id.n_vars(),
+		}
+	}
+
+	pub fn degree(&self) -> usize {
+		match self {
+			ArithExpr::Const(_) => 0,
+			ArithExpr::Var(_) => 1,
+			ArithExpr::Add(left, right) => max(left.degree(), right.degree()),
+			ArithExpr::Mul(left, right) => left.degree() + right.degree(),
+			ArithExpr::Pow(_, exp) => *exp as usize,
+		}
+	}
+
+	pub fn pow(self, exp: u64) -> Self {
+		ArithExpr::Pow(Box::new(self), exp)
+	}
+
+	/// Replaces every variable `i` with variable `indices[i]`.
+	///
+	/// Returns `None` if the expression refers to a variable outside of `indices`.
+	pub fn remap_vars(self, indices: &[usize]) -> Option<Self> {
+		Some(match self {
+			ArithExpr::Const(value) => ArithExpr::Const(value),
+			ArithExpr::Var(index) => ArithExpr::Var(*indices.get(index)?),
+			ArithExpr::Add(left, right) => ArithExpr::Add(
+				Box::new((*left).remap_vars(indices)?),
+				Box::new((*right).remap_vars(indices)?),
+			),
+			ArithExpr::Mul(left, right) => ArithExpr::Mul(
+				Box::new((*left).remap_vars(indices)?),
+				Box::new((*right).remap_vars(indices)?),
+			),
+			ArithExpr::Pow(base, exp) => {
+				ArithExpr::Pow(Box::new((*base).remap_vars(indices)?), exp)
+			}
+		})
+	}
+
+	/// Representation in an isomorphic or extension field.
+	pub fn convert_field<FTgt: Field + From<F>>(&self) -> ArithExpr<FTgt> {
+		match self {
+			ArithExpr::Const(value) => ArithExpr::Const((*value).into()),
+			ArithExpr::Var(index) => ArithExpr::Var(*index),
+			ArithExpr::Add(left, right) => {
+				ArithExpr::Add(Box::new(left.convert_field()), Box::new(right.convert_field()))
+			}
+			ArithExpr::Mul(left, right) => {
+				ArithExpr::Mul(Box::new(left.convert_field()), Box::new(right.convert_field()))
+			}
+			ArithExpr::Pow(base, exp) => ArithExpr::Pow(Box::new(base.convert_field()), *exp),
+		}
+	}
+}
+
+impl<F> Add for ArithExpr<F>
+where
+	F: Field,
+{
+	type Output = Self;
+
+	fn add(self, rhs: Self) -> Self {
+		ArithExpr::Add(Box::new(self), Box::new(rhs))
+	}
+}
+
+impl<F> Sub for ArithExpr<F>
+where
+	F: Field,
+{
+	type Output = Self;
+
+	fn sub(self, rhs: Self) -> Self {
+		ArithExpr::Add(Box::new(self), Box::new(rhs))
+	}
+}
+
+impl<F> Mul for ArithExpr<F>
+where
+	F: Field,
+{
+	type Output = Self;
+
+	fn mul(self, rhs: Self) -> Self {
+		ArithExpr::Mul(Box::new(self), Box::new(rhs))
+	}
+}
+
+impl<F: TowerField> SerializeBytes for ArithExpr<F> {
+	fn serialize(&self, mut write_buf: impl BufMut) -> Result<(), SerializationError> {
+		match self {
+			ArithExpr::Const(value) => {
+				0u8.serialize(&mut write_buf)?;
+				serialize_canonical(*value, write_buf)
+			}
+			ArithExpr::Var(index) => {
+				1u8.serialize(&mut write_buf)?;
+				index.serialize(write_buf)
+			}
+			ArithExpr::Add(left, right) => {
+				2u8.serialize(&mut write_buf)?;
+				left.serialize(&mut write_buf)?;
+				right.serialize(write_buf)
+			}
+			ArithExpr::Mul(left, right) => {
+				3u8.serialize(&mut write_buf)?;
+				left.serialize(&mut write_buf)?;
+				right.serialize(write_buf)
+			}
+			ArithExpr::Pow(base, exp) => {
+				4u8.serialize(&mut write_buf)?;
+				base.serialize(&mut write_buf)?;
+				exp.serialize(write_buf)
+			}
+		}
+	}
+}
+
+/// The maximum nesting depth of a deserialized [`ArithExpr`], which bounds the recursion of
+/// [`DeserializeBytes::deserialize`] on untrusted input.
+pub const MAX_DESERIALIZED_DEPTH: usize = 256;
+
+impl<F: TowerField> DeserializeBytes for ArithExpr<F> {
+	fn deserialize(mut read_buf: impl Buf) -> Result<Self, SerializationError> {
+		deserialize_with_depth(&mut read_buf, 0)
+	}
+}
+
+/// Deserializes a sub-expression nested `depth` levels deep.
+///
+/// The buffer is a trait object so that the recursion does not instantiate a new function for
+/// every level of nesting.
+fn deserialize_with_depth<F: TowerField>(
+	read_buf: &mut dyn Buf,
+	depth: usize,
+) -> Result<ArithExpr<F>, SerializationError> {
+	if depth >= MAX_DESERIALIZED_DEPTH {
+		return Err(SerializationError::InvalidValue(format!(
+			"ArithExpr is nested deeper than {MAX_DESERIALIZED_DEPTH} levels"
+		)));
+	}
+	let sub_expr =
+		|read_buf: &mut dyn Buf| deserialize_with_depth(read_buf, depth + 1).map(Box::new);
+
+	Ok(match u8::deserialize(&mut *read_buf)? {
+		0 => ArithExpr::Const(deserialize_canonical(read_buf)?),
+		1 => {
+			let index = usize::deserialize(read_buf)?;
+			// The expression must have a representable number of variables
+			index.checked_add(1).ok_or_else(|| {
+				SerializationError::InvalidValue(format!("ArithExpr variable index {index}"))
+			})?;
+			ArithExpr::Var(index)
+		}
+		2 => ArithExpr::Add(sub_expr(read_buf)?, sub_expr(read_buf)?),
+		3 => ArithExpr::Mul(sub_expr(read_buf)?, sub_expr(read_buf)?),
+		4 => ArithExpr::Pow(sub_expr(read_buf)?, u64::deserialize(read_buf)?),
+		index => {
+			return Err(SerializationError::UnknownEnumVariant {
+				name: "ArithExpr",
+				index,
+			})
+		}
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use binius_field::{BinaryField128b, BinaryField8b};
+
+	#[test]
+	fn test_remap_vars() {
+		let expr = ArithExpr::<BinaryField8b>::Var(0) * ArithExpr::Var(1)
+			+ ArithExpr::Const(BinaryField8b::ONE);
+		let remapped = expr.remap_vars(&[3, 5]).unwrap();
+		assert_eq!(remapped.n_vars(), 6);
+		assert_eq!(remapped.degree(), 2);
+		assert!(ArithExpr::<BinaryField8b>::Var(2)
+			.remap_vars(&[0, 1])
+			.is_none());
+	}
+
+	#[test]
+	fn test_serialization_roundtrip() {
+		let expr = (ArithExpr::Var(0) * ArithExpr::Var(1)).pow(3)
+			+ ArithExpr::Const(BinaryField128b::new(0x1234));
+		let mut buf = Vec::new();
+		expr.serialize(&mut buf).unwrap();
+		let deserialized = ArithExpr::<BinaryField128b>::deserialize(buf.as_slice()).unwrap();
+		assert_eq!(deserialized, expr);
+	}
+
+	#[test]
+	fn test_deserialize_rejects_unrepresentable_var() {
+		let mut buf = Vec::new();
+		ArithExpr::<BinaryField8b>::Var(usize::MAX)
+			.serialize(&mut buf)
+			.unwrap();
+		assert!(matches!(
+			ArithExpr::<BinaryField8b>::deserialize(buf.as_slice()),
+			Err(SerializationError::InvalidValue(_))
+		));
+	}
+
+	#[test]
+	fn test_deserialize_depth_bound() {
+		let nested = |depth: usize| {
+			(0..depth).fold(ArithExpr::<BinaryField8b>::Var(0), |expr, _| expr.pow(2))
+		};
+
+		let mut buf = Vec::new();
+		nested(MAX_DESERIALIZED_DEPTH - 1)
+			.serialize(&mut buf)
+			.unwrap();
+		assert!(ArithExpr::<BinaryField8b>::deserialize(buf.as_slice()).is_ok());
+
+		let mut buf = Vec::new();
+		nested(MAX_DESERIALIZED_DEPTH).serialize(&mut buf).unwrap();
+		assert!(matches!(
+			ArithExpr::<BinaryField8b>::deserialize(buf.as_slice()),
+			Err(SerializationError::InvalidValue(_))
+		));
+	}
+}
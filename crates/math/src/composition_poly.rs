@@ -1,6 +1,6 @@
 // Copyright 2024 Irreducible Inc.
 
-use crate::Error;
+use crate::{ArithExpr, Error};
 use auto_impl::auto_impl;
 use binius_field::PackedField;
 use stackalloc::stackalloc_with_default;
@@ -31,6 +31,13 @@ where
 	/// Returns the maximum binary tower level of all constants in the arithmetic expression.
 	fn binary_tower_level(&self) -> usize;
 
+	/// Returns the polynomial as a symbolic arithmetic expression, if it is known.
+	///
+	/// Constraint systems can only be serialized if all their compositions have an expression.
+	fn expression(&self) -> Option<ArithExpr<P::Scalar>> {
+		None
+	}
+
 	/// Batch evaluation that admits non-strided argument layout.
 	/// `batch_query` is a slice of slice references of equal length, which furthermore should equal
 	/// the length of `evals` parameter.
@@ -2,6 +2,7 @@
 
 #![feature(step_trait)]
 
+mod arith_expr;
 mod composition_poly;
 mod deinterleave;
 mod error;
@@ -14,6 +15,7 @@ mod packing_deref;
 mod tensor_prod_eq_ind;
 mod univariate;
 
+pub use arith_expr::*;
 pub use composition_poly::*;
 pub use deinterleave::*;
 pub use error::*;
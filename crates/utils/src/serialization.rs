@@ -9,6 +9,18 @@ pub enum Error {
 	WriteBufferFull,
 	#[error("Not enough data in read buffer to deserialize")]
 	NotEnoughBytes,
+	#[error("Unknown variant {index} of {name}")]
+	UnknownEnumVariant { name: &'static str, index: u8 },
+	#[error("Unknown type tag {0}")]
+	UnknownTypeTag(String),
+	#[error("Type {0} does not support serialization")]
+	SerializationNotImplemented(String),
+	#[error("Deserialized value is invalid: {0}")]
+	InvalidValue(String),
+	#[error("Length {0} does not fit in a usize")]
+	UsizeTooLarge(u64),
+	#[error("String is not valid UTF-8")]
+	InvalidUtf8,
 }
 
 pub trait SerializeBytes {
@@ -20,3 +32,217 @@ pub trait DeserializeBytes {
 	where
 		Self: Sized;
 }
+
+/// Fails with [`Error::WriteBufferFull`] unless `write_buf` has room for `len` more bytes.
+pub fn ensure_remaining_mut(write_buf: &impl BufMut, len: usize) -> Result<(), Error> {
+	if write_buf.remaining_mut() < len {
+		return Err(Error::WriteBufferFull);
+	}
+	Ok(())
+}
+
+/// Fails with [`Error::NotEnoughBytes`] unless `read_buf` has at least `len` more bytes.
+pub fn ensure_remaining(read_buf: &impl Buf, len: usize) -> Result<(), Error> {
+	if read_buf.remaining() < len {
+		return Err(Error::NotEnoughBytes);
+	}
+	Ok(())
+}
+
+macro_rules! serialize_deserialize_int {
+	($int:ty, $put:ident, $get:ident) => {
+		impl SerializeBytes for $int {
+			fn serialize(&self, mut write_buf: impl BufMut) -> Result<(), Error> {
+				ensure_remaining_mut(&write_buf, std::mem::size_of::<$int>())?;
+				write_buf.$put(*self);
+				Ok(())
+			}
+		}
+
+		impl DeserializeBytes for $int {
+			fn deserialize(mut read_buf: impl Buf) -> Result<Self, Error> {
+				ensure_remaining(&read_buf, std::mem::size_of::<$int>())?;
+				Ok(read_buf.$get())
+			}
+		}
+	};
+}
+
+serialize_deserialize_int!(u8, put_u8, get_u8);
+serialize_deserialize_int!(u16, put_u16_le, get_u16_le);
+serialize_deserialize_int!(u32, put_u32_le, get_u32_le);
+serialize_deserialize_int!(u64, put_u64_le, get_u64_le);
+
+/// Lengths and indices are encoded as `u64` so that the encoding does not depend on the platform.
+impl SerializeBytes for usize {
+	fn serialize(&self, write_buf: impl BufMut) -> Result<(), Error> {
+		(*self as u64).serialize(write_buf)
+	}
+}
+
+impl DeserializeBytes for usize {
+	fn deserialize(read_buf: impl Buf) -> Result<Self, Error> {
+		let value = u64::deserialize(read_buf)?;
+		usize::try_from(value).map_err(|_| Error::UsizeTooLarge(value))
+	}
+}
+
+impl SerializeBytes for bool {
+	fn serialize(&self, write_buf: impl BufMut) -> Result<(), Error> {
+		u8::from(*self).serialize(write_buf)
+	}
+}
+
+impl DeserializeBytes for bool {
+	fn deserialize(read_buf: impl Buf) -> Result<Self, Error> {
+		match u8::deserialize(read_buf)? {
+			0 => Ok(false),
+			1 => Ok(true),
+			index => Err(Error::UnknownEnumVariant {
+				name: "bool",
+				index,
+			}),
+		}
+	}
+}
+
+impl SerializeBytes for str {
+	fn serialize(&self, mut write_buf: impl BufMut) -> Result<(), Error> {
+		self.len().serialize(&mut write_buf)?;
+		ensure_remaining_mut(&write_buf, self.len())?;
+		write_buf.put_slice(self.as_bytes());
+		Ok(())
+	}
+}
+
+impl SerializeBytes for String {
+	fn serialize(&self, write_buf: impl BufMut) -> Result<(), Error> {
+		self.as_str().serialize(write_buf)
+	}
+}
+
+impl DeserializeBytes for String {
+	fn deserialize(mut read_buf: impl Buf) -> Result<Self, Error> {
+		let len = usize::deserialize(&mut read_buf)?;
+		ensure_remaining(&read_buf, len)?;
+		let mut bytes = vec![0u8; len];
+		read_buf.copy_to_slice(&mut bytes);
+		String::from_utf8(bytes).map_err(|_| Error::InvalidUtf8)
+	}
+}
+
+impl<T: SerializeBytes> SerializeBytes for [T] {
+	fn serialize(&self, mut write_buf: impl BufMut) -> Result<(), Error> {
+		self.len().serialize(&mut write_buf)?;
+		for item in self {
+			item.serialize(&mut write_buf)?;
+		}
+		Ok(())
+	}
+}
+
+impl<T: SerializeBytes> SerializeBytes for Vec<T> {
+	fn serialize(&self, write_buf: impl BufMut) -> Result<(), Error> {
+		self.as_slice().serialize(write_buf)
+	}
+}
+
+impl<T: DeserializeBytes> DeserializeBytes for Vec<T> {
+	fn deserialize(mut read_buf: impl Buf) -> Result<Self, Error> {
+		let len = usize::deserialize(&mut read_buf)?;
+		// Do not trust the length prefix for the allocation, a corrupted prefix must not abort.
+		let mut items = Vec::with_capacity(len.min(read_buf.remaining()));
+		for _ in 0..len {
+			items.push(T::deserialize(&mut read_buf)?);
+		}
+		Ok(items)
+	}
+}
+
+impl<T: SerializeBytes> SerializeBytes for Option<T> {
+	fn serialize(&self, mut write_buf: impl BufMut) -> Result<(), Error> {
+		match self {
+			None => false.serialize(write_buf),
+			Some(value) => {
+				true.serialize(&mut write_buf)?;
+				value.serialize(write_buf)
+			}
+		}
+	}
+}
+
+impl<T: DeserializeBytes> DeserializeBytes for Option<T> {
+	fn deserialize(mut read_buf: impl Buf) -> Result<Self, Error> {
+		if bool::deserialize(&mut read_buf)? {
+			Ok(Some(T::deserialize(read_buf)?))
+		} else {
+			Ok(None)
+		}
+	}
+}
+
+impl<T: SerializeBytes + ?Sized> SerializeBytes for Box<T> {
+	fn serialize(&self, write_buf: impl BufMut) -> Result<(), Error> {
+		self.as_ref().serialize(write_buf)
+	}
+}
+
+impl<T: DeserializeBytes> DeserializeBytes for Box<T> {
+	fn deserialize(read_buf: impl Buf) -> Result<Self, Error> {
+		T::deserialize(read_buf).map(Box::new)
+	}
+}
+
+impl<A: SerializeBytes, B: SerializeBytes> SerializeBytes for (A, B) {
+	fn serialize(&self, mut write_buf: impl BufMut) -> Result<(), Error> {
+		self.0.serialize(&mut write_buf)?;
+		self.1.serialize(write_buf)
+	}
+}
+
+impl<A: DeserializeBytes, B: DeserializeBytes> DeserializeBytes for (A, B) {
+	fn deserialize(mut read_buf: impl Buf) -> Result<Self, Error> {
+		let a = A::deserialize(&mut read_buf)?;
+		let b = B::deserialize(read_buf)?;
+		Ok((a, b))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn roundtrip<T: SerializeBytes + DeserializeBytes + PartialEq + std::fmt::Debug>(value: T) {
+		let mut buf = Vec::new();
+		value.serialize(&mut buf).unwrap();
+		let mut read_buf = buf.as_slice();
+		assert_eq!(T::deserialize(&mut read_buf).unwrap(), value);
+		assert!(read_buf.is_empty());
+	}
+
+	#[test]
+	fn test_roundtrip() {
+		roundtrip(0x1234u16);
+		roundtrip(usize::MAX);
+		roundtrip(String::from("oracle"));
+		roundtrip(vec![Some((1u32, true)), None]);
+		roundtrip(Box::new(vec![String::new()]));
+	}
+
+	#[test]
+	fn test_truncated() {
+		let mut buf = Vec::new();
+		vec![1u64, 2, 3].serialize(&mut buf).unwrap();
+		for len in 0..buf.len() {
+			assert!(matches!(Vec::<u64>::deserialize(&buf[..len]), Err(Error::NotEnoughBytes)));
+		}
+	}
+
+	#[test]
+	fn test_unknown_variant() {
+		assert!(matches!(
+			bool::deserialize([2u8].as_slice()),
+			Err(Error::UnknownEnumVariant { index: 2, .. })
+		));
+	}
+}
@@ -0,0 +1,163 @@
+# Copyright 2024 Irreducible Inc.
+
+# Reference implementation of Grøstl (https://www.groestl.info/Groestl.pdf) generating the test
+# vectors in crates/hash/tests/vectors, including the permutation vectors that the circuits crate
+# reads from there as well. These are not the KAT files of the submission package: the digests are
+# our own, and the implementation checks itself against a handful of published Grøstl-256 digests
+# before writing anything.
+#
+# Usage:
+#   python3 groestl.py ../../crates/hash/tests/vectors
+
+import os
+import sys
+
+SBOX = [
+    0x63, 0x7C, 0x77, 0x7B, 0xF2, 0x6B, 0x6F, 0xC5, 0x30, 0x01, 0x67, 0x2B, 0xFE, 0xD7, 0xAB, 0x76,
+    0xCA, 0x82, 0xC9, 0x7D, 0xFA, 0x59, 0x47, 0xF0, 0xAD, 0xD4, 0xA2, 0xAF, 0x9C, 0xA4, 0x72, 0xC0,
+    0xB7, 0xFD, 0x93, 0x26, 0x36, 0x3F, 0xF7, 0xCC, 0x34, 0xA5, 0xE5, 0xF1, 0x71, 0xD8, 0x31, 0x15,
+    0x04, 0xC7, 0x23, 0xC3, 0x18, 0x96, 0x05, 0x9A, 0x07, 0x12, 0x80, 0xE2, 0xEB, 0x27, 0xB2, 0x75,
+    0x09, 0x83, 0x2C, 0x1A, 0x1B, 0x6E, 0x5A, 0xA0, 0x52, 0x3B, 0xD6, 0xB3, 0x29, 0xE3, 0x2F, 0x84,
+    0x53, 0xD1, 0x00, 0xED, 0x20, 0xFC, 0xB1, 0x5B, 0x6A, 0xCB, 0xBE, 0x39, 0x4A, 0x4C, 0x58, 0xCF,
+    0xD0, 0xEF, 0xAA, 0xFB, 0x43, 0x4D, 0x33, 0x85, 0x45, 0xF9, 0x02, 0x7F, 0x50, 0x3C, 0x9F, 0xA8,
+    0x51, 0xA3, 0x40, 0x8F, 0x92, 0x9D, 0x38, 0xF5, 0xBC, 0xB6, 0xDA, 0x21, 0x10, 0xFF, 0xF3, 0xD2,
+    0xCD, 0x0C, 0x13, 0xEC, 0x5F, 0x97, 0x44, 0x17, 0xC4, 0xA7, 0x7E, 0x3D, 0x64, 0x5D, 0x19, 0x73,
+    0x60, 0x81, 0x4F, 0xDC, 0x22, 0x2A, 0x90, 0x88, 0x46, 0xEE, 0xB8, 0x14, 0xDE, 0x5E, 0x0B, 0xDB,
+    0xE0, 0x32, 0x3A, 0x0A, 0x49, 0x06, 0x24, 0x5C, 0xC2, 0xD3, 0xAC, 0x62, 0x91, 0x95, 0xE4, 0x79,
+    0xE7, 0xC8, 0x37, 0x6D, 0x8D, 0xD5, 0x4E, 0xA9, 0x6C, 0x56, 0xF4, 0xEA, 0x65, 0x7A, 0xAE, 0x08,
+    0xBA, 0x78, 0x25, 0x2E, 0x1C, 0xA6, 0xB4, 0xC6, 0xE8, 0xDD, 0x74, 0x1F, 0x4B, 0xBD, 0x8B, 0x8A,
+    0x70, 0x3E, 0xB5, 0x66, 0x48, 0x03, 0xF6, 0x0E, 0x61, 0x35, 0x57, 0xB9, 0x86, 0xC1, 0x1D, 0x9E,
+    0xE1, 0xF8, 0x98, 0x11, 0x69, 0xD9, 0x8E, 0x94, 0x9B, 0x1E, 0x87, 0xE9, 0xCE, 0x55, 0x28, 0xDF,
+    0x8C, 0xA1, 0x89, 0x0D, 0xBF, 0xE6, 0x42, 0x68, 0x41, 0x99, 0x2D, 0x0F, 0xB0, 0x54, 0xBB, 0x16,
+]
+
+MIX_BYTES = [0x02, 0x02, 0x03, 0x04, 0x05, 0x03, 0x05, 0x07]
+
+# (columns, rounds, ShiftBytes of P, ShiftBytes of Q)
+SMALL = (8, 10, [0, 1, 2, 3, 4, 5, 6, 7], [1, 3, 5, 7, 0, 2, 4, 6])
+LARGE = (16, 14, [0, 1, 2, 3, 4, 5, 6, 11], [1, 3, 5, 11, 0, 2, 4, 6])
+
+
+def gf_mul(a, b):
+    out = 0
+    while b:
+        if b & 1:
+            out ^= a
+        a <<= 1
+        if a & 0x100:
+            a ^= 0x11B
+        b >>= 1
+    return out
+
+
+def permutation(state, params, q):
+    """Grøstl P or Q on a state given as bytes, column after column"""
+    cols, rounds, shift_p, shift_q = params
+    shift = shift_q if q else shift_p
+    # a[row][col]
+    a = [[state[c * 8 + r] for c in range(cols)] for r in range(8)]
+    for i in range(rounds):
+        for c in range(cols):
+            if q:
+                for r in range(7):
+                    a[r][c] ^= 0xFF
+                a[7][c] ^= 0xFF ^ (c << 4) ^ i
+            else:
+                a[0][c] ^= (c << 4) ^ i
+        a = [[SBOX[x] for x in row] for row in a]
+        a = [[a[r][(c + shift[r]) % cols] for c in range(cols)] for r in range(8)]
+        a = [
+            [
+                sum_xor(gf_mul(MIX_BYTES[(k - r) % 8], a[k][c]) for k in range(8))
+                for c in range(cols)
+            ]
+            for r in range(8)
+        ]
+    return bytes(a[r][c] for c in range(cols) for r in range(8))
+
+
+def sum_xor(values):
+    out = 0
+    for v in values:
+        out ^= v
+    return out
+
+
+def xor_bytes(a, b):
+    return bytes(x ^ y for x, y in zip(a, b))
+
+
+def groestl(msg, digest_bits):
+    params = SMALL if digest_bits <= 256 else LARGE
+    block_len = params[0] * 8
+    h = bytearray(block_len)
+    h[-8:] = digest_bits.to_bytes(8, "big")
+    h = bytes(h)
+
+    num_blocks = (len(msg) + 9 + block_len - 1) // block_len
+    padded = msg + b"\x80" + bytes(num_blocks * block_len - len(msg) - 9)
+    padded += num_blocks.to_bytes(8, "big")
+    for i in range(num_blocks):
+        m = padded[i * block_len : (i + 1) * block_len]
+        p = permutation(xor_bytes(h, m), params, False)
+        q = permutation(m, params, True)
+        h = xor_bytes(xor_bytes(p, q), h)
+
+    out = xor_bytes(permutation(h, params, False), h)
+    return out[-digest_bits // 8 :]
+
+
+def self_check():
+    vectors = [
+        (b"", "1a52d11d550039be16107f9c58db9ebcc417f16f736adb2502567119f0083467"),
+        (
+            b"The quick brown fox jumps over the lazy dog",
+            "8c7ad62eb26a21297bc39c2d7293b4bd4d3399fa8afab29e970471739e28b301",
+        ),
+        (
+            b"The quick brown fox jumps over the lazy dog.",
+            "f48290b1bcacee406a0429b993adb8fb3d065f4b09cbcdb464a631d4a0080aaf",
+        ),
+        (b"A" * 64, "fb3ba0dd1af025433fd91b0813a5c7e14a885beb988a61e9efabcf6e9fcb1073"),
+        (b"A" * 65, "55bbe6657e052e83b95f2f468a22fd4ed8f4dd07f966e3addb593ffaa874820c"),
+    ]
+    for msg, expected in vectors:
+        assert groestl(msg, 256).hex() == expected, msg
+
+
+def messages():
+    """Deterministic messages of lengths around the block and padding boundaries"""
+    lengths = [0, 1, 3, 31, 32, 54, 55, 56, 63, 64, 65, 118, 119, 120, 127, 128, 129, 200, 256, 300]
+    for length in lengths:
+        yield bytes((17 * i + length) % 256 for i in range(length))
+
+
+def write_hash_vectors(path, digest_bits):
+    with open(path, "w") as f:
+        f.write(
+            f"# Grøstl-{digest_bits} test vectors, generated by testgen/hash/groestl.py, not taken "
+            "from the submission KATs\n"
+        )
+        for msg in messages():
+            f.write(f"\nLen = {len(msg)}\nMsg = {msg.hex()}\nMD = {groestl(msg, digest_bits).hex()}\n")
+
+
+def write_permutation_vectors(path):
+    with open(path, "w") as f:
+        f.write(
+            "# Grøstl-256 P and Q permutation test vectors, generated by testgen/hash/groestl.py, "
+            "not taken from the submission KATs\n"
+        )
+        for i in range(8):
+            state = bytes((29 * j + 7 * i * i) % 256 for j in range(64))
+            f.write(f"\nIn = {state.hex()}\n")
+            f.write(f"P = {permutation(state, SMALL, False).hex()}\n")
+            f.write(f"Q = {permutation(state, SMALL, True).hex()}\n")
+
+
+if __name__ == "__main__":
+    self_check()
+    out_dir = sys.argv[1]
+    for bits in [224, 256, 512]:
+        write_hash_vectors(os.path.join(out_dir, f"groestl{bits}.txt"), bits)
+    write_permutation_vectors(os.path.join(out_dir, "groestl256_pq.txt"))
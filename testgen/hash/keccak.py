@@ -0,0 +1,91 @@
+# Copyright 2024 Irreducible Inc.
+
+# Reference implementation of Keccak-256 (the pre-standard padding used by Ethereum) generating
+# crates/hash/tests/vectors/keccak256.txt. The permutation and sponge are checked against SHA3-256
+# from hashlib, which differs only in the domain separation byte.
+#
+# Usage:
+#   python3 keccak.py ../../crates/hash/tests/vectors
+
+import hashlib
+import os
+import sys
+
+RATE = 136
+
+ROUND_CONSTANTS = [
+    0x0000000000000001, 0x0000000000008082, 0x800000000000808A, 0x8000000080008000,
+    0x000000000000808B, 0x0000000080000001, 0x8000000080008081, 0x8000000000008009,
+    0x000000000000008A, 0x0000000000000088, 0x0000000080008009, 0x000000008000000A,
+    0x000000008000808B, 0x800000000000008B, 0x8000000000008089, 0x8000000000008003,
+    0x8000000000008002, 0x8000000000000080, 0x000000000000800A, 0x800000008000000A,
+    0x8000000080008081, 0x8000000000008080, 0x0000000080000001, 0x8000000080008008,
+]
+
+# Rotation offsets indexed by x + 5 * y
+ROTATIONS = [
+    0, 1, 62, 28, 27,
+    36, 44, 6, 55, 20,
+    3, 10, 43, 25, 39,
+    41, 45, 15, 21, 8,
+    18, 2, 61, 56, 14,
+]
+
+MASK = (1 << 64) - 1
+
+
+def rotl(x, n):
+    return ((x << n) | (x >> (64 - n))) & MASK if n else x
+
+
+def keccak_f(a):
+    for rc in ROUND_CONSTANTS:
+        c = [a[x] ^ a[x + 5] ^ a[x + 10] ^ a[x + 15] ^ a[x + 20] for x in range(5)]
+        d = [c[(x - 1) % 5] ^ rotl(c[(x + 1) % 5], 1) for x in range(5)]
+        a = [a[i] ^ d[i % 5] for i in range(25)]
+        b = [0] * 25
+        for x in range(5):
+            for y in range(5):
+                b[y + 5 * ((2 * x + 3 * y) % 5)] = rotl(a[x + 5 * y], ROTATIONS[x + 5 * y])
+        a = [b[i] ^ (~b[(i + 1) % 5 + 5 * (i // 5)] & b[(i + 2) % 5 + 5 * (i // 5)]) for i in range(25)]
+        a[0] ^= rc
+    return a
+
+
+def sponge(msg, domain):
+    padded = bytearray(msg + bytes([domain]) + bytes(-(len(msg) + 1) % RATE))
+    padded[-1] |= 0x80
+    state = [0] * 25
+    for i in range(0, len(padded), RATE):
+        block = padded[i : i + RATE]
+        for j in range(RATE // 8):
+            state[j] ^= int.from_bytes(block[8 * j : 8 * j + 8], "little")
+        state = keccak_f(state)
+    return b"".join(x.to_bytes(8, "little") for x in state[:4])
+
+
+def keccak256(msg):
+    return sponge(msg, 0x01)
+
+
+def self_check():
+    for n in [0, 1, 3, 135, 136, 137, 300]:
+        msg = bytes(range(n % 256)) * (n // 256) + bytes(range(n % 256))
+        assert sponge(msg, 0x06) == hashlib.sha3_256(msg).digest(), n
+    assert keccak256(b"").hex() == "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"
+    assert keccak256(b"abc").hex() == "4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45"
+
+
+def messages():
+    """Deterministic messages of lengths around the rate and padding boundaries"""
+    lengths = [0, 1, 3, 32, 64, 134, 135, 136, 137, 200, 271, 272, 273, 500]
+    for length in lengths:
+        yield bytes((31 * i + length) % 256 for i in range(length))
+
+
+if __name__ == "__main__":
+    self_check()
+    with open(os.path.join(sys.argv[1], "keccak256.txt"), "w") as f:
+        f.write("# Keccak-256 test vectors, generated by testgen/hash/keccak.py\n")
+        for msg in messages():
+            f.write(f"\nLen = {len(msg)}\nMsg = {msg.hex()}\nMD = {keccak256(msg).hex()}\n")
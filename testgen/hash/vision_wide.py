@@ -0,0 +1,315 @@
+# Copyright 2024 Irreducible Inc.
+
+# Generates the constants of the Vision-64b and Vision-128b instances in
+# crates/hash/src/vision_wide_constants.rs, the known answers of their tests and their test vectors.
+#
+# Usage:
+#   python3 vision_wide.py constants > ../../crates/hash/src/vision_wide_constants.rs
+#   (followed by rustfmt on the generated file)
+#   python3 vision_wide.py kat
+#   python3 vision_wide.py vectors ../../crates/hash/tests/vectors
+
+import os
+import sys
+from hashlib import shake_256
+
+# NUM_ROUNDS_WIDE in crates/hash/src/vision_wide.rs, a placeholder while the instances are
+# experimental
+NUM_ROUNDS = 10
+
+# Field elements are integers in the canonical tower basis, an element of level k has 2^k bits
+# and is a0 + a1 * X_k with a0, a1 of level k - 1, where X_k^2 = X_{k-1} * X_k + 1 and X_0 = 1.
+
+
+def _mul_alpha(a, k):
+    if k == 0:
+        return a
+    h = 1 << (k - 1)
+    mask = (1 << h) - 1
+    a0, a1 = a & mask, a >> h
+    return a1 | ((a0 ^ _mul_alpha(a1, k - 1)) << h)
+
+
+def _mul_slow(a, b, k):
+    if k == 0:
+        return a & b
+    h = 1 << (k - 1)
+    mask = (1 << h) - 1
+    a0, a1, b0, b1 = a & mask, a >> h, b & mask, b >> h
+    z0 = _mul_slow(a0, b0, k - 1)
+    z2 = _mul_slow(a1, b1, k - 1)
+    z1 = _mul_slow(a0 ^ a1, b0 ^ b1, k - 1) ^ z0 ^ z2
+    return (z0 ^ z2) | ((z1 ^ _mul_alpha(z2, k - 1)) << h)
+
+
+MUL_8B = [[_mul_slow(a, b, 3) for b in range(256)] for a in range(256)]
+
+
+def mul(a, b, k):
+    if k <= 3:
+        return MUL_8B[a][b] if k == 3 else _mul_slow(a, b, k)
+    h = 1 << (k - 1)
+    mask = (1 << h) - 1
+    a0, a1, b0, b1 = a & mask, a >> h, b & mask, b >> h
+    z0 = mul(a0, b0, k - 1)
+    z2 = mul(a1, b1, k - 1)
+    z1 = mul(a0 ^ a1, b0 ^ b1, k - 1) ^ z0 ^ z2
+    return (z0 ^ z2) | ((z1 ^ _mul_alpha(z2, k - 1)) << h)
+
+
+def invert_or_zero(a, k):
+    if a == 0:
+        return 0
+    if k <= 3:
+        return next(b for b in range(1 << (1 << k)) if mul(a, b, k) == 1)
+    # The conjugate of X_k is X_k + X_{k-1}, the norm a * conj(a) lies in the subfield
+    h = 1 << (k - 1)
+    mask = (1 << h) - 1
+    a0, a1 = a & mask, a >> h
+    conj0 = a0 ^ _mul_alpha(a1, k - 1)
+    norm = mul(a0, conj0, k - 1) ^ mul(a1, a1, k - 1)
+    norm_inv = invert_or_zero(norm, k - 1)
+    return mul(conj0, norm_inv, k - 1) | (mul(a1, norm_inv, k - 1) << h)
+
+
+def power(a, e, k):
+    acc = 1
+    while e:
+        if e & 1:
+            acc = mul(acc, a, k)
+        a = mul(a, a, k)
+        e >>= 1
+    return acc
+
+
+def self_check():
+    # Powers of the generator 0x13 from the 8-bit exp table of binius_field
+    assert [power(0x13, i, 3) for i in range(5)] == [0x01, 0x13, 0x43, 0x66, 0xAB]
+    # Multiplicative generators of binius_field have full order
+    generators = [
+        (4, 0xE2DE, [3, 5, 17, 257]),
+        (5, 0x03E21CEA, [3, 5, 17, 257, 65537]),
+        (6, 0x070F870DCD9C1D88, [3, 5, 17, 257, 641, 65537, 6700417]),
+        (
+            7,
+            0x2E895399AF449ACE499596F6E5FCCAFA,
+            [3, 5, 17, 257, 641, 65537, 274177, 6700417, 67280421310721],
+        ),
+    ]
+    for k, g, factors in generators:
+        order = (1 << (1 << k)) - 1
+        assert power(g, order, k) == 1
+        assert all(power(g, order // p, k) != 1 for p in factors)
+        assert mul(g, invert_or_zero(g, k), k) == 1
+
+
+class Stream:
+    """Little-endian words of the SHAKE256 output for a domain string"""
+
+    def __init__(self, domain):
+        self.shake = shake_256(domain.encode())
+        self.pos = 0
+
+    def next(self, bits):
+        n = bits // 8
+        # Squeezing more each time and skipping the consumed prefix keeps it simple
+        out = self.shake.digest(self.pos + n)[self.pos :]
+        self.pos += n
+        return int.from_bytes(out, "little")
+
+
+def gf2_inverse(columns, n):
+    """Inverts the F2-linear map given by the images of the basis vectors, None if singular"""
+    # Rows of the augmented matrix [A | I], row i holds bit i of every column
+    rows = [
+        sum(((columns[j] >> i) & 1) << j for j in range(n)) | (1 << (n + i)) for i in range(n)
+    ]
+    for col in range(n):
+        pivot = next((r for r in range(col, n) if (rows[r] >> col) & 1), None)
+        if pivot is None:
+            return None
+        rows[col], rows[pivot] = rows[pivot], rows[col]
+        for r in range(n):
+            if r != col and (rows[r] >> col) & 1:
+                rows[r] ^= rows[col]
+    inv_rows = [row >> n for row in rows]
+    return [sum(((inv_rows[i] >> j) & 1) << i for i in range(n)) for j in range(n)]
+
+
+class Instance:
+    def __init__(self, name, k, state_len, rate):
+        self.name = name
+        self.k = k
+        self.bits = 1 << k
+        self.state_len = state_len
+        self.rate = rate
+
+        stream = Stream(f"binius {name} constants")
+        # B(x) = b_0 + b_1 x + b_2 x^2 + b_3 x^4, a linearized affine polynomial of degree 4
+        while True:
+            coeffs = [stream.next(self.bits) for _ in range(4)]
+            if 0 in coeffs[1:]:
+                continue
+            fwd = [self.linearized(coeffs, 1 << i) for i in range(self.bits)]
+            inv = gf2_inverse(fwd, self.bits)
+            if inv is not None:
+                break
+        self.fwd_bases = fwd
+        self.fwd_const = coeffs[0]
+        self.inv_bases = inv
+        self.inv_const = self.apply(inv, coeffs[0])
+
+        # Cauchy matrix 1 / (x_i + y_j) over BinaryField8b with x_i = i, y_j = state_len + j
+        self.mds = [
+            [invert_or_zero(i ^ (state_len + j), 3) for j in range(state_len)]
+            for i in range(state_len)
+        ]
+
+        self.round_keys = [
+            [stream.next(self.bits) for _ in range(state_len)] for _ in range(2 * NUM_ROUNDS + 1)
+        ]
+
+    def linearized(self, coeffs, x):
+        x2 = mul(x, x, self.k)
+        x4 = mul(x2, x2, self.k)
+        return mul(coeffs[1], x, self.k) ^ mul(coeffs[2], x2, self.k) ^ mul(coeffs[3], x4, self.k)
+
+    def apply(self, bases, x):
+        out = 0
+        for i in range(self.bits):
+            if (x >> i) & 1:
+                out ^= bases[i]
+        return out
+
+    def sbox(self, x, bases, const):
+        return self.apply(bases, invert_or_zero(x, self.k)) ^ const
+
+    def mds_mul(self, state):
+        out = []
+        for row in self.mds:
+            acc = 0
+            for coeff, x in zip(row, state):
+                # Multiplication by a subfield element is the multiplication in the extension
+                acc ^= mul(coeff, x, self.k)
+            out.append(acc)
+        return out
+
+    def permute(self, state):
+        state = [x ^ key for x, key in zip(state, self.round_keys[0])]
+        for r in range(NUM_ROUNDS):
+            state = [self.sbox(x, self.inv_bases, self.inv_const) for x in state]
+            state = self.mds_mul(state)
+            state = [x ^ key for x, key in zip(state, self.round_keys[1 + 2 * r])]
+            state = [self.sbox(x, self.fwd_bases, self.fwd_const) for x in state]
+            state = self.mds_mul(state)
+            state = [x ^ key for x, key in zip(state, self.round_keys[2 + 2 * r])]
+        return state
+
+    def hash(self, msg):
+        state = [0] * self.state_len
+        state[self.rate] = len(msg) * self.bits // 8
+        for i, x in enumerate(msg):
+            state[i % self.rate] = x
+            if i % self.rate == self.rate - 1:
+                state = self.permute(state)
+        if len(msg) % self.rate != 0:
+            for i in range(len(msg) % self.rate, self.rate):
+                state[i] = 0
+            state = self.permute(state)
+        return state[: self.state_len - self.rate]
+
+
+INSTANCES = [
+    Instance("Vision-64b", 6, 12, 8),
+    Instance("Vision-128b", 7, 6, 4),
+]
+
+
+def rust_type(inst):
+    return f"BinaryField{inst.bits}b"
+
+
+def rust_elem(inst, x):
+    return f"{rust_type(inst)}::new({x:#0{inst.bits // 4 + 2}x})"
+
+
+def print_constants():
+    print("// Copyright 2024 Irreducible Inc.")
+    print()
+    print("//! Constants of the Vision-64b and Vision-128b instances, generated by")
+    print("//! `testgen/hash/vision_wide.py`.")
+    print()
+    print("use crate::vision_wide::NUM_ROUNDS_WIDE;")
+    print("use binius_field::{BinaryField128b, BinaryField64b};")
+    for inst in INSTANCES:
+        prefix = inst.name.replace("-", "").upper()
+        ty = rust_type(inst)
+        m = inst.state_len
+        print()
+        print(f"pub const {prefix}_AFFINE_FWD: [{ty}; {inst.bits}] = [")
+        for x in inst.fwd_bases:
+            print(f"\t{rust_elem(inst, x)},")
+        print("];")
+        print()
+        print(f"pub const {prefix}_AFFINE_FWD_CONST: {ty} = {rust_elem(inst, inst.fwd_const)};")
+        print()
+        print(f"pub const {prefix}_AFFINE_INV: [{ty}; {inst.bits}] = [")
+        for x in inst.inv_bases:
+            print(f"\t{rust_elem(inst, x)},")
+        print("];")
+        print()
+        print(f"pub const {prefix}_AFFINE_INV_CONST: {ty} = {rust_elem(inst, inst.inv_const)};")
+        print()
+        print("#[rustfmt::skip]")
+        print(f"pub const {prefix}_MDS: [[u8; {m}]; {m}] = [")
+        for row in inst.mds:
+            print("\t[" + ", ".join(f"0x{x:02x}" for x in row) + "],")
+        print("];")
+        print()
+        print("#[rustfmt::skip]")
+        print(f"pub const {prefix}_ROUND_KEYS: [[{ty}; {m}]; 2 * NUM_ROUNDS_WIDE + 1] = [")
+        for key in inst.round_keys:
+            print("\t[")
+            for x in key:
+                print(f"\t\t{rust_elem(inst, x)},")
+            print("\t],")
+        print("];")
+
+
+def print_kats():
+    for inst in INSTANCES:
+        print(inst.name)
+        print("  permute(0..m):", [hex(x) for x in inst.permute(list(range(inst.state_len)))])
+        print("  hash([0xdeadbeef]):", [hex(x) for x in inst.hash([0xDEADBEEF])])
+        for n in [2 * inst.rate, inst.rate + 3]:
+            print(f"  hash(0..{n}):", [hex(x) for x in inst.hash(list(range(n)))])
+
+
+def write_vectors(out_dir):
+    """Writes the test vectors in crates/hash/tests/vectors, elements are serialized little-endian"""
+    for inst in INSTANCES:
+        name = inst.name.replace("-", "").lower()
+        width = inst.bits // 8
+
+        def to_hex(elems):
+            return b"".join(x.to_bytes(width, "little") for x in elems).hex()
+
+        stream = Stream(f"binius {inst.name} test vectors")
+        with open(os.path.join(out_dir, f"{name}.txt"), "w") as f:
+            f.write(f"# {inst.name} test vectors, generated by testgen/hash/vision_wide.py\n")
+            f.write("# Len is the number of field elements\n")
+            for n in [1, 2, inst.rate - 1, inst.rate, inst.rate + 1, 2 * inst.rate, 5 * inst.rate + 3]:
+                msg = [stream.next(inst.bits) for _ in range(n)]
+                f.write(f"\nLen = {n}\nMsg = {to_hex(msg)}\nMD = {to_hex(inst.hash(msg))}\n")
+
+
+if __name__ == "__main__":
+    self_check()
+    if sys.argv[1:] == ["constants"]:
+        print_constants()
+    elif sys.argv[1:] == ["kat"]:
+        print_kats()
+    elif len(sys.argv) == 3 and sys.argv[1] == "vectors":
+        write_vectors(sys.argv[2])
+    else:
+        sys.exit(__doc__ or "usage: vision_wide.py constants|kat|vectors <dir>")